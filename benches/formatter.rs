@@ -0,0 +1,109 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! The process-wide default formatter under contention: the lock-free [`ArcSwap`] storage
+//! of `chronounit::formatter` against the former `Arc<Mutex<Option<...>>>` one.
+//!
+//! `cargo bench --bench formatter`
+
+// ----------------------------------------------------------------
+
+use std::sync::{Arc, Barrier, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, TimeZone, Utc};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use lazy_static::lazy_static;
+
+use chronounit::formatter;
+use chronounit::formatter::pattern::DateTimePattern;
+use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+
+// ----------------------------------------------------------------
+
+/// `THREADS` the numbers of threads formatting at the same time.
+const THREADS: [usize; 4] = [1, 4, 16, 64];
+
+lazy_static! {
+    /// The former storage, a mutex every call serialises on.
+    static ref MUTEX_FORMATTER: Arc<Mutex<Option<DefaultDateTimeFormatter>>> =
+        Arc::new(Mutex::new(Some(DefaultDateTimeFormatter::builtin())));
+}
+
+fn format_with_mutex(datetime: &DateTime<Utc>) -> String {
+    MUTEX_FORMATTER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .unwrap()
+        .format_date_time_utc(datetime, DateTimePattern::YyyyMmDdHhMmSs)
+}
+
+fn format_lock_free(datetime: &DateTime<Utc>) -> String {
+    formatter::format_date_time_utc(datetime, DateTimePattern::YyyyMmDdHhMmSs)
+}
+
+/// Runs `iters` calls of `format` on each of `threads` threads started together,
+/// and returns the wall time of the slowest.
+fn contended(threads: usize, iters: u64, format: fn(&DateTime<Utc>) -> String) -> Duration {
+    let datetime = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let barrier = Barrier::new(threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    barrier.wait();
+                    let start = Instant::now();
+                    for _ in 0..iters {
+                        criterion::black_box(format(criterion::black_box(&datetime)));
+                    }
+
+                    start.elapsed()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .max()
+            .unwrap_or_default()
+    })
+}
+
+fn bench_default_formatter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("default_formatter");
+    for threads in THREADS {
+        group.bench_with_input(
+            BenchmarkId::new("mutex", threads),
+            &threads,
+            |b, &threads| b.iter_custom(|iters| contended(threads, iters, format_with_mutex)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("lock_free", threads),
+            &threads,
+            |b, &threads| b.iter_custom(|iters| contended(threads, iters, format_lock_free)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_default_formatter);
+criterion_main!(benches);
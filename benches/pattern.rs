@@ -0,0 +1,117 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! Repeated formatting with one pattern, as a log formatter does: the strftime string
+//! parsed on every call, as formerly, against the chrono items parsed once and the
+//! [`CompiledPattern`] of the formatter, written digit by digit.
+//!
+//! `cargo bench --bench pattern`
+
+// ----------------------------------------------------------------
+
+use std::fmt::Write;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, TimeZone, Utc};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use chronounit::formatter::compiled::CompiledPattern;
+use chronounit::formatter::pattern::DateTimePattern;
+use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+
+// ----------------------------------------------------------------
+
+/// `PATTERNS` the patterns of the fast paths, and their strftime strings.
+const PATTERNS: [(DateTimePattern, &str); 2] = [
+    (
+        DateTimePattern::YyyyMmDdHhMmSs,
+        DateTimePattern::YYYY_MM_DD_HH_MM_SS,
+    ),
+    (
+        DateTimePattern::YyyyMmDdHhMmSsSss,
+        DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS,
+    ),
+];
+
+fn datetime() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 9).unwrap() + chrono::Duration::milliseconds(123)
+}
+
+fn bench_format(c: &mut Criterion) {
+    let datetime = datetime();
+    let mut group = c.benchmark_group("format");
+    for (pattern, value) in PATTERNS {
+        let items: Vec<Item<'static>> = StrftimeItems::new(value).collect();
+        let compiled = CompiledPattern::new(pattern.clone());
+        let dtf = DefaultDateTimeFormatter::new(pattern.clone());
+
+        group.bench_with_input(
+            BenchmarkId::new("strftime", &pattern),
+            &value,
+            |b, &value| b.iter(|| black_box(&datetime).format(value).to_string()),
+        );
+        group.bench_with_input(BenchmarkId::new("items", &pattern), &items, |b, items| {
+            b.iter(|| {
+                black_box(&datetime)
+                    .format_with_items(items.iter())
+                    .to_string()
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("compiled", &pattern),
+            &compiled,
+            |b, compiled| b.iter(|| compiled.format(black_box(&datetime))),
+        );
+        group.bench_with_input(BenchmarkId::new("formatter", &pattern), &dtf, |b, dtf| {
+            b.iter(|| dtf.format_date_time_utc_default(black_box(&datetime)))
+        });
+    }
+    group.finish();
+}
+
+/// Into a reused buffer, without an intermediate `String`, as a log line is.
+fn bench_write(c: &mut Criterion) {
+    let datetime = datetime();
+    let mut group = c.benchmark_group("write");
+    for (pattern, value) in PATTERNS {
+        let dtf = DefaultDateTimeFormatter::new(pattern.clone());
+        let mut buffer = String::with_capacity(64);
+
+        group.bench_with_input(
+            BenchmarkId::new("strftime", &pattern),
+            &value,
+            |b, &value| {
+                b.iter(|| {
+                    buffer.clear();
+                    write!(buffer, "{}", black_box(&datetime).format(value)).unwrap();
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("formatter", &pattern), &dtf, |b, dtf| {
+            b.iter(|| {
+                buffer.clear();
+                dtf.write_date_time_utc_default(&mut buffer, black_box(&datetime))
+                    .unwrap();
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_format, bench_write);
+criterion_main!(benches);
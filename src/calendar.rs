@@ -0,0 +1,262 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Months, NaiveDate, NaiveDateTime, TimeZone};
+
+use crate::error::ParseCalendarUnitError;
+
+// ----------------------------------------------------------------
+
+/// Aliases accepted by [`CalendarUnit::from_str`], matched case-insensitively.
+const ALIASES: &[(&str, CalendarUnit)] = &[
+    ("mo", CalendarUnit::Months),
+    ("mos", CalendarUnit::Months),
+    ("month", CalendarUnit::Months),
+    ("months", CalendarUnit::Months),
+    ("q", CalendarUnit::Quarters),
+    ("qtr", CalendarUnit::Quarters),
+    ("qtrs", CalendarUnit::Quarters),
+    ("quarter", CalendarUnit::Quarters),
+    ("quarters", CalendarUnit::Quarters),
+    ("y", CalendarUnit::Years),
+    ("yr", CalendarUnit::Years),
+    ("yrs", CalendarUnit::Years),
+    ("year", CalendarUnit::Years),
+    ("years", CalendarUnit::Years),
+];
+
+// ----------------------------------------------------------------
+
+/// [`CalendarUnit`] calendar unit.
+///
+/// Unlike a [`TimeUnit`](crate::TimeUnit), a calendar unit has no fixed length, so it
+/// can't be converted into nanoseconds, only added to a date.
+///
+/// Adding months keeps the day of month, unless that day does not exist in the resulting
+/// month, then it is clamped to the last day of that month: `2024-01-31` plus one month
+/// is `2024-02-29`. The clamping is not remembered, adding one month twice to `2024-01-31`
+/// gives `2024-03-29`, while adding two months at once gives `2024-03-31`.
+/// The time of day is left untouched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CalendarUnit {
+    /// Calendar unit representing one month.
+    Months,
+    /// Calendar unit representing three months.
+    Quarters,
+    /// Calendar unit representing twelve months.
+    Years,
+}
+
+impl CalendarUnit {
+    /// 3
+    pub const MONTHS_PER_QUARTER: u32 = 3;
+    /// 12
+    pub const MONTHS_PER_YEAR: u32 = 12;
+
+    // ----------------------------------------------------------------
+
+    pub const MONTHS_NAME: &'static str = "Months";
+    pub const QUARTERS_NAME: &'static str = "Quarters";
+    pub const YEARS_NAME: &'static str = "Years";
+
+    // ----------------------------------------------------------------
+
+    /// The number of months in this [`CalendarUnit`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::calendar::CalendarUnit;
+    ///
+    /// assert_eq!(CalendarUnit::Months.months(), 1);
+    /// assert_eq!(CalendarUnit::Quarters.months(), 3);
+    /// assert_eq!(CalendarUnit::Years.months(), 12);
+    /// ```
+    pub fn months(&self) -> u32 {
+        match self {
+            CalendarUnit::Months => 1,
+            CalendarUnit::Quarters => Self::MONTHS_PER_QUARTER,
+            CalendarUnit::Years => Self::MONTHS_PER_YEAR,
+        }
+    }
+
+    /// Adds the given amount of this [`CalendarUnit`] to a [`NaiveDate`],
+    /// clamping to the end of the month.
+    ///
+    /// # Arguments
+    /// `date` - The date to add to.
+    /// `amount` - The amount of this unit, negative to go back in time.
+    ///
+    /// # Returns
+    /// The resulting date, or `None` if it is out of the range of [`NaiveDate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use chronounit::calendar::CalendarUnit;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    ///
+    /// assert_eq!(CalendarUnit::Months.add_to_date(date, 1), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// assert_eq!(CalendarUnit::Quarters.add_to_date(date, 1), NaiveDate::from_ymd_opt(2024, 4, 30));
+    /// assert_eq!(CalendarUnit::Years.add_to_date(date, -1), NaiveDate::from_ymd_opt(2023, 1, 31));
+    /// assert_eq!(CalendarUnit::Years.add_to_date(date, i64::MAX), None);
+    /// ```
+    pub fn add_to_date(&self, date: NaiveDate, amount: i64) -> Option<NaiveDate> {
+        let (months, forward) = self.months_of(amount)?;
+        if forward {
+            date.checked_add_months(months)
+        } else {
+            date.checked_sub_months(months)
+        }
+    }
+
+    /// Adds the given amount of this [`CalendarUnit`] to a [`NaiveDateTime`],
+    /// clamping to the end of the month.
+    ///
+    /// # Arguments
+    /// `datetime` - The date-time to add to.
+    /// `amount` - The amount of this unit, negative to go back in time.
+    ///
+    /// # Returns
+    /// The resulting date-time, or `None` if it is out of the range of [`NaiveDateTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use chronounit::calendar::CalendarUnit;
+    ///
+    /// let ndt = NaiveDateTime::parse_from_str("2024-03-31 22:55:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let expected = NaiveDateTime::parse_from_str("2024-02-29 22:55:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// assert_eq!(CalendarUnit::Months.add_to_naive_date_time(ndt, -1), Some(expected));
+    /// ```
+    pub fn add_to_naive_date_time(
+        &self,
+        datetime: NaiveDateTime,
+        amount: i64,
+    ) -> Option<NaiveDateTime> {
+        let (months, forward) = self.months_of(amount)?;
+        if forward {
+            datetime.checked_add_months(months)
+        } else {
+            datetime.checked_sub_months(months)
+        }
+    }
+
+    /// Adds the given amount of this [`CalendarUnit`] to a [`DateTime`] in its own time zone,
+    /// clamping to the end of the month.
+    ///
+    /// # Arguments
+    /// `datetime` - The date-time to add to.
+    /// `amount` - The amount of this unit, negative to go back in time.
+    ///
+    /// # Returns
+    /// The resulting date-time, or `None` if it is out of range, or if the resulting local
+    /// time does not exist or is ambiguous in the time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::calendar::CalendarUnit;
+    ///
+    /// let datetime = Utc.with_ymd_and_hms(2023, 11, 30, 12, 0, 0).unwrap();
+    /// let expected = Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(CalendarUnit::Quarters.add_to_date_time(datetime, 1), Some(expected));
+    /// ```
+    pub fn add_to_date_time<Tz: TimeZone>(
+        &self,
+        datetime: DateTime<Tz>,
+        amount: i64,
+    ) -> Option<DateTime<Tz>> {
+        let (months, forward) = self.months_of(amount)?;
+        if forward {
+            datetime.checked_add_months(months)
+        } else {
+            datetime.checked_sub_months(months)
+        }
+    }
+
+    /// Retrieves the string representation of this [`CalendarUnit`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::calendar::CalendarUnit;
+    ///
+    /// assert_eq!(CalendarUnit::Quarters.value(), "Quarters");
+    /// ```
+    pub fn value(&self) -> String {
+        self.to_string()
+    }
+
+    /// The absolute amount of months, and whether they are added or subtracted.
+    fn months_of(&self, amount: i64) -> Option<(Months, bool)> {
+        let months = amount.unsigned_abs().checked_mul(self.months() as u64)?;
+
+        Some((Months::new(u32::try_from(months).ok()?), amount >= 0))
+    }
+}
+
+impl fmt::Display for CalendarUnit {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CalendarUnit::Months => CalendarUnit::MONTHS_NAME,
+            CalendarUnit::Quarters => CalendarUnit::QUARTERS_NAME,
+            CalendarUnit::Years => CalendarUnit::YEARS_NAME,
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for CalendarUnit {
+    type Err = ParseCalendarUnitError;
+
+    /// Parses a [`CalendarUnit`] from its name, singular name or abbreviation,
+    /// e.g. `months`/`mo`, `quarter`/`q` or `years`/`y`, case-insensitively.
+    ///
+    /// `m` is not accepted, since it is commonly used for minutes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::calendar::CalendarUnit;
+    ///
+    /// assert_eq!("Months".parse(), Ok(CalendarUnit::Months));
+    /// assert_eq!("qtr".parse(), Ok(CalendarUnit::Quarters));
+    /// assert_eq!("Y".parse(), Ok(CalendarUnit::Years));
+    /// assert!("m".parse::<CalendarUnit>().is_err());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let lower_case = value.trim().to_lowercase();
+        ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == lower_case)
+            .map(|&(_, unit)| unit)
+            .ok_or_else(|| ParseCalendarUnitError::new(value))
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::time::Duration;
+
+use crate::duration::formatter::{DurationFormatter, DurationStyle};
+use crate::duration::iso8601::IsoDuration;
+use crate::duration::parser::DurationParser;
+use crate::error::ParseDurationError;
+
+// ----------------------------------------------------------------
+
+pub mod formatter;
+pub mod iso8601;
+pub mod parser;
+
+// ----------------------------------------------------------------
+
+/// Parses a human duration string, such as `1h 30m 15s 250ms`, into a [`Duration`].
+///
+/// See [`DurationParser`] for the accepted syntax.
+///
+/// # Arguments
+/// `value` - The duration string.
+///
+/// # Returns
+/// The parsed [`Duration`], or a [`ParseDurationError`] if `value` is malformed, negative or too large.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration;
+///
+/// assert_eq!(duration::parse("1h 30m 15s 250ms"), Ok(Duration::from_millis(5_415_250)));
+/// assert_eq!(duration::parse("1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(duration::parse("1h 30m 15.5s"), Ok(Duration::from_millis(5_415_500)));
+/// assert_eq!(duration::parse("2 days 3 hours"), Ok(Duration::from_secs(183_600)));
+/// assert!(duration::parse("-5m").is_err());
+/// ```
+pub fn parse(value: &str) -> Result<Duration, ParseDurationError> {
+    DurationParser::new().parse(value)
+}
+
+/// Parses a human duration string, such as `-5m`, into a [`chrono::Duration`].
+///
+/// See [`DurationParser`] for the accepted syntax.
+///
+/// # Arguments
+/// `value` - The duration string, which may start with a sign.
+///
+/// # Returns
+/// The parsed [`chrono::Duration`], or a [`ParseDurationError`] if `value` is malformed or too large.
+///
+/// # Examples
+/// ```rust
+/// use chronounit::duration;
+///
+/// assert_eq!(duration::parse_chrono("-5m"), Ok(chrono::Duration::minutes(-5)));
+/// assert_eq!(duration::parse_chrono("1d 12h"), Ok(chrono::Duration::hours(36)));
+/// ```
+pub fn parse_chrono(value: &str) -> Result<chrono::Duration, ParseDurationError> {
+    DurationParser::new().parse_chrono(value)
+}
+
+/// Formats a [`Duration`] in the given [`DurationStyle`], showing all units up to days.
+///
+/// See [`DurationFormatter`] to limit the units shown or to keep zero components.
+///
+/// # Arguments
+/// `duration` - The [`Duration`] to format.
+/// `style` - The [`DurationStyle`].
+///
+/// # Returns
+/// The formatted duration.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration::{self, formatter::DurationStyle};
+///
+/// let elapsed = Duration::from_secs(5415);
+/// assert_eq!(duration::format(elapsed, DurationStyle::Compact), "1h30m15s");
+/// assert_eq!(duration::format(elapsed, DurationStyle::Verbose), "1 hour, 30 minutes, 15 seconds");
+/// assert_eq!(duration::format(elapsed, DurationStyle::Largest(2)), "~1 hour 30 minutes");
+/// ```
+pub fn format(duration: Duration, style: DurationStyle) -> String {
+    DurationFormatter::new(style).format(duration)
+}
+
+/// Formats a [`chrono::Duration`] in the given [`DurationStyle`], showing all units up to days.
+///
+/// # Arguments
+/// `duration` - The [`chrono::Duration`] to format, prefixed with `-` when negative.
+/// `style` - The [`DurationStyle`].
+///
+/// # Returns
+/// The formatted duration.
+///
+/// # Examples
+/// ```rust
+/// use chronounit::duration::{self, formatter::DurationStyle};
+///
+/// assert_eq!(duration::format_chrono(chrono::Duration::minutes(-90), DurationStyle::Compact), "-1h30m");
+/// ```
+pub fn format_chrono(duration: chrono::Duration, style: DurationStyle) -> String {
+    DurationFormatter::new(style).format_chrono(duration)
+}
+
+/// Parses an ISO 8601 duration, such as `PT1H30M` or `P3DT4H`, into a [`Duration`].
+///
+/// See [`IsoDuration::parse`] for the accepted syntax.
+///
+/// # Arguments
+/// `value` - The ISO 8601 duration.
+///
+/// # Returns
+/// The parsed [`Duration`], or a [`ParseDurationError`] if `value` is malformed, negative,
+/// too large or has years or months.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration;
+/// use chronounit::error::ParseDurationErrorKind;
+///
+/// assert_eq!(duration::parse_iso8601("PT1H30M"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(duration::parse_iso8601("P2W"), Ok(Duration::from_secs(14 * 86_400)));
+/// assert_eq!(duration::parse_iso8601("PT0.25S"), Ok(Duration::from_millis(250)));
+/// assert_eq!(
+///     duration::parse_iso8601("P1M").unwrap_err().kind(),
+///     &ParseDurationErrorKind::CalendarComponent('M')
+/// );
+/// ```
+pub fn parse_iso8601(value: &str) -> Result<Duration, ParseDurationError> {
+    IsoDuration::parse(value)?.to_duration()
+}
+
+/// Parses an ISO 8601 duration, such as `-PT5M`, into a [`chrono::Duration`].
+///
+/// # Arguments
+/// `value` - The ISO 8601 duration.
+///
+/// # Returns
+/// The parsed [`chrono::Duration`], or a [`ParseDurationError`] if `value` is malformed,
+/// too large or has years or months.
+///
+/// # Examples
+/// ```rust
+/// use chronounit::duration;
+///
+/// assert_eq!(duration::parse_iso8601_chrono("-PT5M"), Ok(chrono::Duration::minutes(-5)));
+/// assert_eq!(duration::parse_iso8601_chrono("PT-6H3M"), Ok(chrono::Duration::minutes(-357)));
+/// ```
+pub fn parse_iso8601_chrono(value: &str) -> Result<chrono::Duration, ParseDurationError> {
+    IsoDuration::parse(value)?.to_chrono_duration()
+}
+
+/// Formats a [`Duration`] as an ISO 8601 duration of hours, minutes and seconds.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration;
+///
+/// assert_eq!(duration::format_iso8601(Duration::from_secs(5400)), "PT1H30M");
+/// assert_eq!(duration::format_iso8601(Duration::from_millis(250)), "PT0.25S");
+/// assert_eq!(duration::format_iso8601(Duration::ZERO), "PT0S");
+/// ```
+pub fn format_iso8601(duration: Duration) -> String {
+    IsoDuration::from_duration(duration).to_string()
+}
+
+/// Formats a [`chrono::Duration`] as an ISO 8601 duration of hours, minutes and seconds.
+///
+/// # Examples
+/// ```rust
+/// use chronounit::duration;
+///
+/// assert_eq!(duration::format_iso8601_chrono(chrono::Duration::minutes(-5)), "-PT5M");
+/// ```
+pub fn format_iso8601_chrono(duration: chrono::Duration) -> String {
+    IsoDuration::from_chrono_duration(duration).to_string()
+}
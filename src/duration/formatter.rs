@@ -0,0 +1,316 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::time::Duration;
+
+use crate::TimeUnit;
+
+// ----------------------------------------------------------------
+
+/// The units a duration is split into, from the largest to the smallest.
+const UNITS: [TimeUnit; 8] = [
+    TimeUnit::Weeks,
+    TimeUnit::Days,
+    TimeUnit::Hours,
+    TimeUnit::Minutes,
+    TimeUnit::Seconds,
+    TimeUnit::Milliseconds,
+    TimeUnit::Microseconds,
+    TimeUnit::Nanoseconds,
+];
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+// ----------------------------------------------------------------
+
+/// [`DurationStyle`] how a [`DurationFormatter`] renders a duration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DurationStyle {
+    /// Abbreviated components without separators, e.g. `1h30m15s`.
+    Compact,
+    /// Full unit names separated by commas, e.g. `1 hour, 30 minutes`.
+    Verbose,
+    /// At most the given number of largest components with full unit names, prefixed with `~`
+    /// when smaller components were dropped, e.g. `~2 days 3 hours`.
+    ///
+    /// The components are adjacent: unless zero components are kept, they stop at the first
+    /// zero one, e.g. `~2 days` rather than `2 days 5 minutes`. `Largest(0)` is `Largest(1)`.
+    Largest(usize),
+    /// A single decimal amount of the given unit, with the given number of fraction
+    /// digits, e.g. `5400.000s`.
+    Fixed(TimeUnit, usize),
+}
+
+/// [`DurationFormatter`] renders a duration in a human-readable [`DurationStyle`].
+///
+/// A duration is split into components from the largest to the smallest unit shown.
+/// Amounts above the largest unit are folded into it, amounts below the smallest unit
+/// are truncated. By default all units up to [`TimeUnit::Days`] are shown and zero
+/// components are suppressed.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration::formatter::{DurationFormatter, DurationStyle};
+/// use chronounit::TimeUnit;
+///
+/// let duration = Duration::from_millis(5_415_250);
+///
+/// let df = DurationFormatter::new(DurationStyle::Compact);
+/// assert_eq!(df.format(duration), "1h30m15s250ms");
+///
+/// let df = df.with_smallest_unit(TimeUnit::Seconds);
+/// assert_eq!(df.format(duration), "1h30m15s");
+///
+/// let df = DurationFormatter::new(DurationStyle::Verbose).with_smallest_unit(TimeUnit::Minutes);
+/// assert_eq!(df.format(duration), "1 hour, 30 minutes");
+///
+/// let df = DurationFormatter::new(DurationStyle::Largest(2));
+/// assert_eq!(df.format(Duration::from_secs(183_900)), "~2 days 3 hours");
+///
+/// let df = DurationFormatter::new(DurationStyle::Fixed(TimeUnit::Seconds, 3));
+/// assert_eq!(df.format(Duration::from_secs(5400)), "5400.000s");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DurationFormatter {
+    style: DurationStyle,
+    smallest: TimeUnit,
+    largest: TimeUnit,
+    suppress_zero: bool,
+}
+
+impl Default for DurationFormatter {
+    /// override
+    fn default() -> Self {
+        DurationFormatter::new(DurationStyle::Compact)
+    }
+}
+
+impl DurationFormatter {
+    /// Creates a [`DurationFormatter`] of the given [`DurationStyle`], showing all units up to
+    /// [`TimeUnit::Days`] and suppressing zero components.
+    pub fn new(style: DurationStyle) -> Self {
+        DurationFormatter {
+            style,
+            smallest: TimeUnit::Nanoseconds,
+            largest: TimeUnit::Days,
+            suppress_zero: true,
+        }
+    }
+
+    /// Returns a copy of this formatter with the given [`DurationStyle`].
+    pub fn with_style(self, style: DurationStyle) -> Self {
+        DurationFormatter { style, ..self }
+    }
+
+    /// Returns a copy of this formatter which truncates components below `unit`.
+    ///
+    /// The largest unit is raised to `unit` if needed.
+    pub fn with_smallest_unit(self, unit: TimeUnit) -> Self {
+        DurationFormatter {
+            smallest: unit,
+            largest: self.largest.max(unit),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this formatter which folds components above `unit` into `unit`,
+    /// e.g. `2 days` are rendered as `48h` when the largest unit is [`TimeUnit::Hours`].
+    ///
+    /// The smallest unit is lowered to `unit` if needed.
+    pub fn with_largest_unit(self, unit: TimeUnit) -> Self {
+        DurationFormatter {
+            smallest: self.smallest.min(unit),
+            largest: unit,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this formatter which suppresses, or keeps, the zero components
+    /// between the largest non-zero component and the smallest unit, e.g. `1h0m15s`.
+    pub fn with_zero_suppression(self, suppress_zero: bool) -> Self {
+        DurationFormatter {
+            suppress_zero,
+            ..self
+        }
+    }
+
+    /// The [`DurationStyle`] of this formatter.
+    pub fn style(&self) -> DurationStyle {
+        self.style
+    }
+
+    /// The smallest [`TimeUnit`] shown.
+    pub fn smallest_unit(&self) -> TimeUnit {
+        self.smallest
+    }
+
+    /// The largest [`TimeUnit`] shown.
+    pub fn largest_unit(&self) -> TimeUnit {
+        self.largest
+    }
+
+    /// Whether zero components are suppressed.
+    pub fn suppresses_zero(&self) -> bool {
+        self.suppress_zero
+    }
+
+    /// Formats a [`Duration`].
+    ///
+    /// A zero duration is rendered as `0s`, or in the unit nearest to seconds within the
+    /// smallest and largest unit.
+    ///
+    /// # Arguments
+    /// `duration` - The [`Duration`] to format.
+    ///
+    /// # Returns
+    /// The formatted duration.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::duration::formatter::{DurationFormatter, DurationStyle};
+    /// use chronounit::TimeUnit;
+    ///
+    /// let df = DurationFormatter::new(DurationStyle::Verbose);
+    /// assert_eq!(df.format(Duration::from_secs(90_061)), "1 day, 1 hour, 1 minute, 1 second");
+    /// assert_eq!(df.format(Duration::ZERO), "0 seconds");
+    ///
+    /// let df = DurationFormatter::new(DurationStyle::Compact)
+    ///     .with_smallest_unit(TimeUnit::Seconds)
+    ///     .with_largest_unit(TimeUnit::Hours)
+    ///     .with_zero_suppression(false);
+    /// assert_eq!(df.format(Duration::from_secs(2 * 86_400 + 15)), "48h0m15s");
+    /// ```
+    pub fn format(&self, duration: Duration) -> String {
+        self.format_nanos(false, duration.as_nanos())
+    }
+
+    /// Formats a [`chrono::Duration`], which is prefixed with `-` when negative.
+    ///
+    /// # Arguments
+    /// `duration` - The [`chrono::Duration`] to format.
+    ///
+    /// # Returns
+    /// The formatted duration.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chronounit::duration::formatter::{DurationFormatter, DurationStyle};
+    ///
+    /// let df = DurationFormatter::new(DurationStyle::Compact);
+    /// assert_eq!(df.format_chrono(chrono::Duration::minutes(-5)), "-5m");
+    /// assert_eq!(df.format_chrono(chrono::Duration::milliseconds(1500)), "1s500ms");
+    /// ```
+    pub fn format_chrono(&self, duration: chrono::Duration) -> String {
+        let seconds = duration.num_seconds() as i128;
+        let nanos = seconds * NANOS_PER_SECOND as i128 + duration.subsec_nanos() as i128;
+
+        self.format_nanos(nanos < 0, nanos.unsigned_abs())
+    }
+
+    fn format_nanos(&self, negative: bool, nanos: u128) -> String {
+        let sign = if negative { "-" } else { "" };
+        match self.style {
+            DurationStyle::Fixed(unit, precision) => {
+                format!("{}{}", sign, self.fixed(nanos, unit, precision))
+            }
+            DurationStyle::Largest(count) => {
+                let components = self.with_zero_suppression(false).components(nanos);
+                let shown = components
+                    .iter()
+                    .take(count.max(1))
+                    .take_while(|&&(_, n)| n > 0 || !self.suppress_zero)
+                    .count()
+                    .max(1); // The leading component, or the zero one of a zero duration
+                let approximated = components[shown..].iter().any(|&(_, n)| n > 0);
+                let text = self.join(&components[..shown], " ");
+                format!("{}{}{}", sign, if approximated { "~" } else { "" }, text)
+            }
+            DurationStyle::Verbose => {
+                format!("{}{}", sign, self.join(&self.components(nanos), ", "))
+            }
+            DurationStyle::Compact => format!("{}{}", sign, self.join(&self.components(nanos), "")),
+        }
+    }
+
+    /// Splits `nanos` into the components shown, from the largest unit to the smallest,
+    /// starting at the largest non-zero component.
+    fn components(&self, nanos: u128) -> Vec<(TimeUnit, u128)> {
+        let mut remainder = nanos;
+        let mut components = Vec::new();
+        for unit in UNITS
+            .into_iter()
+            .filter(|unit| (self.smallest..=self.largest).contains(unit))
+        {
+            let scale = unit.scale() as u128;
+            let amount = remainder / scale;
+            remainder %= scale;
+            if amount > 0 || (!self.suppress_zero && !components.is_empty()) {
+                components.push((unit, amount));
+            }
+        }
+
+        if components.is_empty() {
+            let unit = TimeUnit::Seconds.clamp(self.smallest, self.largest);
+            components.push((unit, 0));
+        }
+
+        components
+    }
+
+    fn join(&self, components: &[(TimeUnit, u128)], separator: &str) -> String {
+        components
+            .iter()
+            .map(|&(unit, amount)| match self.style {
+                DurationStyle::Compact => format!("{}{}", amount, unit.abbreviation()),
+                _ => format!("{} {}", amount, unit_name(unit, amount)),
+            })
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    fn fixed(&self, nanos: u128, unit: TimeUnit, precision: usize) -> String {
+        let scale = unit.scale() as u128;
+        let (whole, remainder) = (nanos / scale, nanos % scale);
+        if precision == 0 {
+            return format!("{}{}", whole, unit.symbol());
+        }
+
+        // Long division, so that every digit is exact and nothing overflows.
+        let mut fraction = String::with_capacity(precision);
+        let mut remainder = remainder;
+        for _ in 0..precision {
+            remainder *= 10;
+            fraction.push(char::from(b'0' + (remainder / scale) as u8));
+            remainder %= scale;
+        }
+
+        format!("{}.{}{}", whole, fraction, unit.symbol())
+    }
+}
+
+/// The singular or plural lower case name of `unit`, e.g. `1 hour` or `2 hours`.
+fn unit_name(unit: TimeUnit, amount: u128) -> &'static str {
+    let name = unit.lower_case_name();
+    if amount == 1 {
+        &name[..name.len() - 1]
+    } else {
+        name
+    }
+}
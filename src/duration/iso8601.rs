@@ -0,0 +1,528 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::error::{ParseDurationError, ParseDurationErrorKind};
+use crate::TimeUnit;
+
+// ----------------------------------------------------------------
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const FRACTION_DIGITS: u32 = 9;
+
+// ----------------------------------------------------------------
+
+/// [`IsoDuration`] an ISO 8601 duration, such as `P3DT4H` or `PT1H30M15.5S`.
+///
+/// The components are kept as written, so that calendar components, which have no fixed
+/// length, survive a round trip. Each component may carry its own sign, as in `PT-6H3M`,
+/// a leading sign negates all of them.
+///
+/// Weeks and days are converted as exactly 7 and 1 times 24 hours, years and months cannot
+/// be converted and are reported as [`ParseDurationErrorKind::CalendarComponent`].
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration::iso8601::IsoDuration;
+///
+/// let iso: IsoDuration = "P3DT4H".parse().unwrap();
+/// assert_eq!(iso.days(), 3);
+/// assert_eq!(iso.hours(), 4);
+/// assert_eq!(iso.to_duration(), Ok(Duration::from_secs(3 * 86_400 + 4 * 3_600)));
+/// assert_eq!(iso.to_string(), "P3DT4H");
+///
+/// let iso: IsoDuration = "P1Y2M".parse().unwrap();
+/// assert!(iso.has_calendar_components());
+/// assert!(iso.to_duration().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IsoDuration {
+    years: i64,
+    months: i64,
+    weeks: i64,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    /// Fraction of the seconds, with the sign of the seconds.
+    nanos: i32,
+}
+
+impl IsoDuration {
+    /// Parses an ISO 8601 duration, `[±]P[nY][nM][nW][nD][T[nH][nM][n[.f]S]]`.
+    ///
+    /// Designators are case-insensitive, only the seconds may have a fraction, with up to
+    /// nine significant digits, and either `.` or `,` as the decimal sign.
+    ///
+    /// # Arguments
+    /// `value` - The ISO 8601 duration, e.g. `PT1H30M`.
+    ///
+    /// # Returns
+    /// The parsed [`IsoDuration`], or a [`ParseDurationError`] pointing at the offending position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chronounit::duration::iso8601::IsoDuration;
+    /// use chronounit::error::ParseDurationErrorKind;
+    ///
+    /// let iso = IsoDuration::parse("-PT1.5S").unwrap();
+    /// assert_eq!(iso.seconds(), -1);
+    /// assert_eq!(iso.nanos(), -500_000_000);
+    ///
+    /// let err = IsoDuration::parse("PT1H30").unwrap_err();
+    /// assert_eq!(err.kind(), &ParseDurationErrorKind::MissingUnit);
+    /// assert_eq!(err.position(), 6);
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, ParseDurationError> {
+        Parser::new(value).parse()
+    }
+
+    /// Creates an [`IsoDuration`] of hours, minutes and seconds from a [`Duration`],
+    /// the form used by `java.time.Duration`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::duration::iso8601::IsoDuration;
+    ///
+    /// let iso = IsoDuration::from_duration(Duration::from_millis(93_784_500));
+    /// assert_eq!(iso.to_string(), "PT26H3M4.5S");
+    /// ```
+    pub fn from_duration(duration: Duration) -> Self {
+        Self::from_nanos(duration.as_nanos() as i128)
+    }
+
+    /// Creates an [`IsoDuration`] of hours, minutes and seconds from a [`chrono::Duration`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chronounit::duration::iso8601::IsoDuration;
+    ///
+    /// let iso = IsoDuration::from_chrono_duration(chrono::Duration::minutes(-90));
+    /// assert_eq!(iso.to_string(), "-PT1H30M");
+    /// ```
+    pub fn from_chrono_duration(duration: chrono::Duration) -> Self {
+        let nanos =
+            duration.num_seconds() as i128 * NANOS_PER_SECOND + duration.subsec_nanos() as i128;
+        Self::from_nanos(nanos)
+    }
+
+    fn from_nanos(nanos: i128) -> Self {
+        let seconds = nanos / NANOS_PER_SECOND;
+        let (hour, minute) = (
+            TimeUnit::SECONDS_PER_HOUR as i128,
+            TimeUnit::SECONDS_PER_MINUTE as i128,
+        );
+        IsoDuration {
+            hours: (seconds / hour) as i64,
+            minutes: (seconds % hour / minute) as i64,
+            seconds: (seconds % minute) as i64,
+            nanos: (nanos % NANOS_PER_SECOND) as i32,
+            ..IsoDuration::default()
+        }
+    }
+
+    /// The years component.
+    pub fn years(&self) -> i64 {
+        self.years
+    }
+
+    /// The months component.
+    pub fn months(&self) -> i64 {
+        self.months
+    }
+
+    /// The weeks component.
+    pub fn weeks(&self) -> i64 {
+        self.weeks
+    }
+
+    /// The days component.
+    pub fn days(&self) -> i64 {
+        self.days
+    }
+
+    /// The hours component.
+    pub fn hours(&self) -> i64 {
+        self.hours
+    }
+
+    /// The minutes component.
+    pub fn minutes(&self) -> i64 {
+        self.minutes
+    }
+
+    /// The whole seconds component.
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// The fraction of the seconds component in nanoseconds, with the sign of the seconds.
+    pub fn nanos(&self) -> i32 {
+        self.nanos
+    }
+
+    /// Whether the years or months component is non-zero, which have no fixed length.
+    pub fn has_calendar_components(&self) -> bool {
+        self.years != 0 || self.months != 0
+    }
+
+    /// Converts this duration into a [`Duration`].
+    ///
+    /// # Returns
+    /// The [`Duration`], or a [`ParseDurationError`] of kind
+    /// [`CalendarComponent`](ParseDurationErrorKind::CalendarComponent),
+    /// [`Negative`](ParseDurationErrorKind::Negative) or [`Overflow`](ParseDurationErrorKind::Overflow).
+    pub fn to_duration(&self) -> Result<Duration, ParseDurationError> {
+        let nanos = self.total_nanos()?;
+        if nanos < 0 {
+            return Err(ParseDurationError::new(ParseDurationErrorKind::Negative, 0));
+        }
+
+        u64::try_from(nanos / NANOS_PER_SECOND)
+            .map(|seconds| Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+            .map_err(|_| ParseDurationError::new(ParseDurationErrorKind::Overflow, 0))
+    }
+
+    /// Converts this duration into a [`chrono::Duration`].
+    ///
+    /// # Returns
+    /// The [`chrono::Duration`], or a [`ParseDurationError`] of kind
+    /// [`CalendarComponent`](ParseDurationErrorKind::CalendarComponent) or
+    /// [`Overflow`](ParseDurationErrorKind::Overflow).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chronounit::duration::iso8601::IsoDuration;
+    ///
+    /// let iso = IsoDuration::parse("PT-6H3M").unwrap();
+    /// assert_eq!(iso.to_chrono_duration(), Ok(chrono::Duration::minutes(-357)));
+    /// ```
+    pub fn to_chrono_duration(&self) -> Result<chrono::Duration, ParseDurationError> {
+        let nanos = self.total_nanos()?;
+        i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+            .ok()
+            .and_then(|seconds| {
+                chrono::Duration::new(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+            })
+            .ok_or_else(|| ParseDurationError::new(ParseDurationErrorKind::Overflow, 0))
+    }
+
+    fn total_nanos(&self) -> Result<i128, ParseDurationError> {
+        if self.years != 0 {
+            return Err(ParseDurationError::new(
+                ParseDurationErrorKind::CalendarComponent('Y'),
+                0,
+            ));
+        }
+        if self.months != 0 {
+            return Err(ParseDurationError::new(
+                ParseDurationErrorKind::CalendarComponent('M'),
+                0,
+            ));
+        }
+
+        // Every component fits in an `i64`, the sum of them can't overflow an `i128`.
+        let seconds = self.weeks as i128 * TimeUnit::SECONDS_PER_WEEK as i128
+            + self.days as i128 * TimeUnit::SECONDS_PER_DAY as i128
+            + self.hours as i128 * TimeUnit::SECONDS_PER_HOUR as i128
+            + self.minutes as i128 * TimeUnit::SECONDS_PER_MINUTE as i128
+            + self.seconds as i128;
+
+        Ok(seconds * NANOS_PER_SECOND + self.nanos as i128)
+    }
+
+    fn components(&self) -> [i64; 7] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ]
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    /// override
+    ///
+    /// Writes a leading `-` when every non-zero component is negative, and a sign per
+    /// negative component otherwise. A zero duration is written as `PT0S`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let negative = (self.components().iter().any(|&n| n < 0) || self.nanos < 0)
+            && self.components().iter().all(|&n| n <= 0)
+            && self.nanos <= 0;
+        if negative {
+            f.write_str("-")?;
+        }
+
+        let component = |n: i64| {
+            if negative {
+                n.unsigned_abs() as i128
+            } else {
+                n as i128
+            }
+        };
+        f.write_str("P")?;
+        for (amount, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if amount != 0 {
+                write!(f, "{}{}", component(amount), designator)?;
+            }
+        }
+
+        let time = self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.nanos != 0;
+        if !time {
+            return if self.components().iter().all(|&n| n == 0) {
+                f.write_str("T0S")
+            } else {
+                Ok(())
+            };
+        }
+
+        f.write_str("T")?;
+        for (amount, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+            if amount != 0 {
+                write!(f, "{}{}", component(amount), designator)?;
+            }
+        }
+        if self.seconds != 0 || self.nanos != 0 {
+            if !negative && (self.seconds < 0 || self.nanos < 0) {
+                f.write_str("-")?;
+            }
+            write!(f, "{}", self.seconds.unsigned_abs())?;
+            if self.nanos != 0 {
+                let fraction = format!("{:09}", self.nanos.unsigned_abs());
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+            f.write_str("S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = ParseDurationError;
+
+    /// override
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        IsoDuration::parse(value)
+    }
+}
+
+impl From<Duration> for IsoDuration {
+    /// override
+    fn from(duration: Duration) -> Self {
+        IsoDuration::from_duration(duration)
+    }
+}
+
+impl From<chrono::Duration> for IsoDuration {
+    /// override
+    fn from(duration: chrono::Duration) -> Self {
+        IsoDuration::from_chrono_duration(duration)
+    }
+}
+
+// ----------------------------------------------------------------
+
+fn error<T>(kind: ParseDurationErrorKind, position: usize) -> Result<T, ParseDurationError> {
+    Err(ParseDurationError::new(kind, position))
+}
+
+struct Parser<'a> {
+    value: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(value: &'a str) -> Self {
+        Parser { value, position: 0 }
+    }
+
+    fn parse(&mut self) -> Result<IsoDuration, ParseDurationError> {
+        if self.value.is_empty() {
+            return error(ParseDurationErrorKind::Empty, 0);
+        }
+
+        let negative = self.sign();
+        match self.peek() {
+            Some('P') | Some('p') => self.position += 1,
+            Some(token) => {
+                return error(
+                    ParseDurationErrorKind::UnexpectedToken(token),
+                    self.position,
+                )
+            }
+            None => return error(ParseDurationErrorKind::Empty, self.position),
+        }
+
+        let mut iso = IsoDuration::default();
+        // The designators of the date part, then of the time part, in their required order.
+        let date = ['Y', 'M', 'W', 'D'];
+        let time = ['H', 'M', 'S'];
+        let (mut designators, mut in_time, mut components) = (&date[..], false, 0);
+        loop {
+            match self.peek() {
+                None if components == 0 => {
+                    return error(ParseDurationErrorKind::Empty, self.position)
+                }
+                None => break,
+                Some('T') | Some('t') if !in_time => {
+                    self.position += 1;
+                    (designators, in_time, components) = (&time[..], true, 0);
+                    continue;
+                }
+                Some(_) => {}
+            }
+
+            let start = self.position;
+            let (amount, nanos) = self.number()?;
+            let designator = match self.peek() {
+                Some(c) => c.to_ascii_uppercase(),
+                None => return error(ParseDurationErrorKind::MissingUnit, self.position),
+            };
+            let index = match designators.iter().position(|&d| d == designator) {
+                Some(index) => index,
+                None => {
+                    return error(
+                        ParseDurationErrorKind::UnexpectedToken(self.peek().unwrap()),
+                        self.position,
+                    )
+                }
+            };
+            if let Some((_, dot)) = nanos.filter(|_| !(in_time && designator == 'S')) {
+                let token = self.value[dot..].chars().next().unwrap();
+                return error(ParseDurationErrorKind::UnexpectedToken(token), dot);
+            }
+
+            let amount = if negative {
+                amount.checked_neg()
+            } else {
+                Some(amount)
+            }
+            .ok_or_else(|| ParseDurationError::new(ParseDurationErrorKind::Overflow, start))?;
+            let nanos = nanos.map_or(0, |(nanos, _)| nanos);
+            match (in_time, designator) {
+                (false, 'Y') => iso.years = amount,
+                (false, 'M') => iso.months = amount,
+                (false, 'W') => iso.weeks = amount,
+                (false, _) => iso.days = amount,
+                (true, 'H') => iso.hours = amount,
+                (true, 'M') => iso.minutes = amount,
+                (true, _) => {
+                    iso.seconds = amount;
+                    iso.nanos = if negative { -nanos } else { nanos };
+                }
+            }
+
+            self.position += 1;
+            designators = &designators[index + 1..];
+            components += 1;
+        }
+
+        Ok(iso)
+    }
+
+    /// Parses `[±]digits[(.|,)digits]`, returns the signed integer part and, if any,
+    /// the signed fraction in nanoseconds with the position of the decimal sign.
+    fn number(&mut self) -> Result<(i64, Option<(i32, usize)>), ParseDurationError> {
+        let start = self.position;
+        let negative = self.sign();
+        let digits = self.digits();
+        if digits.is_empty() {
+            return match self.peek() {
+                Some(token) => error(
+                    ParseDurationErrorKind::UnexpectedToken(token),
+                    self.position,
+                ),
+                None => error(ParseDurationErrorKind::Empty, self.position),
+            };
+        }
+
+        let overflow = || ParseDurationError::new(ParseDurationErrorKind::Overflow, start);
+        let amount: i64 = if negative {
+            format!("-{}", digits).parse().map_err(|_| overflow())?
+        } else {
+            digits.parse().map_err(|_| overflow())?
+        };
+
+        let nanos = match self.peek() {
+            Some('.') | Some(',') => {
+                let dot = self.position;
+                self.position += 1;
+                let fraction = self.digits();
+                if fraction.is_empty() {
+                    let token = self.value[dot..].chars().next().unwrap();
+                    return error(ParseDurationErrorKind::UnexpectedToken(token), dot);
+                }
+
+                let nanos = fraction
+                    .chars()
+                    .chain(std::iter::repeat('0'))
+                    .take(FRACTION_DIGITS as usize)
+                    .fold(0, |nanos, c| nanos * 10 + c.to_digit(10).unwrap() as i32);
+                Some((if negative { -nanos } else { nanos }, dot))
+            }
+            _ => None,
+        };
+
+        Ok((amount, nanos))
+    }
+
+    /// Consumes an optional sign, returns whether it is `-`.
+    fn sign(&mut self) -> bool {
+        match self.peek() {
+            Some('-') => {
+                self.position += 1;
+                true
+            }
+            Some('+') => {
+                self.position += 1;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn digits(&mut self) -> &'a str {
+        let start = self.position;
+        let length = self.value[start..]
+            .bytes()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        self.position += length;
+
+        &self.value[start..self.position]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.value[self.position..].chars().next()
+    }
+}
@@ -0,0 +1,355 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::iter::Peekable;
+use std::str::CharIndices;
+use std::time::Duration;
+
+use crate::error::{ParseDurationError, ParseDurationErrorKind};
+use crate::{TimeUnit, UnitAliasMode};
+
+// ----------------------------------------------------------------
+
+/// The number of fractional digits which still contribute to a nanosecond amount,
+/// even when the unit is [`TimeUnit::Weeks`], the largest one.
+const MAX_FRACTION_DIGITS: u32 = 18;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+// ----------------------------------------------------------------
+
+/// [`DurationParser`] parses human duration strings, such as `1h 30m 15s 250ms`.
+///
+/// A duration is an optional leading sign followed by one or more components.
+/// Each component is a decimal number and a unit, the unit may be any alias
+/// accepted by [`TimeUnit::parse_alias`]. Components may be written back to back
+/// (`1h30m`), or separated by whitespace, a comma or the word `and`
+/// (`2 days, 3 hours and 5 minutes`). A bare `0` is accepted without unit.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use chronounit::duration::parser::DurationParser;
+/// use chronounit::UnitAliasMode;
+///
+/// let parser = DurationParser::new();
+/// assert_eq!(parser.parse("1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parser.parse("1.5s"), Ok(Duration::from_millis(1500)));
+///
+/// let strict = DurationParser::new().with_alias_mode(UnitAliasMode::Strict);
+/// assert!(strict.parse("5m").is_err());
+/// assert_eq!(strict.parse("5min"), Ok(Duration::from_secs(300)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DurationParser {
+    mode: UnitAliasMode,
+}
+
+impl DurationParser {
+    /// Creates a [`DurationParser`] which accepts all unit aliases, see [`UnitAliasMode::Lenient`].
+    pub fn new() -> Self {
+        DurationParser {
+            mode: UnitAliasMode::Lenient,
+        }
+    }
+
+    /// Returns a copy of this parser which resolves units with the given [`UnitAliasMode`].
+    pub fn with_alias_mode(self, mode: UnitAliasMode) -> Self {
+        DurationParser { mode }
+    }
+
+    /// The [`UnitAliasMode`] used to resolve units.
+    pub fn alias_mode(&self) -> UnitAliasMode {
+        self.mode
+    }
+
+    /// Parses `value` into a [`Duration`].
+    ///
+    /// # Arguments
+    /// `value` - The duration string, e.g. `1h 30m 15.5s`.
+    ///
+    /// # Returns
+    /// The parsed [`Duration`], or a [`ParseDurationError`] if `value` is malformed,
+    /// negative or larger than [`Duration::MAX`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::duration::parser::DurationParser;
+    /// use chronounit::error::ParseDurationErrorKind;
+    ///
+    /// let parser = DurationParser::new();
+    /// assert_eq!(parser.parse("2 days 3 hours"), Ok(Duration::from_secs(183600)));
+    /// assert_eq!(parser.parse("-5m").unwrap_err().kind(), &ParseDurationErrorKind::Negative);
+    /// ```
+    pub fn parse(&self, value: &str) -> Result<Duration, ParseDurationError> {
+        let nanos = self.parse_nanos(value)?;
+        if nanos < 0 {
+            return Err(ParseDurationError::new(
+                ParseDurationErrorKind::Negative,
+                sign_position(value),
+            ));
+        }
+
+        u64::try_from(nanos / NANOS_PER_SECOND)
+            .map(|seconds| Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+            .map_err(|_| ParseDurationError::new(ParseDurationErrorKind::Overflow, 0))
+    }
+
+    /// Parses `value` into a [`chrono::Duration`], which may be negative.
+    ///
+    /// # Arguments
+    /// `value` - The duration string, e.g. `-5m`.
+    ///
+    /// # Returns
+    /// The parsed [`chrono::Duration`], or a [`ParseDurationError`] if `value` is malformed
+    /// or out of the range of [`chrono::Duration`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chronounit::duration::parser::DurationParser;
+    ///
+    /// let parser = DurationParser::new();
+    /// assert_eq!(parser.parse_chrono("-5m"), Ok(chrono::Duration::minutes(-5)));
+    /// assert_eq!(parser.parse_chrono("1h 30m"), Ok(chrono::Duration::minutes(90)));
+    /// ```
+    pub fn parse_chrono(&self, value: &str) -> Result<chrono::Duration, ParseDurationError> {
+        let nanos = self.parse_nanos(value)?;
+        i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+            .ok()
+            .and_then(|seconds| {
+                chrono::Duration::new(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+            })
+            .ok_or_else(|| ParseDurationError::new(ParseDurationErrorKind::Overflow, 0))
+    }
+
+    /// Parses `value` into a signed amount of nanoseconds.
+    ///
+    /// Fractions below one nanosecond are truncated.
+    ///
+    /// # Arguments
+    /// `value` - The duration string, e.g. `1h 30m 15s 250ms`.
+    ///
+    /// # Returns
+    /// The amount of nanoseconds, or a [`ParseDurationError`] pointing at the offending position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chronounit::duration::parser::DurationParser;
+    /// use chronounit::error::ParseDurationErrorKind;
+    ///
+    /// let parser = DurationParser::new();
+    /// assert_eq!(parser.parse_nanos("1.5us"), Ok(1_500));
+    /// assert_eq!(parser.parse_nanos("-1s 500ms"), Ok(-1_500_000_000));
+    ///
+    /// let error = parser.parse_nanos("1h 30").unwrap_err();
+    /// assert_eq!(error.kind(), &ParseDurationErrorKind::MissingUnit);
+    /// assert_eq!(error.position(), 5);
+    /// ```
+    pub fn parse_nanos(&self, value: &str) -> Result<i128, ParseDurationError> {
+        Cursor::new(value, self.mode).parse()
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The position reported for [`ParseDurationErrorKind::Negative`], the leading sign.
+fn sign_position(value: &str) -> usize {
+    value.len() - value.trim_start().len()
+}
+
+fn error<T>(kind: ParseDurationErrorKind, position: usize) -> Result<T, ParseDurationError> {
+    Err(ParseDurationError::new(kind, position))
+}
+
+struct Cursor<'a> {
+    value: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    mode: UnitAliasMode,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(value: &'a str, mode: UnitAliasMode) -> Self {
+        Cursor {
+            value,
+            chars: value.char_indices().peekable(),
+            mode,
+        }
+    }
+
+    fn parse(&mut self) -> Result<i128, ParseDurationError> {
+        self.skip_whitespace();
+        let negative = match self.peek() {
+            Some('-') => {
+                self.chars.next();
+                true
+            }
+            Some('+') => {
+                self.chars.next();
+                false
+            }
+            Some(_) => false,
+            None => return error(ParseDurationErrorKind::Empty, 0),
+        };
+        self.skip_whitespace();
+
+        let mut total: i128 = 0;
+        loop {
+            let start = self.position();
+            let nanos = match self.component()? {
+                Some(nanos) => nanos,
+                None => return error(ParseDurationErrorKind::Empty, start),
+            };
+            total = total
+                .checked_add(nanos)
+                .ok_or_else(|| ParseDurationError::new(ParseDurationErrorKind::Overflow, start))?;
+
+            if !self.separator()? {
+                break;
+            }
+        }
+
+        Ok(if negative { -total } else { total })
+    }
+
+    /// Parses `number unit`, returns `None` if the input is exhausted.
+    fn component(&mut self) -> Result<Option<i128>, ParseDurationError> {
+        let start = self.position();
+        let (integer, fraction, digits) = match self.peek() {
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(start)?,
+            Some(token) => return error(ParseDurationErrorKind::UnexpectedToken(token), start),
+            None => return Ok(None),
+        };
+
+        self.skip_whitespace();
+        let unit_start = self.position();
+        let unit = self.take_while(|c| c.is_alphabetic());
+        if unit.is_empty() {
+            // `0` is the same in every unit.
+            if integer == 0 && fraction == 0 && self.peek().is_none() {
+                return Ok(Some(0));
+            }
+            return match self.peek() {
+                Some(token) if !token.is_whitespace() && token != ',' => {
+                    error(ParseDurationErrorKind::UnexpectedToken(token), unit_start)
+                }
+                _ => error(ParseDurationErrorKind::MissingUnit, unit_start),
+            };
+        }
+
+        let unit = TimeUnit::parse_alias(unit, self.mode).map_err(|err| {
+            let kind = if err.is_ambiguous() {
+                ParseDurationErrorKind::AmbiguousUnit(unit.to_string())
+            } else {
+                ParseDurationErrorKind::UnknownUnit(unit.to_string())
+            };
+            ParseDurationError::new(kind, unit_start)
+        })?;
+
+        let scale = unit.scale() as i128;
+        let overflow = || ParseDurationError::new(ParseDurationErrorKind::Overflow, start);
+        let whole = integer.checked_mul(scale).ok_or_else(overflow)?;
+        let part = fraction * scale / 10i128.pow(digits);
+
+        whole.checked_add(part).map(Some).ok_or_else(overflow)
+    }
+
+    /// Parses `digits[.digits]` or `.digits`, returns the integer part, the fraction
+    /// and the number of fraction digits.
+    fn number(&mut self, start: usize) -> Result<(i128, i128, u32), ParseDurationError> {
+        let integer_digits = self.take_while(|c| c.is_ascii_digit());
+        let mut integer: i128 = 0;
+        for c in integer_digits.chars() {
+            integer = integer
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(c.to_digit(10).unwrap() as i128))
+                .ok_or_else(|| ParseDurationError::new(ParseDurationErrorKind::Overflow, start))?;
+        }
+
+        let (mut fraction, mut digits) = (0i128, 0u32);
+        if self.peek() == Some('.') {
+            let dot = self.position();
+            self.chars.next();
+            let fraction_digits = self.take_while(|c| c.is_ascii_digit());
+            if integer_digits.is_empty() && fraction_digits.is_empty() {
+                return error(ParseDurationErrorKind::UnexpectedToken('.'), dot);
+            }
+            for c in fraction_digits.chars().take(MAX_FRACTION_DIGITS as usize) {
+                fraction = fraction * 10 + c.to_digit(10).unwrap() as i128;
+                digits += 1;
+            }
+        }
+
+        Ok((integer, fraction, digits))
+    }
+
+    /// Consumes whitespace, a comma or the word `and` between two components,
+    /// returns `false` at the end of the input.
+    fn separator(&mut self) -> Result<bool, ParseDurationError> {
+        self.skip_whitespace();
+        let mut delimiter = None;
+        if self.peek() == Some(',') {
+            delimiter = Some((self.position(), ','));
+            self.chars.next();
+            self.skip_whitespace();
+        }
+        if self.rest().starts_with("and")
+            && self.rest()[3..].starts_with(|c: char| c.is_whitespace() || c.is_ascii_digit())
+        {
+            delimiter = Some((self.position(), 'a'));
+            self.take_while(|c| c.is_alphabetic());
+            self.skip_whitespace();
+        }
+
+        match (self.peek(), delimiter) {
+            (Some(_), _) => Ok(true),
+            (None, Some((position, token))) => {
+                error(ParseDurationErrorKind::UnexpectedToken(token), position)
+            }
+            (None, None) => Ok(false),
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position();
+        while self.peek().is_some_and(&predicate) {
+            self.chars.next();
+        }
+
+        &self.value[start..self.position()]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars
+            .peek()
+            .map_or(self.value.len(), |&(position, _)| position)
+    }
+
+    fn rest(&mut self) -> &'a str {
+        let position = self.position();
+        &self.value[position..]
+    }
+}
@@ -0,0 +1,642 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::error::Error;
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+
+use crate::formatter::parser::LenientMatch;
+use crate::formatter::pattern::DateTimePattern;
+use crate::formatter::zone::Zone;
+use crate::TimeUnit;
+
+// ----------------------------------------------------------------
+
+/// [`OverflowError`] a time amount could not be represented in the requested target.
+///
+/// Returned by the `checked_*` family of [`TimeUnit`] conversions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverflowError {
+    unit: TimeUnit,
+    amount: i128,
+    target: &'static str,
+}
+
+impl OverflowError {
+    pub(crate) fn new(unit: TimeUnit, amount: i128, target: &'static str) -> Self {
+        OverflowError {
+            unit,
+            amount,
+            target,
+        }
+    }
+
+    /// The [`TimeUnit`] of the original amount.
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// The original amount which overflowed.
+    pub fn amount(&self) -> i128 {
+        self.amount
+    }
+
+    /// The name of the target representation, e.g. `nanoseconds` or `Duration`.
+    pub fn target(&self) -> &'static str {
+        self.target
+    }
+}
+
+impl fmt::Display for OverflowError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "overflow converting {} {:?} to {}",
+            self.amount, self.unit, self.target
+        )
+    }
+}
+
+impl Error for OverflowError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseTimeUnitError`] a string could not be parsed into a [`TimeUnit`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseTimeUnitError {
+    value: String,
+    ambiguous: bool,
+}
+
+impl ParseTimeUnitError {
+    pub(crate) fn new(value: &str) -> Self {
+        ParseTimeUnitError {
+            value: value.to_string(),
+            ambiguous: false,
+        }
+    }
+
+    pub(crate) fn ambiguous(value: &str) -> Self {
+        ParseTimeUnitError {
+            value: value.to_string(),
+            ambiguous: true,
+        }
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Whether the string is a known but ambiguous alias, rejected by
+    /// [`UnitAliasMode::Strict`](crate::UnitAliasMode::Strict).
+    pub fn is_ambiguous(&self) -> bool {
+        self.ambiguous
+    }
+}
+
+impl fmt::Display for ParseTimeUnitError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ambiguous {
+            write!(f, "ambiguous time unit: `{}`", self.value)
+        } else {
+            write!(f, "invalid time unit: `{}`", self.value)
+        }
+    }
+}
+
+impl Error for ParseTimeUnitError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseCalendarUnitError`] a string could not be parsed into a
+/// [`CalendarUnit`](crate::calendar::CalendarUnit).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCalendarUnitError {
+    value: String,
+}
+
+impl ParseCalendarUnitError {
+    pub(crate) fn new(value: &str) -> Self {
+        ParseCalendarUnitError {
+            value: value.to_string(),
+        }
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseCalendarUnitError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid calendar unit: `{}`", self.value)
+    }
+}
+
+impl Error for ParseCalendarUnitError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseDateTimePatternError`] a string could not be parsed into a
+/// [`DateTimePattern`](crate::formatter::pattern::DateTimePattern).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDateTimePatternError {
+    value: String,
+}
+
+impl ParseDateTimePatternError {
+    pub(crate) fn new(value: &str) -> Self {
+        ParseDateTimePatternError {
+            value: value.to_string(),
+        }
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseDateTimePatternError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date-time pattern: `{}`", self.value)
+    }
+}
+
+impl Error for ParseDateTimePatternError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseDurationErrorKind`] the reason a duration string could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseDurationErrorKind {
+    /// The input, or a part of it which requires at least one component, is empty.
+    Empty,
+    /// An unexpected character was found.
+    UnexpectedToken(char),
+    /// A number is not followed by a unit, e.g. `15`.
+    MissingUnit,
+    /// A unit is not recognised, e.g. `15 fortnights`.
+    UnknownUnit(String),
+    /// A unit is ambiguous and was rejected by the strict mode, e.g. `15m`.
+    AmbiguousUnit(String),
+    /// The duration does not fit in the target representation.
+    Overflow,
+    /// The duration is negative, but the target representation is unsigned.
+    Negative,
+    /// An ISO 8601 calendar component, `Y` or `M`, has no fixed length and cannot be
+    /// converted exactly.
+    CalendarComponent(char),
+}
+
+/// [`ParseDurationError`] a duration string could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDurationError {
+    kind: ParseDurationErrorKind,
+    position: usize,
+}
+
+impl ParseDurationError {
+    pub(crate) fn new(kind: ParseDurationErrorKind, position: usize) -> Self {
+        ParseDurationError { kind, position }
+    }
+
+    /// The reason of the error.
+    pub fn kind(&self) -> &ParseDurationErrorKind {
+        &self.kind
+    }
+
+    /// The byte offset in the input at which the error was detected.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseDurationErrorKind::Empty => f.write_str("empty duration"),
+            ParseDurationErrorKind::UnexpectedToken(token) => {
+                write!(f, "unexpected `{}` at position {}", token, self.position)
+            }
+            ParseDurationErrorKind::MissingUnit => {
+                write!(f, "missing unit at position {}", self.position)
+            }
+            ParseDurationErrorKind::UnknownUnit(unit) => {
+                write!(f, "unknown unit `{}` at position {}", unit, self.position)
+            }
+            ParseDurationErrorKind::AmbiguousUnit(unit) => {
+                write!(f, "ambiguous unit `{}` at position {}", unit, self.position)
+            }
+            ParseDurationErrorKind::Overflow => {
+                write!(f, "duration overflow at position {}", self.position)
+            }
+            ParseDurationErrorKind::Negative => f.write_str("negative duration"),
+            ParseDurationErrorKind::CalendarComponent(designator) => write!(
+                f,
+                "calendar component `{}` cannot be converted exactly",
+                designator
+            ),
+        }
+    }
+}
+
+impl Error for ParseDurationError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseJavaPatternErrorKind`] the reason a Java pattern could not be translated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseJavaPatternErrorKind {
+    /// A pattern letter, or its count, has no equivalent, e.g. `G` or `MMMMM`.
+    UnsupportedLetter(char, usize),
+    /// A character reserved for future use, such as `#`, `{` or `}`.
+    ReservedCharacter(char),
+    /// A quoted literal is not closed.
+    UnterminatedQuote,
+    /// An optional section `[...]`, which has no `chrono` equivalent.
+    OptionalSection,
+}
+
+/// [`ParseJavaPatternError`] a Java `DateTimeFormatter` pattern could not be translated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseJavaPatternError {
+    pattern: String,
+    kind: ParseJavaPatternErrorKind,
+    position: usize,
+}
+
+impl ParseJavaPatternError {
+    pub(crate) fn new(pattern: &str, kind: ParseJavaPatternErrorKind, position: usize) -> Self {
+        ParseJavaPatternError {
+            pattern: pattern.to_string(),
+            kind,
+            position,
+        }
+    }
+
+    /// The pattern which could not be translated.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The reason of the error.
+    pub fn kind(&self) -> &ParseJavaPatternErrorKind {
+        &self.kind
+    }
+
+    /// The byte offset in the pattern at which the error was detected.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseJavaPatternError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseJavaPatternErrorKind::UnsupportedLetter(letter, count) => write!(
+                f,
+                "unsupported pattern letter `{}` at position {} in `{}`",
+                letter.to_string().repeat(*count),
+                self.position,
+                self.pattern
+            ),
+            ParseJavaPatternErrorKind::ReservedCharacter(c) => write!(
+                f,
+                "reserved character `{}` at position {} in `{}`",
+                c, self.position, self.pattern
+            ),
+            ParseJavaPatternErrorKind::UnterminatedQuote => write!(
+                f,
+                "unterminated quote at position {} in `{}`",
+                self.position, self.pattern
+            ),
+            ParseJavaPatternErrorKind::OptionalSection => write!(
+                f,
+                "unsupported optional section at position {} in `{}`",
+                self.position, self.pattern
+            ),
+        }
+    }
+}
+
+impl Error for ParseJavaPatternError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseDateTimeErrorKind`] the reason a value could not be parsed with a
+/// [`DateTimePattern`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDateTimeErrorKind {
+    /// The value does not match the pattern, e.g. a wrong separator or trailing input.
+    Mismatch,
+    /// A field is out of range, or the fields are inconsistent, e.g. `2024-02-30`.
+    OutOfRange,
+    /// The pattern does not carry enough fields to build a date, e.g. [`DateTimePattern::HhMmSs`].
+    MissingDate,
+    /// The pattern does not carry enough fields to build a time, e.g. [`DateTimePattern::AmPm`].
+    MissingTime,
+    /// The value has a zone name but no offset, which cannot be converted to UTC,
+    /// e.g. with [`DateTimePattern::YyyyMmDdHhMmSsZone`].
+    MissingOffset,
+    /// The value has no offset and, read as a local time in the zone of the formatter,
+    /// is ambiguous or does not exist.
+    LocalTime(LocalTimeErrorKind),
+}
+
+/// [`ParseDateTimeError`] a value could not be parsed with a [`DateTimePattern`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDateTimeError {
+    value: String,
+    pattern: DateTimePattern,
+    kind: ParseDateTimeErrorKind,
+    source: Option<chrono::ParseError>,
+}
+
+impl ParseDateTimeError {
+    pub(crate) fn new(
+        value: &str,
+        pattern: DateTimePattern,
+        kind: ParseDateTimeErrorKind,
+        source: Option<chrono::ParseError>,
+    ) -> Self {
+        ParseDateTimeError {
+            value: value.to_string(),
+            pattern,
+            kind,
+            source,
+        }
+    }
+
+    /// The value which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The pattern the value was parsed with.
+    pub fn pattern(&self) -> DateTimePattern {
+        self.pattern.clone()
+    }
+
+    /// The reason of the error.
+    pub fn kind(&self) -> ParseDateTimeErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ParseDateTimeError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.kind {
+            ParseDateTimeErrorKind::Mismatch => "input does not match the pattern",
+            ParseDateTimeErrorKind::OutOfRange => "value out of range",
+            ParseDateTimeErrorKind::MissingDate => "the pattern has no complete date",
+            ParseDateTimeErrorKind::MissingTime => "the pattern has no complete time",
+            ParseDateTimeErrorKind::MissingOffset => "a zone name cannot be converted to UTC",
+            ParseDateTimeErrorKind::LocalTime(LocalTimeErrorKind::Ambiguous(..)) => {
+                "the local time is ambiguous in the zone"
+            }
+            ParseDateTimeErrorKind::LocalTime(LocalTimeErrorKind::Nonexistent) => {
+                "the local time does not exist in the zone"
+            }
+        };
+
+        write!(
+            f,
+            "cannot parse `{}` with pattern `{}`: {}",
+            self.value, self.pattern, reason
+        )
+    }
+}
+
+impl Error for ParseDateTimeError {
+    /// override
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|error| error as &(dyn Error + 'static))
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// [`LenientParseErrorKind`] the reason a [`LenientParser`](crate::formatter::parser::LenientParser)
+/// could not parse a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LenientParseErrorKind {
+    /// None of the patterns matches.
+    NoMatch,
+    /// The day and month of the value could be read either way, see
+    /// [`AmbiguityPolicy::Reject`](crate::formatter::parser::AmbiguityPolicy::Reject).
+    Ambiguous(LenientMatch),
+}
+
+/// [`LenientParseError`] a value could not be parsed by a
+/// [`LenientParser`](crate::formatter::parser::LenientParser).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LenientParseError {
+    value: String,
+    kind: LenientParseErrorKind,
+    errors: Vec<ParseDateTimeError>,
+}
+
+impl LenientParseError {
+    pub(crate) fn new(
+        value: &str,
+        kind: LenientParseErrorKind,
+        errors: Vec<ParseDateTimeError>,
+    ) -> Self {
+        LenientParseError {
+            value: value.to_string(),
+            kind,
+            errors,
+        }
+    }
+
+    /// The value which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The reason of the error.
+    pub fn kind(&self) -> LenientParseErrorKind {
+        self.kind.clone()
+    }
+
+    /// The errors of the patterns which did not match, in the order they were tried.
+    pub fn errors(&self) -> &[ParseDateTimeError] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for LenientParseError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LenientParseErrorKind::NoMatch => {
+                write!(f, "no date-time pattern matches `{}`", self.value)
+            }
+            LenientParseErrorKind::Ambiguous(matched) => write!(
+                f,
+                "ambiguous date-time `{}`: `{}` with pattern `{}`, or `{}`",
+                self.value,
+                matched.value(),
+                matched.pattern(),
+                matched
+                    .alternative()
+                    .map_or_else(String::new, |alternative| alternative.to_string())
+            ),
+        }
+    }
+}
+
+impl Error for LenientParseError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseZoneError`] a string could not be parsed into a [`Zone`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseZoneError {
+    value: String,
+}
+
+impl ParseZoneError {
+    pub(crate) fn new(value: &str) -> Self {
+        ParseZoneError {
+            value: value.to_string(),
+        }
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseZoneError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid time zone: `{}`", self.value)
+    }
+}
+
+impl Error for ParseZoneError {}
+
+// ----------------------------------------------------------------
+
+/// [`ParseLocaleError`] a string could not be parsed into a [`Locale`](crate::formatter::locale::Locale).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLocaleError {
+    value: String,
+}
+
+impl ParseLocaleError {
+    pub(crate) fn new(value: &str) -> Self {
+        ParseLocaleError {
+            value: value.to_string(),
+        }
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseLocaleError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported locale: `{}`", self.value)
+    }
+}
+
+impl Error for ParseLocaleError {}
+
+// ----------------------------------------------------------------
+
+/// [`LocalTimeErrorKind`] the reason a local time does not resolve to a single instant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalTimeErrorKind {
+    /// The local time occurs twice, when the clocks are set back: the earliest and
+    /// the latest instant.
+    Ambiguous(DateTime<FixedOffset>, DateTime<FixedOffset>),
+    /// The local time is skipped, when the clocks are set forward.
+    Nonexistent,
+}
+
+/// [`LocalTimeError`] a local time does not resolve to a single instant in a [`Zone`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocalTimeError {
+    datetime: NaiveDateTime,
+    zone: Zone,
+    kind: LocalTimeErrorKind,
+}
+
+impl LocalTimeError {
+    pub(crate) fn new(datetime: NaiveDateTime, zone: Zone, kind: LocalTimeErrorKind) -> Self {
+        LocalTimeError {
+            datetime,
+            zone,
+            kind,
+        }
+    }
+
+    /// The local time.
+    pub fn datetime(&self) -> NaiveDateTime {
+        self.datetime
+    }
+
+    /// The zone of the local time.
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    /// The reason of the error.
+    pub fn kind(&self) -> LocalTimeErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for LocalTimeError {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            LocalTimeErrorKind::Ambiguous(earliest, latest) => write!(
+                f,
+                "local time `{}` is ambiguous in `{}`: `{}` or `{}`",
+                self.datetime, self.zone, earliest, latest
+            ),
+            LocalTimeErrorKind::Nonexistent => write!(
+                f,
+                "local time `{}` does not exist in `{}`",
+                self.datetime, self.zone
+            ),
+        }
+    }
+}
+
+impl Error for LocalTimeError {}
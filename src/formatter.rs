@@ -16,16 +16,34 @@
 
 // ----------------------------------------------------------------
 
-use std::sync::{Arc, Mutex};
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
 
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use arc_swap::ArcSwap;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use lazy_static::lazy_static;
+use pin_project_lite::pin_project;
 
+use crate::error::ParseDateTimeError;
+use crate::formatter::compiled::CompiledPattern;
+use crate::formatter::locale::Locale;
 use crate::formatter::pattern::DateTimePattern;
+use crate::formatter::zone::{AmbiguousTimePolicy, Zone};
 
 // ----------------------------------------------------------------
 
+pub mod compiled;
+pub mod java;
+pub mod locale;
+pub mod parser;
 pub mod pattern;
+pub mod zone;
 
 // ----------------------------------------------------------------
 
@@ -51,6 +69,33 @@ pub trait DateTimeFormatter {
     /// Get the pattern associated with this formatter.
     fn activated_pattern(&self) -> DateTimePattern;
 
+    /// Get the time zone instants are formatted in, and values without offset are parsed in,
+    /// [`Zone::Utc`] by default.
+    fn activated_zone(&self) -> Zone {
+        Zone::Utc
+    }
+
+    /// Get the policy for local times which occur twice in the zone,
+    /// [`AmbiguousTimePolicy::Earliest`] by default.
+    fn ambiguous_time_policy(&self) -> AmbiguousTimePolicy {
+        AmbiguousTimePolicy::default()
+    }
+
+    /// Get the locale of the month and weekday names and the AM/PM markers,
+    /// [`Locale::EnUs`] by default.
+    fn activated_locale(&self) -> Locale {
+        Locale::default()
+    }
+
+    /// Get the activated pattern compiled once for repeated formatting, see [`CompiledPattern`],
+    /// [`None`] by default.
+    ///
+    /// It is used to format with [`DateTimeFormatter::activated_pattern`] in the
+    /// [`Locale::EnUs`] locale.
+    fn compiled_pattern(&self) -> Option<&CompiledPattern> {
+        None
+    }
+
     /// Formats a [`DateTime<Utc>`] according to the formatter default pattern(new/or_pattern).
     fn format_date_time_utc_default(&self, datetime: &DateTime<Utc>) -> String {
         self.format_date_time_utc(datetime, self.activated_pattern())
@@ -60,32 +105,109 @@ pub trait DateTimeFormatter {
     ///
     /// This function takes a reference to a `DateTime<Utc>` object and a `DateTimePattern` enum value,
     /// then formats the datetime based on the provided pattern, returning a formatted string.
+    /// The instant is rendered in the zone of the formatter, see [`DateTimeFormatter::activated_zone`],
+    /// with the names of its locale, see [`DateTimeFormatter::activated_locale`].
     fn format_date_time_utc(&self, datetime: &DateTime<Utc>, pattern: DateTimePattern) -> String {
+        formatted(|w| self.write_date_time_utc(w, datetime, pattern))
+    }
+
+    /// Writes a [`DateTime<Utc>`] into `w` according to the formatter default pattern(new/or_pattern).
+    fn write_date_time_utc_default(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Utc>,
+    ) -> fmt::Result {
+        self.write_date_time_utc(w, datetime, self.activated_pattern())
+    }
+
+    /// Writes a [`DateTime<Utc>`] into `w` according to the specified pattern,
+    /// as [`DateTimeFormatter::format_date_time_utc`] without allocating the formatted string.
+    ///
+    /// The zone, locale and interchange patterns still build their format items on each call,
+    /// see [`CompiledPattern`] for the patterns formatted without them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::fmt::Write;
+    ///
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+    ///
+    /// let mut buffer = String::from("at ");
+    /// dtf.write_date_time_utc(&mut buffer, &datetime_utc, DateTimePattern::YyyyMmDdHhMmSs)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(buffer, "at 2024-03-12 22:55:00");
+    /// ```
+    fn write_date_time_utc(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Utc>,
+        pattern: DateTimePattern,
+    ) -> fmt::Result {
+        let (zone, locale) = (self.activated_zone(), self.activated_locale());
+        let compiled = self
+            .compiled_pattern()
+            .filter(|compiled| *compiled.pattern() == pattern && locale == Locale::EnUs);
+        if zone != Zone::Utc && !pattern.is_utc_only() {
+            return match compiled {
+                Some(compiled) => zone.write_compiled(w, datetime, compiled),
+                None => zone.write(w, datetime, pattern, locale),
+            }; // Formats in the zone of the formatter
+        }
+        if locale != Locale::EnUs {
+            return pattern.write_localized(w, datetime, locale); // Formats with the names of the locale
+        }
+        if let Some(compiled) = compiled {
+            return compiled.write(w, datetime); // Formats with the pattern compiled once
+        }
+
         match pattern {
-            DateTimePattern::YyyyMmDd => datetime.format(DateTimePattern::YYYY_MM_DD).to_string(), // Formats as "year-month-day"
-            DateTimePattern::MmDdYyyy => datetime.format(DateTimePattern::MM_DD_YYYY).to_string(), // Formats as "month-day-year"
-            DateTimePattern::DdMmYyyy => datetime.format(DateTimePattern::DD_MM_YYYY).to_string(), // Formats as "day-month-year"
+            DateTimePattern::YyyyMmDd => datetime.format(DateTimePattern::YYYY_MM_DD).write_to(w), // Formats as "year-month-day"
+            DateTimePattern::MmDdYyyy => datetime.format(DateTimePattern::MM_DD_YYYY).write_to(w), // Formats as "month-day-year"
+            DateTimePattern::DdMmYyyy => datetime.format(DateTimePattern::DD_MM_YYYY).write_to(w), // Formats as "day-month-year"
             DateTimePattern::YyyyMmDdHhMm => datetime
                 .format(DateTimePattern::YYYY_MM_DD_HH_MM)
-                .to_string(), // Formats as "year-month-day hour-minute"
-            DateTimePattern::YyyyMmDdHhMmSs => datetime
-                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS)
-                .to_string(), // Formats as "year-month-day hour-minute-second"
-            DateTimePattern::YyyyMmDdHhMmSsSss => datetime
-                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS)
-                .to_string(), // Formats as "year-month-day hour-minute-second-millisecond
-            DateTimePattern::HhMm => datetime.format(DateTimePattern::HH_MM).to_string(), // Formats as "hour-minute"
-            DateTimePattern::HhMmSs => datetime.format(DateTimePattern::HH_MM_SS).to_string(), // Formats as "hour-minute-second"
-            DateTimePattern::MonthFull => datetime.format(DateTimePattern::MONTH_FULL).to_string(), // Formats as "full month name"
-            DateTimePattern::MonthAbbr => datetime.format(DateTimePattern::MONTH_ABBR).to_string(), // Formats as "abbreviated month name"
+                .write_to(w), // Formats as "year-month-day hour-minute"
+            DateTimePattern::YyyyMmDdHhMmSs | DateTimePattern::YyyyMmDdHhMmSsSss => {
+                pattern.write_localized(w, datetime, Locale::EnUs)
+            } // Formats as "year-month-day hour-minute-second[-millisecond]", digit by digit
+            DateTimePattern::YyyyMmDdHhMmSsOffset => datetime
+                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS_OFFSET)
+                .write_to(w), // Formats as "year-month-day hour-minute-second offset"
+            DateTimePattern::YyyyMmDdHhMmSsZone => datetime
+                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS_ZONE)
+                .write_to(w), // Formats as "year-month-day hour-minute-second zone"
+            DateTimePattern::HhMm => datetime.format(DateTimePattern::HH_MM).write_to(w), // Formats as "hour-minute"
+            DateTimePattern::HhMmSs => datetime.format(DateTimePattern::HH_MM_SS).write_to(w), // Formats as "hour-minute-second"
+            DateTimePattern::MonthFull => datetime.format(DateTimePattern::MONTH_FULL).write_to(w), // Formats as "full month name"
+            DateTimePattern::MonthAbbr => datetime.format(DateTimePattern::MONTH_ABBR).write_to(w), // Formats as "abbreviated month name"
             DateTimePattern::WeekdayFull => {
-                datetime.format(DateTimePattern::WEEKDAY_FULL).to_string()
+                datetime.format(DateTimePattern::WEEKDAY_FULL).write_to(w)
             } // Formats as "full weekday name"
             DateTimePattern::WeekdayAbbr => {
-                datetime.format(DateTimePattern::WEEKDAY_ABBR).to_string()
+                datetime.format(DateTimePattern::WEEKDAY_ABBR).write_to(w)
             } // Formats as "abbreviated weekday name"
-            DateTimePattern::AmPm => datetime.format(DateTimePattern::AM_PM).to_string(), // Formats as "AM/PM"
-            DateTimePattern::Timestamp => datetime.timestamp().to_string(), // Formats as "timestamp"
+            DateTimePattern::AmPm => datetime.format(DateTimePattern::AM_PM).write_to(w), // Formats as "AM/PM"
+            DateTimePattern::Timestamp => write!(w, "{}", datetime.timestamp()), // Formats as "timestamp"
+            DateTimePattern::Epoch(_) | DateTimePattern::EpochFractional(_) => {
+                pattern.write_localized(w, datetime, Locale::EnUs)
+            } // Formats as the count since the epoch
+            DateTimePattern::Rfc3339(_)
+            | DateTimePattern::Iso8601Basic(_)
+            | DateTimePattern::IsoWeekDate
+            | DateTimePattern::Rfc2822
+            | DateTimePattern::HttpDate => datetime
+                .format_with_items(pattern.items().iter())
+                .write_to(w), // Formats as the interchange format
+            DateTimePattern::Custom(custom) => datetime
+                .format_with_items(custom.items().iter())
+                .write_to(w), // Formats as the custom pattern
         }
     }
 
@@ -113,93 +235,1047 @@ pub trait DateTimeFormatter {
         self.format_date_time_utc(&datetime_utc, pattern)
     }
 
-    /// Formats a [`NaiveDateTime`] date and time according to the formatter default pattern(new/or_pattern).
-    fn format_naive_date_time_default(&self, datetime: &NaiveDateTime) -> String {
-        self.format_naive_date_time(datetime, self.activated_pattern())
-    }
+    /// Formats a [`NaiveDateTime`] date and time according to the formatter default pattern(new/or_pattern).
+    fn format_naive_date_time_default(&self, datetime: &NaiveDateTime) -> String {
+        self.format_naive_date_time(datetime, self.activated_pattern())
+    }
+
+    /// Formats a [`NaiveDateTime`] according to the specified pattern.
+    ///
+    /// This function takes a reference to a [`NaiveDateTime`] object and a `DateTimePattern` enum value,
+    /// then formats the datetime based on the provided pattern, returning a formatted string.
+    fn format_naive_date_time(&self, datetime: &NaiveDateTime, pattern: DateTimePattern) -> String {
+        formatted(|w| self.write_naive_date_time(w, datetime, pattern))
+    }
+
+    /// Writes a [`NaiveDateTime`] into `w` according to the formatter default pattern(new/or_pattern).
+    fn write_naive_date_time_default(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &NaiveDateTime,
+    ) -> fmt::Result {
+        self.write_naive_date_time(w, datetime, self.activated_pattern())
+    }
+
+    /// Writes a [`NaiveDateTime`] into `w` according to the specified pattern,
+    /// as [`DateTimeFormatter::format_naive_date_time`] without allocating the formatted string.
+    fn write_naive_date_time(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &NaiveDateTime,
+        pattern: DateTimePattern,
+    ) -> fmt::Result {
+        let locale = self.activated_locale();
+        if locale != Locale::EnUs {
+            return pattern.write_localized(w, &datetime.and_utc(), locale); // Formats with the names of the locale
+        }
+        if let Some(compiled) = self
+            .compiled_pattern()
+            .filter(|compiled| *compiled.pattern() == pattern)
+        {
+            return compiled.write(w, &datetime.and_utc()); // Formats with the pattern compiled once
+        }
+
+        match pattern {
+            DateTimePattern::YyyyMmDd => datetime.format(DateTimePattern::YYYY_MM_DD).write_to(w), // Formats as "year-month-day"
+            DateTimePattern::MmDdYyyy => datetime.format(DateTimePattern::MM_DD_YYYY).write_to(w), // Formats as "month-day-year"
+            DateTimePattern::DdMmYyyy => datetime.format(DateTimePattern::DD_MM_YYYY).write_to(w), // Formats as "day-month-year"
+            DateTimePattern::YyyyMmDdHhMm => datetime
+                .format(DateTimePattern::YYYY_MM_DD_HH_MM)
+                .write_to(w), // Formats as "year-month-day hour-minute"
+            DateTimePattern::YyyyMmDdHhMmSs | DateTimePattern::YyyyMmDdHhMmSsSss => {
+                pattern.write_localized(w, &datetime.and_utc(), Locale::EnUs)
+            } // Formats as "year-month-day hour-minute-second[-millisecond]", digit by digit
+            DateTimePattern::YyyyMmDdHhMmSsOffset => datetime
+                .and_utc()
+                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS_OFFSET)
+                .write_to(w), // Formats as "year-month-day hour-minute-second offset", in UTC
+            DateTimePattern::YyyyMmDdHhMmSsZone => datetime
+                .and_utc()
+                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS_ZONE)
+                .write_to(w), // Formats as "year-month-day hour-minute-second zone", in UTC
+            DateTimePattern::HhMm => datetime.format(DateTimePattern::HH_MM).write_to(w), // Formats as "hour-minute"
+            DateTimePattern::HhMmSs => datetime.format(DateTimePattern::HH_MM_SS).write_to(w), // Formats as "hour-minute-second"
+            DateTimePattern::MonthFull => datetime.format(DateTimePattern::MONTH_FULL).write_to(w), // Formats as "full month name"
+            DateTimePattern::MonthAbbr => datetime.format(DateTimePattern::MONTH_ABBR).write_to(w), // Formats as "abbreviated month name"
+            DateTimePattern::WeekdayFull => {
+                datetime.format(DateTimePattern::WEEKDAY_FULL).write_to(w)
+            } // Formats as "full weekday name"
+            DateTimePattern::WeekdayAbbr => {
+                datetime.format(DateTimePattern::WEEKDAY_ABBR).write_to(w)
+            } // Formats as "abbreviated weekday name"
+            DateTimePattern::AmPm => datetime.format(DateTimePattern::AM_PM).write_to(w), // Formats as "AM/PM"
+            DateTimePattern::Timestamp => write!(w, "{}", datetime.and_utc().timestamp()), // Formats as "timestamp"
+            DateTimePattern::Epoch(_) | DateTimePattern::EpochFractional(_) => {
+                pattern.write_localized(w, &datetime.and_utc(), Locale::EnUs)
+            } // Formats as the count since the epoch
+            DateTimePattern::Rfc3339(_)
+            | DateTimePattern::Iso8601Basic(_)
+            | DateTimePattern::IsoWeekDate
+            | DateTimePattern::Rfc2822
+            | DateTimePattern::HttpDate => datetime
+                .and_utc()
+                .format_with_items(pattern.items().iter())
+                .write_to(w), // Formats as the interchange format, in UTC
+            DateTimePattern::Custom(custom) => datetime
+                .and_utc()
+                .format_with_items(custom.items().iter())
+                .write_to(w), // Formats as the custom pattern, with offset specifiers in UTC
+        }
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Formats a [`DateTime<Tz>`] in its own time zone according to the formatter default pattern(new/or_pattern).
+    fn format_date_time_default<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: fmt::Display,
+        Self: Sized,
+    {
+        self.format_date_time(datetime, self.activated_pattern())
+    }
+
+    /// Formats a [`DateTime<Tz>`], e.g. [`DateTime<FixedOffset>`] or [`DateTime<Local>`](chrono::Local),
+    /// in its own time zone according to the specified pattern.
+    ///
+    /// Offset and zone name specifiers, such as those of [`DateTimePattern::YyyyMmDdHhMmSsOffset`],
+    /// render the offset of `datetime`. A formatter with a zone, see [`DateTimeFormatter::activated_zone`],
+    /// renders the instant in its zone instead, as [`DateTimeFormatter::format_date_time_utc`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, TimeZone};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    /// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    /// let datetime = shanghai.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     dtf.format_date_time(&datetime, DateTimePattern::YyyyMmDdHhMmSsOffset),
+    ///     "2024-03-01 09:00:00 +08:00"
+    /// );
+    /// ```
+    fn format_date_time<Tz: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz>,
+        pattern: DateTimePattern,
+    ) -> String
+    where
+        Tz::Offset: fmt::Display,
+        Self: Sized,
+    {
+        formatted(|w| self.write_date_time(w, datetime, pattern))
+    }
+
+    /// Writes a [`DateTime<Tz>`] into `w` in its own time zone according to the specified pattern,
+    /// as [`DateTimeFormatter::format_date_time`] without allocating the formatted string.
+    fn write_date_time<Tz: TimeZone>(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Tz>,
+        pattern: DateTimePattern,
+    ) -> fmt::Result
+    where
+        Tz::Offset: fmt::Display,
+        Self: Sized,
+    {
+        write_date_time_with(self, w, datetime, pattern)
+    }
+
+    /// Converts a [`DateTime<Tz>`] to the time zone `zone`, then formats it according to
+    /// the formatter default pattern(new/or_pattern).
+    fn format_date_time_in_default<Tz: TimeZone, Z: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz>,
+        zone: &Z,
+    ) -> String
+    where
+        Z::Offset: fmt::Display,
+        Self: Sized,
+    {
+        self.format_date_time_in(datetime, zone, self.activated_pattern())
+    }
+
+    /// Converts a [`DateTime<Tz>`] to the time zone `zone`, then formats it according to
+    /// the specified pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    /// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    ///
+    /// assert_eq!(
+    ///     dtf.format_date_time_in(&datetime_utc, &shanghai, DateTimePattern::YyyyMmDdHhMmSsOffset),
+    ///     "2024-03-01 09:00:00 +08:00"
+    /// );
+    /// ```
+    fn format_date_time_in<Tz: TimeZone, Z: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz>,
+        zone: &Z,
+        pattern: DateTimePattern,
+    ) -> String
+    where
+        Z::Offset: fmt::Display,
+        Self: Sized,
+    {
+        // In `zone`, rather than the zone of the formatter.
+        formatted(|w| {
+            pattern.write_localized(w, &datetime.with_timezone(zone), self.activated_locale())
+        })
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Writes a [`DateTime<Utc>`] into the byte sink `w`, e.g. a [`File`](std::fs::File)
+    /// or a [`Vec<u8>`], according to the specified pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+    ///
+    /// let mut buffer = Vec::new();
+    /// dtf.write_date_time_utc_io(&mut buffer, &datetime_utc, DateTimePattern::YyyyMmDd)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(buffer, b"2024-03-12");
+    /// ```
+    fn write_date_time_utc_io(
+        &self,
+        w: &mut dyn io::Write,
+        datetime: &DateTime<Utc>,
+        pattern: DateTimePattern,
+    ) -> io::Result<()> {
+        let mut adapter = IoAdapter::new(w);
+        let result = self.write_date_time_utc(&mut adapter, datetime, pattern);
+        adapter.finish(result)
+    }
+
+    /// Writes a [`NaiveDateTime`] into the byte sink `w` according to the specified pattern.
+    fn write_naive_date_time_io(
+        &self,
+        w: &mut dyn io::Write,
+        datetime: &NaiveDateTime,
+        pattern: DateTimePattern,
+    ) -> io::Result<()> {
+        let mut adapter = IoAdapter::new(w);
+        let result = self.write_naive_date_time(&mut adapter, datetime, pattern);
+        adapter.finish(result)
+    }
+
+    /// Displays a [`DateTime<Utc>`] according to the formatter default pattern(new/or_pattern),
+    /// writing straight into the [`fmt::Formatter`] of `format!`, `write!` or `println!`.
+    ///
+    /// A `dyn` formatter, such as the one returned by [`DateTimeFormatter::of_pattern`],
+    /// is displayed through [`DisplayDateTime::utc`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     format!("at {}", dtf.display(&datetime_utc)),
+    ///     "at 2024-03-12 22:55:00"
+    /// );
+    /// assert_eq!(
+    ///     format!("on {}", dtf.display_with(&datetime_utc, DateTimePattern::YyyyMmDd)),
+    ///     "on 2024-03-12"
+    /// );
+    /// assert_eq!(
+    ///     format!("[{:>12}]", dtf.display_with(&datetime_utc, DateTimePattern::YyyyMmDd)),
+    ///     "[  2024-03-12]"
+    /// );
+    /// ```
+    fn display<'a>(&'a self, datetime: &'a DateTime<Utc>) -> DisplayDateTime<'a>
+    where
+        Self: Sized,
+    {
+        self.display_with(datetime, self.activated_pattern())
+    }
+
+    /// Displays a [`DateTime<Utc>`] according to the specified pattern.
+    fn display_with<'a>(
+        &'a self,
+        datetime: &'a DateTime<Utc>,
+        pattern: DateTimePattern,
+    ) -> DisplayDateTime<'a>
+    where
+        Self: Sized,
+    {
+        DisplayDateTime::utc(self, datetime, pattern)
+    }
+
+    /// Displays a [`NaiveDateTime`] according to the formatter default pattern(new/or_pattern).
+    fn display_naive<'a>(&'a self, datetime: &'a NaiveDateTime) -> DisplayDateTime<'a>
+    where
+        Self: Sized,
+    {
+        self.display_naive_with(datetime, self.activated_pattern())
+    }
+
+    /// Displays a [`NaiveDateTime`] according to the specified pattern.
+    fn display_naive_with<'a>(
+        &'a self,
+        datetime: &'a NaiveDateTime,
+        pattern: DateTimePattern,
+    ) -> DisplayDateTime<'a>
+    where
+        Self: Sized,
+    {
+        DisplayDateTime::naive(self, datetime, pattern)
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Parses a [`DateTime<Utc>`] according to the formatter default pattern(new/or_pattern).
+    fn parse_date_time_utc_default(
+        &self,
+        value: &str,
+    ) -> Result<DateTime<Utc>, ParseDateTimeError> {
+        self.parse_date_time_utc(value, self.activated_pattern())
+    }
+
+    /// Parses a [`DateTime<Utc>`] according to the specified pattern.
+    ///
+    /// A value without offset is in UTC, a value with offset is converted to UTC,
+    /// and a pattern without time fields resolves to midnight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    ///
+    /// assert_eq!(
+    ///     dtf.parse_date_time_utc("2024-03-12 22:55:00", DateTimePattern::YyyyMmDdHhMmSs),
+    ///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     dtf.parse_date_time_utc("1710284100", DateTimePattern::Timestamp),
+    ///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap())
+    /// );
+    /// ```
+    fn parse_date_time_utc(
+        &self,
+        value: &str,
+        pattern: DateTimePattern,
+    ) -> Result<DateTime<Utc>, ParseDateTimeError> {
+        parser::parse_date_time_utc(
+            value,
+            pattern,
+            self.activated_zone(),
+            self.ambiguous_time_policy(),
+        )
+    }
+
+    /// Parses a [`NaiveDateTime`] in UTC according to the formatter default pattern(new/or_pattern).
+    fn parse_naive_date_time_utc_default(
+        &self,
+        value: &str,
+    ) -> Result<NaiveDateTime, ParseDateTimeError> {
+        self.parse_naive_date_time_utc(value, self.activated_pattern())
+    }
+
+    /// Parses a [`NaiveDateTime`] in UTC according to the specified pattern,
+    /// the counterpart of [`DateTimeFormatter::format_naive_date_time_utc`].
+    fn parse_naive_date_time_utc(
+        &self,
+        value: &str,
+        pattern: DateTimePattern,
+    ) -> Result<NaiveDateTime, ParseDateTimeError> {
+        self.parse_date_time_utc(value, pattern)
+            .map(|datetime| datetime.naive_utc())
+    }
+
+    /// Parses a [`NaiveDateTime`] according to the formatter default pattern(new/or_pattern).
+    fn parse_naive_date_time_default(
+        &self,
+        value: &str,
+    ) -> Result<NaiveDateTime, ParseDateTimeError> {
+        self.parse_naive_date_time(value, self.activated_pattern())
+    }
+
+    /// Parses a [`NaiveDateTime`] according to the specified pattern.
+    ///
+    /// The value is read as written, an offset in the value is ignored,
+    /// and a pattern without time fields resolves to midnight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    /// let ndt = NaiveDate::from_ymd_opt(2024, 3, 12)
+    ///     .unwrap()
+    ///     .and_hms_milli_opt(22, 55, 0, 125)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     dtf.parse_naive_date_time("2024-03-12 22:55:00.125", DateTimePattern::YyyyMmDdHhMmSsSss),
+    ///     Ok(ndt)
+    /// );
+    /// assert!(dtf.parse_naive_date_time("22:55:00", DateTimePattern::HhMmSs).is_err());
+    /// ```
+    fn parse_naive_date_time(
+        &self,
+        value: &str,
+        pattern: DateTimePattern,
+    ) -> Result<NaiveDateTime, ParseDateTimeError> {
+        parser::parse_naive_date_time(value, pattern)
+    }
+
+    /// Parses a [`NaiveDate`] according to the formatter default pattern(new/or_pattern).
+    fn parse_naive_date_default(&self, value: &str) -> Result<NaiveDate, ParseDateTimeError> {
+        self.parse_naive_date(value, self.activated_pattern())
+    }
+
+    /// Parses a [`NaiveDate`] according to the specified pattern, time fields are validated and dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    ///
+    /// assert_eq!(
+    ///     dtf.parse_naive_date("12-03-2024", DateTimePattern::DdMmYyyy),
+    ///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap())
+    /// );
+    /// ```
+    fn parse_naive_date(
+        &self,
+        value: &str,
+        pattern: DateTimePattern,
+    ) -> Result<NaiveDate, ParseDateTimeError> {
+        parser::parse_naive_date(value, pattern)
+    }
+
+    /// Parses a [`NaiveTime`] according to the formatter default pattern(new/or_pattern).
+    fn parse_naive_time_default(&self, value: &str) -> Result<NaiveTime, ParseDateTimeError> {
+        self.parse_naive_time(value, self.activated_pattern())
+    }
+
+    /// Parses a [`NaiveTime`] according to the specified pattern, date fields are validated and dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveTime;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin();
+    ///
+    /// assert_eq!(
+    ///     dtf.parse_naive_time("22:55", DateTimePattern::HhMm),
+    ///     Ok(NaiveTime::from_hms_opt(22, 55, 0).unwrap())
+    /// );
+    /// ```
+    fn parse_naive_time(
+        &self,
+        value: &str,
+        pattern: DateTimePattern,
+    ) -> Result<NaiveTime, ParseDateTimeError> {
+        parser::parse_naive_time(value, pattern)
+    }
+}
+
+/// [`DisplayDateTime`] a date and time rendered by a [`DateTimeFormatter`] when displayed,
+/// see [`DateTimeFormatter::display`].
+///
+/// The width, fill and alignment of the format specifier, e.g. `{:>25}`, apply to the whole
+/// rendered date and time.
+pub struct DisplayDateTime<'a> {
+    formatter: &'a dyn DateTimeFormatter,
+    datetime: Displayed<'a>,
+    pattern: DateTimePattern,
+}
+
+enum Displayed<'a> {
+    Utc(&'a DateTime<Utc>),
+    Naive(&'a NaiveDateTime),
+}
+
+impl<'a> DisplayDateTime<'a> {
+    /// Displays a [`DateTime<Utc>`] with any formatter, including a `dyn` one,
+    /// as [`DateTimeFormatter::display_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter, DisplayDateTime};
+    ///
+    /// let dtf = DefaultDateTimeFormatter::builtin().of_pattern(DateTimePattern::YyyyMmDd);
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     DisplayDateTime::utc(&*dtf, &datetime_utc, dtf.activated_pattern()).to_string(),
+    ///     "2024-03-12"
+    /// );
+    /// ```
+    pub fn utc(
+        formatter: &'a dyn DateTimeFormatter,
+        datetime: &'a DateTime<Utc>,
+        pattern: DateTimePattern,
+    ) -> Self {
+        DisplayDateTime {
+            formatter,
+            datetime: Displayed::Utc(datetime),
+            pattern,
+        }
+    }
+
+    /// Displays a [`NaiveDateTime`] with any formatter, including a `dyn` one,
+    /// as [`DateTimeFormatter::display_naive_with`].
+    pub fn naive(
+        formatter: &'a dyn DateTimeFormatter,
+        datetime: &'a NaiveDateTime,
+        pattern: DateTimePattern,
+    ) -> Self {
+        DisplayDateTime {
+            formatter,
+            datetime: Displayed::Naive(datetime),
+            pattern,
+        }
+    }
+
+    fn write(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        match self.datetime {
+            Displayed::Utc(datetime) => {
+                self.formatter
+                    .write_date_time_utc(w, datetime, self.pattern.clone())
+            }
+            Displayed::Naive(datetime) => {
+                self.formatter
+                    .write_naive_date_time(w, datetime, self.pattern.clone())
+            }
+        }
+    }
+}
+
+impl fmt::Display for DisplayDateTime<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write(f); // Writes straight into `f`
+        }
+
+        let mut buffer = String::with_capacity(32);
+        self.write(&mut buffer)?;
+        f.pad(&buffer)
+    }
+}
+
+/// Forwards the text of a [`fmt::Write`] into an [`io::Write`], keeping the first I/O error
+/// which [`fmt::Error`] cannot carry.
+struct IoAdapter<'a> {
+    inner: &'a mut dyn io::Write,
+    error: Option<io::Error>,
+}
+
+impl<'a> IoAdapter<'a> {
+    fn new(inner: &'a mut dyn io::Write) -> Self {
+        IoAdapter { inner, error: None }
+    }
+
+    fn finish(self, result: fmt::Result) -> io::Result<()> {
+        match (result, self.error) {
+            (_, Some(error)) => Err(error),
+            (Ok(()), None) => Ok(()),
+            (Err(_), None) => Err(io::Error::other("formatter error")),
+        }
+    }
+}
+
+impl fmt::Write for IoAdapter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+/// Collects the text written by `write` into a [`String`].
+fn formatted(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut formatted = String::new();
+    write(&mut formatted).expect("a formatting trait implementation returned an error");
+
+    formatted
+}
+
+/// [`DefaultDateTimeFormatter`] The default `impl` of [`DateTimeFormatter`]
+pub struct DefaultDateTimeFormatter {
+    /// [`pattern`] the activate pattern([`DateTimePattern`]) of formatter, see [`DefaultDateTimeFormatter::set_pattern`].
+    pattern: DateTimePattern,
+    /// [`zone`] the time zone([`Zone`]) of formatter, see [`DefaultDateTimeFormatter::with_zone`].
+    zone: Zone,
+    /// [`ambiguous_time_policy`] the policy([`AmbiguousTimePolicy`]) for ambiguous local times,
+    /// see [`DefaultDateTimeFormatter::with_ambiguous_time_policy`].
+    ambiguous_time_policy: AmbiguousTimePolicy,
+    /// [`locale`] the locale([`Locale`]) of formatter, see [`DefaultDateTimeFormatter::with_locale`].
+    locale: Locale,
+    /// [`compiled`] the activate pattern compiled once, when the formatter is created.
+    compiled: CompiledPattern,
+}
+
+impl DateTimeFormatter for DefaultDateTimeFormatter {
+    /// override
+    fn of_pattern(&self, pattern: DateTimePattern) -> Box<dyn DateTimeFormatter> {
+        Box::new(
+            DefaultDateTimeFormatter::new(pattern)
+                .with_zone(self.zone)
+                .with_ambiguous_time_policy(self.ambiguous_time_policy)
+                .with_locale(self.locale),
+        )
+    }
+
+    /// override
+    fn activated_pattern(&self) -> DateTimePattern {
+        self.pattern.clone()
+    }
+
+    /// override
+    fn activated_zone(&self) -> Zone {
+        self.zone
+    }
+
+    /// override
+    fn ambiguous_time_policy(&self) -> AmbiguousTimePolicy {
+        self.ambiguous_time_policy
+    }
+
+    /// override
+    fn activated_locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// override
+    fn compiled_pattern(&self) -> Option<&CompiledPattern> {
+        Some(&self.compiled)
+    }
+}
+
+#[allow(dead_code)]
+impl DefaultDateTimeFormatter {
+    pub fn builtin() -> Self {
+        DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSs)
+    }
+
+    pub fn new(pattern: DateTimePattern) -> Self {
+        DefaultDateTimeFormatter {
+            compiled: CompiledPattern::new(pattern.clone()),
+            pattern,
+            zone: Zone::Utc,
+            ambiguous_time_policy: AmbiguousTimePolicy::default(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Creates a formatter with the default date and time layout of `locale`,
+    /// see [`Locale::date_time_pattern`], and its names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::locale::Locale;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    ///
+    /// let dtf = DefaultDateTimeFormatter::localized(Locale::DeDe);
+    /// assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), "01.03.2024 13:05:00");
+    ///
+    /// let dtf = DefaultDateTimeFormatter::localized(Locale::EnUs);
+    /// assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), "03/01/2024 01:05:00 PM");
+    /// ```
+    pub fn localized(locale: Locale) -> Self {
+        DefaultDateTimeFormatter::new(locale.date_time_pattern()).with_locale(locale)
+    }
+
+    /// Sets the activated pattern, and compiles it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let mut dtf = DefaultDateTimeFormatter::builtin();
+    /// dtf.set_pattern(DateTimePattern::YyyyMmDd);
+    ///
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    /// assert_eq!(dtf.activated_pattern(), DateTimePattern::YyyyMmDd);
+    /// assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), "2024-03-01");
+    /// ```
+    pub fn set_pattern(&mut self, pattern: DateTimePattern) {
+        self.compiled = CompiledPattern::new(pattern.clone());
+        self.pattern = pattern;
+    }
+
+    /// Sets the time zone instants are formatted in, and values without offset are parsed in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    /// let dtf = DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSsOffset).with_zone(shanghai);
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    ///
+    /// assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), "2024-03-01 09:00:00 +08:00");
+    /// assert_eq!(
+    ///     dtf.parse_date_time_utc("2024-03-01 09:00:00", DateTimePattern::YyyyMmDdHhMmSs),
+    ///     Ok(datetime_utc)
+    /// );
+    /// ```
+    pub fn with_zone(mut self, zone: impl Into<Zone>) -> Self {
+        self.zone = zone.into();
+        self
+    }
+
+    /// Sets the policy for local times which occur twice in the zone.
+    pub fn with_ambiguous_time_policy(mut self, policy: AmbiguousTimePolicy) -> Self {
+        self.ambiguous_time_policy = policy;
+        self
+    }
+
+    /// Sets the locale of the month and weekday names and the AM/PM markers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use chronounit::formatter::locale::Locale;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    /// let dtf = DefaultDateTimeFormatter::new(DateTimePattern::WeekdayFull).with_locale(Locale::ZhCn);
+    ///
+    /// assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), "星期五");
+    /// assert_eq!(dtf.format_date_time_utc(&datetime_utc, DateTimePattern::AmPm), "下午");
+    /// ```
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// `SharedDateTimeFormatter` a [`DateTimeFormatter`] usable as the process-wide default.
+pub type SharedDateTimeFormatter = Arc<dyn DateTimeFormatter + Send + Sync>;
+
+lazy_static! {
+    /// Initialised once, then read without locking: a reader never waits for a writer,
+    /// and a panic while formatting leaves nothing poisoned.
+    static ref DEFAULT_FORMATTER: ArcSwap<SharedDateTimeFormatter> =
+        ArcSwap::from_pointee(Arc::new(DefaultDateTimeFormatter::builtin()));
+    /// The default formatters replaced by the live [`DefaultFormatterGuard`]s, the outermost first.
+    static ref OVERRIDDEN_FORMATTERS: Mutex<Vec<SharedDateTimeFormatter>> = Mutex::new(Vec::new());
+}
+
+thread_local! {
+    /// The formatters overriding the default one on this thread, the innermost last.
+    static LOCAL_FORMATTERS: RefCell<Vec<SharedDateTimeFormatter>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Calls `f` with the innermost formatter overriding the default one on this thread,
+/// or else the process-wide default formatter.
+fn with_formatter<R>(f: impl FnOnce(&SharedDateTimeFormatter) -> R) -> R {
+    // Cloned out of the borrow, so that `f` may itself override the formatter, and none is left
+    // while the thread is torn down, e.g. when a thread-local logs from its `drop`.
+    let local = LOCAL_FORMATTERS
+        .try_with(|local| local.borrow().last().cloned())
+        .ok()
+        .flatten();
+    match local {
+        Some(formatter) => f(&formatter),
+        None => f(&DEFAULT_FORMATTER.load()),
+    }
+}
+
+/// Sets the process-wide default formatter of the free functions and macros,
+/// [`DefaultDateTimeFormatter::builtin`] until then.
+///
+/// An override on the current thread, see [`override_local_formatter`], or of the current task,
+/// see [`scope_formatter`], takes precedence.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+/// use chronounit::formatter::DefaultDateTimeFormatter;
+///
+/// formatter::set_default_formatter(DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDd));
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+/// assert_eq!(formatter::format_date_time_utc_default(&datetime_utc), "2024-03-01");
+/// ```
+pub fn set_default_formatter<F>(formatter: F)
+where
+    F: DateTimeFormatter + Send + Sync + 'static,
+{
+    replace_default_formatter(formatter);
+}
+
+/// Replaces the process-wide default formatter, see [`set_default_formatter`].
+///
+/// # Returns
+/// The previous default formatter.
+///
+/// # Examples
+///
+/// ```rust
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+/// use chronounit::formatter::DefaultDateTimeFormatter;
+///
+/// let previous = formatter::replace_default_formatter(DefaultDateTimeFormatter::new(DateTimePattern::HhMm));
+/// assert_eq!(previous.activated_pattern(), DateTimePattern::YyyyMmDdHhMmSs);
+/// ```
+pub fn replace_default_formatter<F>(formatter: F) -> SharedDateTimeFormatter
+where
+    F: DateTimeFormatter + Send + Sync + 'static,
+{
+    swap_default_formatter(Arc::new(formatter))
+}
+
+/// Resets the process-wide default formatter to [`DefaultDateTimeFormatter::builtin`].
+pub fn reset_default_formatter() {
+    set_default_formatter(DefaultDateTimeFormatter::builtin());
+}
+
+/// Overrides the process-wide default formatter until the returned guard is dropped,
+/// which restores the previous one.
+///
+/// The override is seen by every thread, including tests running in parallel. To override
+/// the default formatter of one thread or task only, see [`override_local_formatter`] and
+/// [`scope_formatter`]. Guards nest: dropping one restores the formatter it replaced and
+/// removes the overrides of the guards created after it, even if they are dropped out of order.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+/// use chronounit::formatter::DefaultDateTimeFormatter;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+/// {
+///     let _guard = formatter::override_default_formatter(DefaultDateTimeFormatter::new(DateTimePattern::HhMm));
+///     assert_eq!(formatter::format_date_time_utc_default(&datetime_utc), "12:00");
+/// }
+/// assert_eq!(formatter::format_date_time_utc_default(&datetime_utc), "2024-03-01 12:00:00");
+/// ```
+pub fn override_default_formatter<F>(formatter: F) -> DefaultFormatterGuard
+where
+    F: DateTimeFormatter + Send + Sync + 'static,
+{
+    let mut overridden = OVERRIDDEN_FORMATTERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    overridden.push(swap_default_formatter(Arc::new(formatter)));
+
+    DefaultFormatterGuard {
+        depth: overridden.len() - 1,
+    }
+}
+
+fn swap_default_formatter(formatter: SharedDateTimeFormatter) -> SharedDateTimeFormatter {
+    let previous = DEFAULT_FORMATTER.swap(Arc::new(formatter));
+    SharedDateTimeFormatter::clone(&previous)
+}
+
+/// [`DefaultFormatterGuard`] restores the previous default formatter when dropped,
+/// see [`override_default_formatter`].
+#[must_use = "the default formatter is restored as soon as the guard is dropped"]
+pub struct DefaultFormatterGuard {
+    depth: usize,
+}
+
+impl Drop for DefaultFormatterGuard {
+    /// override
+    fn drop(&mut self) {
+        let mut overridden = OVERRIDDEN_FORMATTERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        // Nothing left to restore once an outer guard has been dropped.
+        if self.depth < overridden.len() {
+            let previous = overridden.swap_remove(self.depth);
+            overridden.truncate(self.depth);
+            swap_default_formatter(previous);
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Overrides the default formatter on the current thread until the returned guard is dropped,
+/// e.g. for the tenant of a request. The innermost override wins over the process-wide
+/// default formatter, other threads are not affected.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+/// use chronounit::formatter::DefaultDateTimeFormatter;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+/// let tenant = DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMm)
+///     .with_zone(FixedOffset::east_opt(8 * 3600).unwrap());
+/// {
+///     let _guard = formatter::override_local_formatter(tenant);
+///     assert_eq!(formatter::format_date_time_utc_default(&datetime_utc), "2024-03-01 20:00");
+/// }
+/// assert_eq!(formatter::format_date_time_utc_default(&datetime_utc), "2024-03-01 12:00:00");
+/// ```
+pub fn override_local_formatter<F>(formatter: F) -> LocalFormatterGuard
+where
+    F: DateTimeFormatter + Send + Sync + 'static,
+{
+    push_local_formatter(Arc::new(formatter))
+}
+
+/// Runs `future` with `formatter` overriding the default one, on whichever thread polls it,
+/// as a task-local value without depending on an async runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::future::Future;
+/// use std::pin::pin;
+/// use std::sync::Arc;
+/// use std::task::{Context, Poll, Wake};
+///
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+/// use chronounit::formatter::DefaultDateTimeFormatter;
+///
+/// struct Noop;
+///
+/// impl Wake for Noop {
+///     fn wake(self: Arc<Self>) {}
+/// }
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+/// let tenant = DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDd);
+/// let task = pin!(formatter::scope_formatter(tenant, async move {
+///     formatter::format_date_time_utc_default(&datetime_utc)
+/// }));
+///
+/// let waker = Arc::new(Noop).into();
+/// let rendered = task.poll(&mut Context::from_waker(&waker));
+/// assert_eq!(rendered, Poll::Ready("2024-03-01".to_string()));
+/// ```
+pub fn scope_formatter<F, Fut>(formatter: F, future: Fut) -> ScopedFormatter<Fut>
+where
+    F: DateTimeFormatter + Send + Sync + 'static,
+    Fut: Future,
+{
+    ScopedFormatter {
+        formatter: Arc::new(formatter),
+        future,
+    }
+}
+
+fn push_local_formatter(formatter: SharedDateTimeFormatter) -> LocalFormatterGuard {
+    LOCAL_FORMATTERS.with(|local| {
+        let mut local = local.borrow_mut();
+        local.push(formatter);
 
-    /// Formats a [`NaiveDateTime`] according to the specified pattern.
-    ///
-    /// This function takes a reference to a [`NaiveDateTime`] object and a `DateTimePattern` enum value,
-    /// then formats the datetime based on the provided pattern, returning a formatted string.
-    fn format_naive_date_time(&self, datetime: &NaiveDateTime, pattern: DateTimePattern) -> String {
-        match pattern {
-            DateTimePattern::YyyyMmDd => datetime.format(DateTimePattern::YYYY_MM_DD).to_string(), // Formats as "year-month-day"
-            DateTimePattern::MmDdYyyy => datetime.format(DateTimePattern::MM_DD_YYYY).to_string(), // Formats as "month-day-year"
-            DateTimePattern::DdMmYyyy => datetime.format(DateTimePattern::DD_MM_YYYY).to_string(), // Formats as "day-month-year"
-            DateTimePattern::YyyyMmDdHhMm => datetime
-                .format(DateTimePattern::YYYY_MM_DD_HH_MM)
-                .to_string(), // Formats as "year-month-day hour-minute"
-            DateTimePattern::YyyyMmDdHhMmSs => datetime
-                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS)
-                .to_string(), // Formats as "year-month-day hour-minute-second"
-            DateTimePattern::YyyyMmDdHhMmSsSss => datetime
-                .format(DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS)
-                .to_string(), // Formats as "year-month-day hour-minute-second-millisecond
-            DateTimePattern::HhMm => datetime.format(DateTimePattern::HH_MM).to_string(), // Formats as "hour-minute"
-            DateTimePattern::HhMmSs => datetime.format(DateTimePattern::HH_MM_SS).to_string(), // Formats as "hour-minute-second"
-            DateTimePattern::MonthFull => datetime.format(DateTimePattern::MONTH_FULL).to_string(), // Formats as "full month name"
-            DateTimePattern::MonthAbbr => datetime.format(DateTimePattern::MONTH_ABBR).to_string(), // Formats as "abbreviated month name"
-            DateTimePattern::WeekdayFull => {
-                datetime.format(DateTimePattern::WEEKDAY_FULL).to_string()
-            } // Formats as "full weekday name"
-            DateTimePattern::WeekdayAbbr => {
-                datetime.format(DateTimePattern::WEEKDAY_ABBR).to_string()
-            } // Formats as "abbreviated weekday name"
-            DateTimePattern::AmPm => datetime.format(DateTimePattern::AM_PM).to_string(), // Formats as "AM/PM"
-            DateTimePattern::Timestamp => datetime.timestamp().to_string(), // Formats as "timestamp"
+        LocalFormatterGuard {
+            depth: local.len() - 1,
+            _thread: PhantomData,
         }
-    }
+    })
 }
 
-/// [`DefaultDateTimeFormatter`] The default `impl` of [`DateTimeFormatter`]
-pub struct DefaultDateTimeFormatter {
-    /// [`pattern`] the activate pattern([`DateTimePattern`]) of formatter .
-    pub pattern: DateTimePattern,
+/// [`LocalFormatterGuard`] removes the override of the default formatter on the current thread
+/// when dropped, see [`override_local_formatter`].
+#[must_use = "the override is removed as soon as the guard is dropped"]
+pub struct LocalFormatterGuard {
+    depth: usize,
+    /// Bound to the thread of the override.
+    _thread: PhantomData<*const ()>,
 }
 
-impl DateTimeFormatter for DefaultDateTimeFormatter {
-    /// override
-    fn of_pattern(&self, pattern: DateTimePattern) -> Box<dyn DateTimeFormatter> {
-        Box::new(DefaultDateTimeFormatter::new(pattern))
-    }
-
+impl Drop for LocalFormatterGuard {
     /// override
-    fn activated_pattern(&self) -> DateTimePattern {
-        self.pattern.clone()
+    fn drop(&mut self) {
+        // Also removes the overrides of inner guards dropped out of order, and none is left
+        // to remove while the thread is torn down.
+        let _ = LOCAL_FORMATTERS.try_with(|local| local.borrow_mut().truncate(self.depth));
     }
 }
 
-#[allow(dead_code)]
-impl DefaultDateTimeFormatter {
-    pub fn builtin() -> Self {
-        DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSs)
-    }
-
-    pub fn new(pattern: DateTimePattern) -> Self {
-        DefaultDateTimeFormatter { pattern }
+pin_project! {
+    /// [`ScopedFormatter`] a future with its own default formatter, see [`scope_formatter`].
+    #[must_use = "futures do nothing unless polled"]
+    pub struct ScopedFormatter<Fut> {
+        formatter: SharedDateTimeFormatter,
+        #[pin]
+        future: Fut,
     }
 }
 
-// ----------------------------------------------------------------
-
-lazy_static! {
-    static ref BUILT_IN_FORMATTER: Arc<Mutex<Option<DefaultDateTimeFormatter>>> =
-        Arc::new(Mutex::new(None));
-}
+impl<Fut: Future> Future for ScopedFormatter<Fut> {
+    type Output = Fut::Output;
 
-fn formatter() -> Arc<Mutex<Option<DefaultDateTimeFormatter>>> {
-    let mut instance = BUILT_IN_FORMATTER.lock().unwrap();
-    if instance.is_none() {
-        *instance = Some(DefaultDateTimeFormatter::builtin());
+    /// override
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _guard = push_local_formatter(Arc::clone(this.formatter));
+        this.future.poll(cx)
     }
-
-    Arc::clone(&BUILT_IN_FORMATTER)
 }
 
 // ----------------------------------------------------------------
 
-/// Formats a [`DateTime<Utc>`] date and time according to the builtin formatter default pattern([`DateTimePattern::YyyyMmDdHhMmSs`]).
+/// Formats a [`DateTime<Utc>`] date and time according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
 ///
 /// # Examples
 ///
@@ -217,15 +1293,10 @@ fn formatter() -> Arc<Mutex<Option<DefaultDateTimeFormatter>>> {
 /// );
 /// ```
 pub fn format_date_time_utc_default(datetime: &DateTime<Utc>) -> String {
-    formatter()
-        .lock()
-        .unwrap()
-        .as_ref()
-        .unwrap()
-        .format_date_time_utc_default(datetime)
+    with_formatter(|formatter| formatter.format_date_time_utc_default(datetime))
 }
 
-/// Formats a [`NaiveDateTime`] according to the builtin formatter default pattern([`DateTimePattern::YyyyMmDdHhMmSs`]).
+/// Formats a [`NaiveDateTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
 ///
 /// # Examples
 ///
@@ -242,15 +1313,10 @@ pub fn format_date_time_utc_default(datetime: &DateTime<Utc>) -> String {
 /// );
 /// ```
 pub fn format_naive_date_time_utc_default(datetime: &NaiveDateTime) -> String {
-    formatter()
-        .lock()
-        .unwrap()
-        .as_ref()
-        .unwrap()
-        .format_naive_date_time_utc_default(datetime)
+    with_formatter(|formatter| formatter.format_naive_date_time_utc_default(datetime))
 }
 
-/// Formats a [`NaiveDateTime`] according to the builtin formatter default pattern([`DateTimePattern::YyyyMmDdHhMmSs`]).
+/// Formats a [`NaiveDateTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
 ///
 /// # Examples
 ///
@@ -267,12 +1333,7 @@ pub fn format_naive_date_time_utc_default(datetime: &NaiveDateTime) -> String {
 /// );
 /// ```
 pub fn format_naive_date_time_default(datetime: &NaiveDateTime) -> String {
-    formatter()
-        .lock()
-        .unwrap()
-        .as_ref()
-        .unwrap()
-        .format_naive_date_time_default(datetime)
+    with_formatter(|formatter| formatter.format_naive_date_time_default(datetime))
 }
 
 /// Formats a [`DateTime<Utc>`] according to the specified pattern.
@@ -302,12 +1363,7 @@ pub fn format_naive_date_time_default(datetime: &NaiveDateTime) -> String {
 /// );
 /// ```
 pub fn format_date_time_utc(datetime: &DateTime<Utc>, pattern: DateTimePattern) -> String {
-    formatter()
-        .lock()
-        .unwrap()
-        .as_ref()
-        .unwrap()
-        .format_date_time_utc(datetime, pattern)
+    with_formatter(|formatter| formatter.format_date_time_utc(datetime, pattern))
 }
 
 /// Formats a [`NaiveDateTime`] -> [`DateTime<Utc>`] according to the specified pattern.
@@ -336,12 +1392,7 @@ pub fn format_date_time_utc(datetime: &DateTime<Utc>, pattern: DateTimePattern)
 /// );
 /// ```
 pub fn format_naive_date_time_utc(datetime: &NaiveDateTime, pattern: DateTimePattern) -> String {
-    formatter()
-        .lock()
-        .unwrap()
-        .as_ref()
-        .unwrap()
-        .format_naive_date_time_utc(datetime, pattern)
+    with_formatter(|formatter| formatter.format_naive_date_time_utc(datetime, pattern))
 }
 
 /// Formats a [`NaiveDateTime`] according to the specified pattern.
@@ -370,10 +1421,424 @@ pub fn format_naive_date_time_utc(datetime: &NaiveDateTime, pattern: DateTimePat
 /// );
 /// ```
 pub fn format_naive_date_time(datetime: &NaiveDateTime, pattern: DateTimePattern) -> String {
-    formatter()
-        .lock()
-        .unwrap()
-        .as_ref()
-        .unwrap()
-        .format_naive_date_time(datetime, pattern)
+    with_formatter(|formatter| formatter.format_naive_date_time(datetime, pattern))
+}
+
+/// Formats a [`DateTime<Tz>`] in its own time zone according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone};
+/// use chronounit::formatter;
+///
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let datetime = shanghai.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+///
+/// assert_eq!(formatter::format_date_time_default(&datetime), "2024-03-01 09:00:00");
+/// ```
+pub fn format_date_time_default<Tz: TimeZone>(datetime: &DateTime<Tz>) -> String
+where
+    Tz::Offset: fmt::Display,
+{
+    with_formatter(|formatter| {
+        formatted(|w| {
+            write_date_time_with(&**formatter, w, datetime, formatter.activated_pattern())
+        })
+    })
+}
+
+/// Formats a [`DateTime<Tz>`] in its own time zone according to the specified pattern.
+///
+/// A default formatter with a zone renders the instant in its zone instead,
+/// see [`DateTimeFormatter::format_date_time`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let datetime = shanghai.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     formatter::format_date_time(&datetime, DateTimePattern::YyyyMmDdHhMmSsOffset),
+///     "2024-03-01 09:00:00 +08:00"
+/// );
+/// assert_eq!(
+///     formatter::format_date_time(&datetime, DateTimePattern::YyyyMmDdHhMmSsZone),
+///     "2024-03-01 09:00:00 +08:00"
+/// );
+/// ```
+pub fn format_date_time<Tz: TimeZone>(datetime: &DateTime<Tz>, pattern: DateTimePattern) -> String
+where
+    Tz::Offset: fmt::Display,
+{
+    with_formatter(|formatter| {
+        formatted(|w| write_date_time_with(&**formatter, w, datetime, pattern))
+    })
+}
+
+/// Converts a [`DateTime<Tz>`] to the time zone `zone`, then formats it according to
+/// the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use chronounit::formatter;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+///
+/// assert_eq!(
+///     formatter::format_date_time_in_default(&datetime_utc, &shanghai),
+///     "2024-03-01 09:00:00"
+/// );
+/// ```
+pub fn format_date_time_in_default<Tz: TimeZone, Z: TimeZone>(
+    datetime: &DateTime<Tz>,
+    zone: &Z,
+) -> String
+where
+    Z::Offset: fmt::Display,
+{
+    with_formatter(|formatter| {
+        let locale = formatter.activated_locale();
+        formatter
+            .activated_pattern()
+            .format_localized(&datetime.with_timezone(zone), locale)
+    })
+}
+
+/// Converts a [`DateTime<Tz>`] to the time zone `zone`, then formats it according to
+/// the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+/// let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+///
+/// assert_eq!(
+///     formatter::format_date_time_in(&datetime_utc, &new_york, DateTimePattern::YyyyMmDdHhMmSsOffset),
+///     "2024-02-29 20:00:00 -05:00"
+/// );
+/// ```
+pub fn format_date_time_in<Tz: TimeZone, Z: TimeZone>(
+    datetime: &DateTime<Tz>,
+    zone: &Z,
+    pattern: DateTimePattern,
+) -> String
+where
+    Z::Offset: fmt::Display,
+{
+    with_formatter(|formatter| {
+        pattern.format_localized(&datetime.with_timezone(zone), formatter.activated_locale())
+    })
+}
+
+/// [`DateTimeFormatter::write_date_time`] for a `dyn` formatter as well, which cannot call
+/// generic methods: a formatter with a zone writes the instant through the object-safe
+/// [`DateTimeFormatter::write_date_time_utc`], in its zone, others in the zone of `datetime`.
+fn write_date_time_with<Tz: TimeZone>(
+    formatter: &dyn DateTimeFormatter,
+    w: &mut dyn fmt::Write,
+    datetime: &DateTime<Tz>,
+    pattern: DateTimePattern,
+) -> fmt::Result
+where
+    Tz::Offset: fmt::Display,
+{
+    if formatter.activated_zone() != Zone::Utc {
+        return formatter.write_date_time_utc(w, &datetime.with_timezone(&Utc), pattern);
+    }
+
+    pattern.write_localized(w, datetime, formatter.activated_locale())
+}
+
+/// Writes a [`DateTime<Utc>`] into `w` according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+///
+/// let mut buffer = String::with_capacity(32);
+/// formatter::write_date_time_utc_default(&mut buffer, &datetime_utc).unwrap();
+///
+/// assert_eq!(buffer, "2024-03-12 22:55:00");
+/// ```
+pub fn write_date_time_utc_default(
+    w: &mut dyn fmt::Write,
+    datetime: &DateTime<Utc>,
+) -> fmt::Result {
+    with_formatter(|formatter| formatter.write_date_time_utc_default(w, datetime))
+}
+
+/// Writes a [`NaiveDateTime`] into `w` according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+pub fn write_naive_date_time_default(
+    w: &mut dyn fmt::Write,
+    datetime: &NaiveDateTime,
+) -> fmt::Result {
+    with_formatter(|formatter| formatter.write_naive_date_time_default(w, datetime))
+}
+
+/// Writes a [`DateTime<Utc>`] into `w` according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+///
+/// let mut buffer = String::new();
+/// formatter::write_date_time_utc(&mut buffer, &datetime_utc, DateTimePattern::YyyyMmDd).unwrap();
+/// buffer.push('T');
+/// formatter::write_date_time_utc(&mut buffer, &datetime_utc, DateTimePattern::HhMmSs).unwrap();
+///
+/// assert_eq!(buffer, "2024-03-12T22:55:00");
+/// ```
+pub fn write_date_time_utc(
+    w: &mut dyn fmt::Write,
+    datetime: &DateTime<Utc>,
+    pattern: DateTimePattern,
+) -> fmt::Result {
+    with_formatter(|formatter| formatter.write_date_time_utc(w, datetime, pattern))
+}
+
+/// Writes a [`NaiveDateTime`] into `w` according to the specified pattern.
+pub fn write_naive_date_time(
+    w: &mut dyn fmt::Write,
+    datetime: &NaiveDateTime,
+    pattern: DateTimePattern,
+) -> fmt::Result {
+    with_formatter(|formatter| formatter.write_naive_date_time(w, datetime, pattern))
+}
+
+/// Parses a [`DateTime<Utc>`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+///
+/// assert_eq!(
+///     formatter::parse_date_time_utc_default("2024-03-12 22:55:00"),
+///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap())
+/// );
+/// ```
+pub fn parse_date_time_utc_default(value: &str) -> Result<DateTime<Utc>, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_date_time_utc_default(value))
+}
+
+/// Parses a [`NaiveDateTime`] in UTC according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter;
+///
+/// let ndt = NaiveDate::from_ymd_opt(2024, 3, 12)
+///     .unwrap()
+///     .and_hms_opt(22, 55, 0)
+///     .unwrap();
+///
+/// assert_eq!(
+///     formatter::parse_naive_date_time_utc_default("2024-03-12 22:55:00"),
+///     Ok(ndt)
+/// );
+/// ```
+pub fn parse_naive_date_time_utc_default(value: &str) -> Result<NaiveDateTime, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_date_time_utc_default(value))
+}
+
+/// Parses a [`NaiveDateTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter;
+///
+/// let ndt = NaiveDate::from_ymd_opt(2024, 3, 12)
+///     .unwrap()
+///     .and_hms_opt(22, 55, 0)
+///     .unwrap();
+///
+/// assert_eq!(
+///     formatter::parse_naive_date_time_default("2024-03-12 22:55:00"),
+///     Ok(ndt)
+/// );
+/// ```
+pub fn parse_naive_date_time_default(value: &str) -> Result<NaiveDateTime, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_date_time_default(value))
+}
+
+/// Parses a [`NaiveDate`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter;
+///
+/// assert_eq!(
+///     formatter::parse_naive_date_default("2024-03-12 22:55:00"),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap())
+/// );
+/// ```
+pub fn parse_naive_date_default(value: &str) -> Result<NaiveDate, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_date_default(value))
+}
+
+/// Parses a [`NaiveTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use chronounit::formatter;
+///
+/// assert_eq!(
+///     formatter::parse_naive_time_default("2024-03-12 22:55:00"),
+///     Ok(NaiveTime::from_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+pub fn parse_naive_time_default(value: &str) -> Result<NaiveTime, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_time_default(value))
+}
+
+/// Parses a [`DateTime<Utc>`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     formatter::parse_date_time_utc("2024-03-12", DateTimePattern::YyyyMmDd),
+///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 0, 0, 0).unwrap())
+/// );
+/// assert_eq!(
+///     formatter::parse_date_time_utc("1710284100", DateTimePattern::Timestamp),
+///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap())
+/// );
+/// ```
+pub fn parse_date_time_utc(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<DateTime<Utc>, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_date_time_utc(value, pattern))
+}
+
+/// Parses a [`NaiveDateTime`] in UTC according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let ndt = NaiveDate::from_ymd_opt(2024, 3, 12)
+///     .unwrap()
+///     .and_hms_opt(22, 55, 0)
+///     .unwrap();
+///
+/// assert_eq!(
+///     formatter::parse_naive_date_time_utc("2024-03-12 22:55", DateTimePattern::YyyyMmDdHhMm),
+///     Ok(ndt)
+/// );
+/// ```
+pub fn parse_naive_date_time_utc(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveDateTime, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_date_time_utc(value, pattern))
+}
+
+/// Parses a [`NaiveDateTime`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let ndt = NaiveDate::from_ymd_opt(2024, 3, 12)
+///     .unwrap()
+///     .and_hms_opt(22, 55, 0)
+///     .unwrap();
+///
+/// assert_eq!(
+///     formatter::parse_naive_date_time("2024-03-12 22:55:00", DateTimePattern::YyyyMmDdHhMmSs),
+///     Ok(ndt)
+/// );
+/// assert!(formatter::parse_naive_date_time("2024-03-12 22:55", DateTimePattern::YyyyMmDdHhMmSs).is_err());
+/// ```
+pub fn parse_naive_date_time(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveDateTime, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_date_time(value, pattern))
+}
+
+/// Parses a [`NaiveDate`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     formatter::parse_naive_date("03/12/2024", DateTimePattern::MmDdYyyy),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap())
+/// );
+/// ```
+pub fn parse_naive_date(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveDate, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_date(value, pattern))
+}
+
+/// Parses a [`NaiveTime`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use chronounit::formatter;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     formatter::parse_naive_time("22:55:00", DateTimePattern::HhMmSs),
+///     Ok(NaiveTime::from_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+pub fn parse_naive_time(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveTime, ParseDateTimeError> {
+    with_formatter(|formatter| formatter.parse_naive_time(value, pattern))
 }
@@ -0,0 +1,165 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! Compiled patterns of a [`DateTimeFormatter`](crate::formatter::DateTimeFormatter): a
+//! [`DateTimePattern`] parsed once, for formatting many date-times with it.
+//!
+//! [`DateTimePattern::YyyyMmDdHhMmSs`] and [`DateTimePattern::YyyyMmDdHhMmSsSss`], the layouts
+//! of most logs, are written digit by digit into a stack buffer, without parsing a strftime
+//! string or going through chrono items at all.
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::format::Item;
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Timelike};
+
+use crate::formatter::locale::Locale;
+use crate::formatter::pattern::DateTimePattern;
+
+// ----------------------------------------------------------------
+
+/// `CompiledPattern` a [`DateTimePattern`] parsed once, for formatting many date-times with it.
+///
+/// The names are English, see [`DateTimeFormatter::activated_locale`](crate::formatter::DateTimeFormatter::activated_locale)
+/// for the localized ones.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter::compiled::CompiledPattern;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let compiled = CompiledPattern::new(DateTimePattern::YyyyMmDdHhMmSsSss);
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+///
+/// assert_eq!(compiled.pattern(), &DateTimePattern::YyyyMmDdHhMmSsSss);
+/// assert_eq!(compiled.format(&datetime_utc), "2024-03-01 13:05:00.000");
+///
+/// let mut buffer = String::new();
+/// compiled.write(&mut buffer, &datetime_utc).unwrap();
+/// assert_eq!(buffer, "2024-03-01 13:05:00.000");
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompiledPattern {
+    pattern: DateTimePattern,
+    layout: Layout,
+}
+
+#[derive(Clone, Debug)]
+enum Layout {
+    /// `%Y-%m-%d %H:%M:%S`, followed by `%.3f` if `millis`.
+    YyyyMmDdHhMmSs { millis: bool },
+    /// The parsed chrono items.
+    Items(Arc<[Item<'static>]>),
+    /// Written by the pattern itself, as the counts since the epoch and the UTC-only patterns.
+    Pattern,
+}
+
+impl CompiledPattern {
+    /// Compiles `pattern`, which is free for [`DateTimePattern::YyyyMmDdHhMmSs`],
+    /// [`DateTimePattern::YyyyMmDdHhMmSsSss`] and the custom patterns, parsed when created.
+    pub fn new(pattern: DateTimePattern) -> Self {
+        let layout = match &pattern {
+            DateTimePattern::YyyyMmDdHhMmSs => Layout::YyyyMmDdHhMmSs { millis: false },
+            DateTimePattern::YyyyMmDdHhMmSsSss => Layout::YyyyMmDdHhMmSs { millis: true },
+            _ if pattern.is_epoch() || pattern.is_utc_only() => Layout::Pattern,
+            DateTimePattern::Custom(custom) => Layout::Items(custom.shared_items()),
+            _ => Layout::Items(Arc::from(pattern.items().into_owned())),
+        };
+
+        CompiledPattern { pattern, layout }
+    }
+
+    /// The pattern compiled.
+    pub fn pattern(&self) -> &DateTimePattern {
+        &self.pattern
+    }
+
+    /// Formats `datetime` in its own time zone, or in UTC for [`DateTimePattern::HttpDate`].
+    pub fn format<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: fmt::Display,
+    {
+        let mut formatted = String::with_capacity(32);
+        self.write(&mut formatted, datetime)
+            .expect("a formatting trait implementation returned an error");
+
+        formatted
+    }
+
+    /// Writes `datetime` into `w` as [`CompiledPattern::format`], without an intermediate [`String`].
+    pub fn write<Tz: TimeZone>(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Tz>,
+    ) -> fmt::Result
+    where
+        Tz::Offset: fmt::Display,
+    {
+        match &self.layout {
+            Layout::YyyyMmDdHhMmSs { millis } => {
+                let local = datetime.naive_local();
+                if (0..=9999).contains(&local.year()) {
+                    return write_yyyy_mm_dd_hh_mm_ss(w, &local, *millis);
+                }
+
+                datetime
+                    .format_with_items(self.pattern.items().iter())
+                    .write_to(w) // Signed years, beyond the four digits
+            }
+            Layout::Items(items) => datetime.format_with_items(items.iter()).write_to(w),
+            Layout::Pattern => self.pattern.write_localized(w, datetime, Locale::EnUs),
+        }
+    }
+}
+
+/// Writes `datetime` as `%Y-%m-%d %H:%M:%S`, and `%.3f` if `millis`, for a year in `0..=9999`.
+fn write_yyyy_mm_dd_hh_mm_ss(
+    w: &mut dyn fmt::Write,
+    datetime: &NaiveDateTime,
+    millis: bool,
+) -> fmt::Result {
+    let nanos = datetime.nanosecond();
+    let mut buffer = *b"0000-00-00 00:00:00.000";
+    put_digits(&mut buffer[0..4], datetime.year() as u32);
+    put_digits(&mut buffer[5..7], datetime.month());
+    put_digits(&mut buffer[8..10], datetime.day());
+    put_digits(&mut buffer[11..13], datetime.hour());
+    put_digits(&mut buffer[14..16], datetime.minute());
+    // A leap second, from 1_000_000_000 nanoseconds, is the 60th as in chrono
+    put_digits(
+        &mut buffer[17..19],
+        datetime.second() + nanos / 1_000_000_000,
+    );
+    put_digits(&mut buffer[20..23], nanos / 1_000_000 % 1_000);
+
+    let len = if millis { buffer.len() } else { 19 };
+    w.write_str(std::str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?)
+}
+
+/// Fills `slot` with the last decimal digits of `value`, zero-padded.
+fn put_digits(slot: &mut [u8], mut value: u32) {
+    for digit in slot.iter_mut().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
@@ -0,0 +1,214 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! Translation of Java `DateTimeFormatter` patterns, such as `yyyy-MM-dd HH:mm:ss.SSS`,
+//! into `chrono` format items.
+//!
+//! | Letters | Meaning | Example |
+//! |---|---|---|
+//! | `y`, `yyy`, `yyyy`, `u`, `uuuu` | year | `2024` |
+//! | `yy`, `uu` | two-digit year | `24` |
+//! | `Y`, `YYYY` / `YY` | ISO week-based year | `2024` / `24` |
+//! | `Q` / `QQQ` | quarter | `1` / `Q1` |
+//! | `M`, `L` / `MM`, `LL` | month | `3` / `03` |
+//! | `MMM`, `LLL` / `MMMM`, `LLLL` | month name | `Mar` / `March` |
+//! | `w` / `ww` | ISO week of week-based year | `9` / `09` |
+//! | `d` / `dd` | day of month | `5` / `05` |
+//! | `D` / `DDD` | day of year | `72` / `072` |
+//! | `E`, `EE`, `EEE` / `EEEE` | day of week name | `Tue` / `Tuesday` |
+//! | `a` | AM/PM | `PM` |
+//! | `H` / `HH` | hour of day (0-23) | `7` / `07` |
+//! | `h` / `hh` | clock hour of AM/PM (1-12) | `7` / `07` |
+//! | `m` / `mm` | minute | `5` / `05` |
+//! | `s` / `ss` | second | `5` / `05` |
+//! | `S` to `SSSSSSSSS` | fraction of second | `250` |
+//! | `n` | nano of second | `250000000` |
+//! | `z`, `zz`, `zzz` | time zone name | `UTC` |
+//! | `XX` / `XXX` | offset, `Z` for zero | `+0800` / `+08:00` |
+//! | `xx` / `xxx` | offset | `+0000` / `+00:00` |
+//! | `Z`, `ZZ`, `ZZZ` / `ZZZZZ` | offset | `+0000` / `Z` |
+//!
+//! Text in single quotes is literal and `''` is a single quote. A fraction of second is
+//! written with 3, 6 or 9 digits, so `S` and `SS` are padded to milliseconds, `SSSS` and
+//! `SSSSS` to microseconds, and so on. Optional sections `[...]` are reported as unsupported,
+//! since `chrono` items cannot be skipped, as is any other letter or count of a supported letter.
+//! Names are always in English.
+//!
+//! ICU skeletons, such as `yMMMd`, are out of scope: they describe the fields to show rather
+//! than a layout, so they are read as plain patterns, e.g. `yMMMd` as `2024Mar12`.
+
+// ----------------------------------------------------------------
+
+use chrono::format::{Fixed, Item, Numeric, Pad, StrftimeItems};
+
+use crate::error::{ParseJavaPatternError, ParseJavaPatternErrorKind};
+
+// ----------------------------------------------------------------
+
+/// Compiles a Java `DateTimeFormatter` pattern into `chrono` format items.
+///
+/// See [`DateTimePattern::java`](crate::formatter::pattern::DateTimePattern::java) to use the
+/// pattern with the formatter.
+///
+/// # Arguments
+/// `pattern` - A Java pattern, e.g. `yyyy-MM-dd'T'HH:mm:ss.SSSXXX`.
+///
+/// # Returns
+/// The format items, or a [`ParseJavaPatternError`] pointing at the offending letter.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::error::ParseJavaPatternErrorKind;
+/// use chronounit::formatter::java;
+///
+/// let items = java::compile("yyyy-MM-dd'T'HH:mm:ss.SSSXXX").unwrap();
+/// let datetime = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+/// assert_eq!(
+///     datetime.format_with_items(items.iter()).to_string(),
+///     "2024-03-12T22:55:00.000Z"
+/// );
+///
+/// let err = java::compile("G yyyy").unwrap_err();
+/// assert_eq!(err.kind(), &ParseJavaPatternErrorKind::UnsupportedLetter('G', 1));
+/// assert_eq!(err.to_string(), "unsupported pattern letter `G` at position 0 in `G yyyy`");
+/// ```
+pub fn compile(pattern: &str) -> Result<Vec<Item<'static>>, ParseJavaPatternError> {
+    let error = |kind, position| Err(ParseJavaPatternError::new(pattern, kind, position));
+
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        match c {
+            '\'' => {
+                // `''` is an escaped quote, inside and outside of a quoted literal.
+                if chars.next_if(|&(_, c)| c == '\'').is_some() {
+                    literal.push('\'');
+                    continue;
+                }
+                loop {
+                    match chars.next() {
+                        Some((_, '\'')) if chars.next_if(|&(_, c)| c == '\'').is_some() => {
+                            literal.push('\'')
+                        }
+                        Some((_, '\'')) => break,
+                        Some((_, c)) => literal.push(c),
+                        None => {
+                            return error(ParseJavaPatternErrorKind::UnterminatedQuote, position)
+                        }
+                    }
+                }
+            }
+            '[' | ']' => return error(ParseJavaPatternErrorKind::OptionalSection, position),
+            '#' | '{' | '}' => {
+                return error(ParseJavaPatternErrorKind::ReservedCharacter(c), position)
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut count = 1;
+                while chars.next_if(|&(_, next)| next == c).is_some() {
+                    count += 1;
+                }
+                let field = match letter(c, count) {
+                    Some(field) => field,
+                    None => {
+                        return error(
+                            ParseJavaPatternErrorKind::UnsupportedLetter(c, count),
+                            position,
+                        )
+                    }
+                };
+
+                if !literal.is_empty() {
+                    items.push(Item::OwnedLiteral(
+                        std::mem::take(&mut literal).into_boxed_str(),
+                    ));
+                }
+                items.extend(field);
+            }
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        items.push(Item::OwnedLiteral(literal.into_boxed_str()));
+    }
+
+    Ok(items)
+}
+
+// ----------------------------------------------------------------
+
+/// The items of `count` repetitions of the pattern letter `c`, if supported.
+fn letter(c: char, count: usize) -> Option<Vec<Item<'static>>> {
+    let numeric = |numeric: Numeric| match count {
+        1 => Some(Item::Numeric(numeric, Pad::None)),
+        2 => Some(Item::Numeric(numeric, Pad::Zero)),
+        _ => None,
+    };
+    let fixed = |fixed: Fixed| Some(Item::Fixed(fixed));
+
+    let item = match (c, count) {
+        ('y' | 'u', 2) => Some(Item::Numeric(Numeric::YearMod100, Pad::Zero)),
+        ('y' | 'u', 1 | 3) => Some(Item::Numeric(Numeric::Year, Pad::None)),
+        ('y' | 'u', 4) => Some(Item::Numeric(Numeric::Year, Pad::Zero)),
+        ('Y', 2) => Some(Item::Numeric(Numeric::IsoYearMod100, Pad::Zero)),
+        ('Y', 1 | 3) => Some(Item::Numeric(Numeric::IsoYear, Pad::None)),
+        ('Y', 4) => Some(Item::Numeric(Numeric::IsoYear, Pad::Zero)),
+        ('Q' | 'q', 1) => Some(Item::Numeric(Numeric::Quarter, Pad::None)),
+        ('Q' | 'q', 3) => {
+            return Some(vec![
+                Item::Literal("Q"),
+                Item::Numeric(Numeric::Quarter, Pad::None),
+            ])
+        }
+        ('M' | 'L', 1 | 2) => numeric(Numeric::Month),
+        ('M' | 'L', 3) => fixed(Fixed::ShortMonthName),
+        ('M' | 'L', 4) => fixed(Fixed::LongMonthName),
+        ('w', _) => numeric(Numeric::IsoWeek),
+        ('d', _) => numeric(Numeric::Day),
+        ('D', 1) => Some(Item::Numeric(Numeric::Ordinal, Pad::None)),
+        ('D', 3) => Some(Item::Numeric(Numeric::Ordinal, Pad::Zero)),
+        ('E', 1..=3) => fixed(Fixed::ShortWeekdayName),
+        ('E', 4) => fixed(Fixed::LongWeekdayName),
+        ('a', 1) => fixed(Fixed::UpperAmPm),
+        ('H', _) => numeric(Numeric::Hour),
+        ('h', _) => numeric(Numeric::Hour12),
+        ('m', _) => numeric(Numeric::Minute),
+        ('s', _) => numeric(Numeric::Second),
+        // `chrono` only has fixed precisions without a leading dot through `strftime`,
+        // shorter runs are padded to the next one.
+        ('S', 1..=3) => strftime("%3f"),
+        ('S', 4..=6) => strftime("%6f"),
+        ('S', 7..=9) => strftime("%9f"),
+        ('n', 1) => Some(Item::Numeric(Numeric::Nanosecond, Pad::None)),
+        ('z', 1..=3) => fixed(Fixed::TimezoneName),
+        ('X', 2) => fixed(Fixed::TimezoneOffsetZ),
+        ('X', 3) | ('Z', 5) => fixed(Fixed::TimezoneOffsetColonZ),
+        ('x', 2) | ('Z', 1..=3) => fixed(Fixed::TimezoneOffset),
+        ('x', 3) => fixed(Fixed::TimezoneOffsetColon),
+        _ => None,
+    };
+
+    item.map(|item| vec![item])
+}
+
+fn strftime(specifier: &'static str) -> Option<Item<'static>> {
+    StrftimeItems::new(specifier).next()
+}
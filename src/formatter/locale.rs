@@ -0,0 +1,426 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! Locales of a [`DateTimeFormatter`](crate::formatter::DateTimeFormatter): the month and
+//! weekday names, the AM/PM markers and the default date and time layouts.
+//!
+//! The locale data is embedded in the crate, so it renders identically everywhere, without
+//! a system locale. It applies to formatting only: parsing reads the English names,
+//! and the interchange patterns, such as [`DateTimePattern::HttpDate`], are always in English.
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::format::{Fixed, Item};
+use chrono::{DateTime, Datelike, TimeZone, Timelike};
+
+use crate::error::ParseLocaleError;
+use crate::formatter::pattern::DateTimePattern;
+
+// ----------------------------------------------------------------
+
+/// [`Locale`] the language and region a formatter renders names and layouts in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Locale {
+    /// `en_US` English (United States), the names of `strftime`.
+    #[default]
+    EnUs,
+    /// `zh_CN` Chinese (China).
+    ZhCn,
+    /// `ja_JP` Japanese (Japan).
+    JaJp,
+    /// `de_DE` German (Germany).
+    DeDe,
+    /// `fr_FR` French (France).
+    FrFr,
+    /// `es_ES` Spanish (Spain).
+    EsEs,
+}
+
+/// [`LocaleData`] the names and layouts of a [`Locale`].
+struct LocaleData {
+    months: [&'static str; 12],
+    months_abbr: [&'static str; 12],
+    /// From Sunday.
+    weekdays: [&'static str; 7],
+    /// From Sunday.
+    weekdays_abbr: [&'static str; 7],
+    am_pm: [&'static str; 2],
+    am_pm_lower: [&'static str; 2],
+    date: &'static str,
+    time: &'static str,
+    date_time: &'static str,
+}
+
+const EN_US: LocaleData = LocaleData {
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_abbr: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekdays: [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+    weekdays_abbr: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    am_pm: ["AM", "PM"],
+    am_pm_lower: ["am", "pm"],
+    date: "%m/%d/%Y",
+    time: "%I:%M:%S %p",
+    date_time: "%m/%d/%Y %I:%M:%S %p",
+};
+
+const ZH_CN: LocaleData = LocaleData {
+    months: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    months_abbr: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "星期日",
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+    ],
+    weekdays_abbr: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+    am_pm: ["上午", "下午"],
+    am_pm_lower: ["上午", "下午"],
+    date: "%Y年%-m月%-d日",
+    time: "%H:%M:%S",
+    date_time: "%Y年%-m月%-d日 %H:%M:%S",
+};
+
+const JA_JP: LocaleData = LocaleData {
+    months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    months_abbr: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "日曜日",
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+    ],
+    weekdays_abbr: ["日", "月", "火", "水", "木", "金", "土"],
+    am_pm: ["午前", "午後"],
+    am_pm_lower: ["午前", "午後"],
+    date: "%Y/%m/%d",
+    time: "%H:%M:%S",
+    date_time: "%Y/%m/%d %H:%M:%S",
+};
+
+const DE_DE: LocaleData = LocaleData {
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    months_abbr: [
+        "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+    ],
+    weekdays: [
+        "Sonntag",
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+    ],
+    weekdays_abbr: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+    am_pm: ["AM", "PM"],
+    am_pm_lower: ["am", "pm"],
+    date: "%d.%m.%Y",
+    time: "%H:%M:%S",
+    date_time: "%d.%m.%Y %H:%M:%S",
+};
+
+const FR_FR: LocaleData = LocaleData {
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    months_abbr: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    weekdays: [
+        "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+    weekdays_abbr: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    am_pm: ["AM", "PM"],
+    am_pm_lower: ["am", "pm"],
+    date: "%d/%m/%Y",
+    time: "%H:%M:%S",
+    date_time: "%d/%m/%Y %H:%M:%S",
+};
+
+const ES_ES: LocaleData = LocaleData {
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    months_abbr: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    weekdays: [
+        "domingo",
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+    ],
+    weekdays_abbr: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+    am_pm: ["a. m.", "p. m."],
+    am_pm_lower: ["a. m.", "p. m."],
+    date: "%d/%m/%Y",
+    time: "%H:%M:%S",
+    date_time: "%d/%m/%Y %H:%M:%S",
+};
+
+impl Locale {
+    /// `EN_US_NAME` `en_US`
+    pub const EN_US_NAME: &'static str = "en_US";
+    /// `ZH_CN_NAME` `zh_CN`
+    pub const ZH_CN_NAME: &'static str = "zh_CN";
+    /// `JA_JP_NAME` `ja_JP`
+    pub const JA_JP_NAME: &'static str = "ja_JP";
+    /// `DE_DE_NAME` `de_DE`
+    pub const DE_DE_NAME: &'static str = "de_DE";
+    /// `FR_FR_NAME` `fr_FR`
+    pub const FR_FR_NAME: &'static str = "fr_FR";
+    /// `ES_ES_NAME` `es_ES`
+    pub const ES_ES_NAME: &'static str = "es_ES";
+
+    // ----------------------------------------------------------------
+
+    /// Retrieves the POSIX name of the locale, e.g. `zh_CN`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::EnUs => Locale::EN_US_NAME,
+            Locale::ZhCn => Locale::ZH_CN_NAME,
+            Locale::JaJp => Locale::JA_JP_NAME,
+            Locale::DeDe => Locale::DE_DE_NAME,
+            Locale::FrFr => Locale::FR_FR_NAME,
+            Locale::EsEs => Locale::ES_ES_NAME,
+        }
+    }
+
+    /// The full name of the month `month`, from `1` to `12`, e.g. `三月` for March in `zh_CN`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::locale::Locale;
+    ///
+    /// assert_eq!(Locale::DeDe.month_name(3), Some("März"));
+    /// assert_eq!(Locale::DeDe.month_name(13), None);
+    /// ```
+    pub fn month_name(&self, month: u32) -> Option<&'static str> {
+        self.data()
+            .months
+            .get(month.checked_sub(1)? as usize)
+            .copied()
+    }
+
+    /// The full name of the weekday, e.g. `金曜日` for Friday in `ja_JP`.
+    pub fn weekday_name(&self, weekday: chrono::Weekday) -> &'static str {
+        self.data().weekdays[weekday.num_days_from_sunday() as usize]
+    }
+
+    /// The default date layout of the locale, e.g. `%d.%m.%Y` in `de_DE`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use chronounit::formatter::locale::Locale;
+    /// use chronounit::formatter::{DateTimeFormatter, DefaultDateTimeFormatter};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let dtf = DefaultDateTimeFormatter::new(Locale::ZhCn.date_pattern());
+    ///
+    /// assert_eq!(dtf.format_naive_date_time_default(&date), "2024年3月1日");
+    /// ```
+    pub fn date_pattern(&self) -> DateTimePattern {
+        Locale::layout(self.data().date)
+    }
+
+    /// The default time layout of the locale, e.g. `%I:%M:%S %p` in `en_US`.
+    pub fn time_pattern(&self) -> DateTimePattern {
+        Locale::layout(self.data().time)
+    }
+
+    /// The default date and time layout of the locale, e.g. `%Y/%m/%d %H:%M:%S` in `ja_JP`.
+    pub fn date_time_pattern(&self) -> DateTimePattern {
+        Locale::layout(self.data().date_time)
+    }
+
+    fn layout(layout: &'static str) -> DateTimePattern {
+        DateTimePattern::custom(layout).expect("a valid builtin layout")
+    }
+
+    fn data(&self) -> &'static LocaleData {
+        match self {
+            Locale::EnUs => &EN_US,
+            Locale::ZhCn => &ZH_CN,
+            Locale::JaJp => &JA_JP,
+            Locale::DeDe => &DE_DE,
+            Locale::FrFr => &FR_FR,
+            Locale::EsEs => &ES_ES,
+        }
+    }
+
+    /// Replaces the month, weekday and AM/PM items of `items` by their names in this locale
+    /// for `datetime`, leaving the other items to `chrono`.
+    pub(crate) fn localize<Tz: TimeZone>(
+        &self,
+        items: &[Item<'static>],
+        datetime: &DateTime<Tz>,
+    ) -> Vec<Item<'static>> {
+        let data = self.data();
+        let (month0, weekday) = (
+            datetime.month0() as usize,
+            datetime.weekday().num_days_from_sunday() as usize,
+        );
+        let pm = (datetime.hour() >= 12) as usize;
+
+        items
+            .iter()
+            .map(|item| match item {
+                Item::Fixed(Fixed::LongMonthName) => Item::Literal(data.months[month0]),
+                Item::Fixed(Fixed::ShortMonthName) => Item::Literal(data.months_abbr[month0]),
+                Item::Fixed(Fixed::LongWeekdayName) => Item::Literal(data.weekdays[weekday]),
+                Item::Fixed(Fixed::ShortWeekdayName) => Item::Literal(data.weekdays_abbr[weekday]),
+                Item::Fixed(Fixed::UpperAmPm) => Item::Literal(data.am_pm[pm]),
+                Item::Fixed(Fixed::LowerAmPm) => Item::Literal(data.am_pm_lower[pm]),
+                item => item.clone(),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Locale {
+    /// Formats the [`Locale`] by its POSIX name, e.g. `zh_CN`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    /// Parses a [`Locale`] from its POSIX name or BCP 47 tag, case-insensitively,
+    /// ignoring a POSIX encoding, e.g. `zh_CN`, `zh-CN` or `zh_CN.UTF-8`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::locale::Locale;
+    ///
+    /// assert_eq!("ja_JP".parse::<Locale>(), Ok(Locale::JaJp));
+    /// assert_eq!("de-de".parse::<Locale>(), Ok(Locale::DeDe));
+    /// assert_eq!("fr_FR.UTF-8".parse::<Locale>(), Ok(Locale::FrFr));
+    /// assert!("pt_BR".parse::<Locale>().is_err());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let name = value.split(['.', '@']).next().unwrap_or(value);
+        [
+            Locale::EnUs,
+            Locale::ZhCn,
+            Locale::JaJp,
+            Locale::DeDe,
+            Locale::FrFr,
+            Locale::EsEs,
+        ]
+        .into_iter()
+        .find(|locale| locale.name().eq_ignore_ascii_case(&name.replace('-', "_")))
+        .ok_or_else(|| ParseLocaleError::new(value))
+    }
+}
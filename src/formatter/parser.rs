@@ -0,0 +1,525 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! Parsing of values with a [`DateTimePattern`], the counterpart of the `format_*` family
+//! of [`DateTimeFormatter`](crate::formatter::DateTimeFormatter).
+//!
+//! A pattern without time fields, e.g. [`DateTimePattern::YyyyMmDd`], resolves to midnight
+//! when a date-time is requested. [`DateTimePattern::Timestamp`], [`DateTimePattern::Epoch`]
+//! and [`DateTimePattern::EpochFractional`] read signed counts since the unix epoch.
+//! A parsed offset is honoured when converting to UTC, otherwise the value is a local time
+//! in the zone of the formatter, UTC by default. A zone name is skipped since
+//! it cannot be resolved to an offset.
+//!
+//! [`LenientParser`] tries several patterns in order, for inbound data in mixed formats.
+
+// ----------------------------------------------------------------
+
+use chrono::format::{Fixed, Item, Numeric, ParseErrorKind, Parsed};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use crate::error::{
+    LenientParseError, LenientParseErrorKind, ParseDateTimeError, ParseDateTimeErrorKind,
+};
+use crate::formatter::pattern::DateTimePattern;
+use crate::formatter::zone::{AmbiguousTimePolicy, Zone};
+
+/// `NANOS_PER_SECOND` the number of nanoseconds in one second.
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+// ----------------------------------------------------------------
+
+pub(crate) fn parse_date_time_utc(
+    value: &str,
+    pattern: DateTimePattern,
+    zone: Zone,
+    policy: AmbiguousTimePolicy,
+) -> Result<DateTime<Utc>, ParseDateTimeError> {
+    if pattern.is_epoch() {
+        return epoch_of(value, &pattern).map(|datetime| datetime.and_utc());
+    }
+
+    let items = pattern.items();
+    let parsed = parse(value, &pattern, &items)?;
+    let zone = if pattern.is_utc_only() {
+        Zone::Utc
+    } else {
+        zone
+    };
+
+    match parsed.to_datetime() {
+        Ok(datetime) => Ok(datetime.with_timezone(&Utc)),
+        // A zone name alone, e.g. `CST`, does not resolve to an offset.
+        Err(error) if error.kind() == ParseErrorKind::NotEnough && has_zone_name(&items) => {
+            Err(error_of(
+                value,
+                &pattern,
+                error,
+                ParseDateTimeErrorKind::MissingOffset,
+            ))
+        }
+        // No offset in the pattern: the value is a local time in `zone`.
+        Err(error) if error.kind() == ParseErrorKind::NotEnough => {
+            let datetime = naive_date_time_of(value, &pattern, &items, &parsed)?;
+            zone.resolve(&datetime, policy)
+                .map(|datetime| datetime.with_timezone(&Utc))
+                .map_err(|error| {
+                    ParseDateTimeError::new(
+                        value,
+                        pattern,
+                        ParseDateTimeErrorKind::LocalTime(error.kind()),
+                        None,
+                    )
+                })
+        }
+        Err(error) => Err(error_of(
+            value,
+            &pattern,
+            error,
+            ParseDateTimeErrorKind::MissingDate,
+        )),
+    }
+}
+
+pub(crate) fn parse_naive_date_time(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveDateTime, ParseDateTimeError> {
+    if pattern.is_epoch() {
+        return epoch_of(value, &pattern);
+    }
+
+    let items = pattern.items();
+    let parsed = parse(value, &pattern, &items)?;
+
+    naive_date_time_of(value, &pattern, &items, &parsed)
+}
+
+pub(crate) fn parse_naive_date(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveDate, ParseDateTimeError> {
+    if pattern.is_epoch() {
+        return epoch_of(value, &pattern).map(|datetime| datetime.date());
+    }
+
+    let items = pattern.items();
+    let parsed = parse(value, &pattern, &items)?;
+
+    parsed
+        .to_naive_date()
+        .map_err(|error| error_of(value, &pattern, error, ParseDateTimeErrorKind::MissingDate))
+}
+
+pub(crate) fn parse_naive_time(
+    value: &str,
+    pattern: DateTimePattern,
+) -> Result<NaiveTime, ParseDateTimeError> {
+    if pattern.is_epoch() {
+        return epoch_of(value, &pattern).map(|datetime| datetime.time());
+    }
+
+    let items = pattern.items();
+    let parsed = parse(value, &pattern, &items)?;
+
+    parsed
+        .to_naive_time()
+        .map_err(|error| error_of(value, &pattern, error, ParseDateTimeErrorKind::MissingTime))
+}
+
+// ----------------------------------------------------------------
+
+/// `AUTO_PATTERNS` the custom patterns detected by [`LenientParser::auto`],
+/// next to the builtin ones.
+const AUTO_PATTERNS: [&str; 7] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%m/%d/%Y %H:%M:%S",
+    "%m/%d/%Y %H:%M",
+    "%d-%m-%Y %H:%M:%S",
+    "%d-%m-%Y %H:%M",
+    "%Y%m%dT%H%M%S%.f",
+    "%Y%m%d",
+];
+
+/// [`AmbiguityPolicy`] how a [`LenientParser`] treats a value whose day and month
+/// could be read either way, e.g. `03/01/2024`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AmbiguityPolicy {
+    /// The first matching pattern wins, the other reading is reported by
+    /// [`LenientMatch::alternative`].
+    #[default]
+    PreferFirst,
+    /// An ambiguous value is rejected with [`LenientParseErrorKind::Ambiguous`].
+    Reject,
+}
+
+/// [`LenientMatch`] the result of a [`LenientParser`]: the value and the pattern which matched.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LenientMatch {
+    value: NaiveDateTime,
+    pattern: DateTimePattern,
+    alternative: Option<NaiveDateTime>,
+}
+
+impl LenientMatch {
+    /// The parsed value, see [`DateTimeFormatter::parse_naive_date_time`](crate::formatter::DateTimeFormatter::parse_naive_date_time).
+    pub fn value(&self) -> NaiveDateTime {
+        self.value
+    }
+
+    /// The pattern which matched.
+    pub fn pattern(&self) -> DateTimePattern {
+        self.pattern.clone()
+    }
+
+    /// The value with day and month swapped, if that reading is a different valid date.
+    pub fn alternative(&self) -> Option<NaiveDateTime> {
+        self.alternative
+    }
+
+    /// Whether the day and month of the value could be read either way.
+    pub fn is_ambiguous(&self) -> bool {
+        self.alternative.is_some()
+    }
+}
+
+/// [`LenientParser`] parses a value with the first of several [`DateTimePattern`]s which matches.
+///
+/// A pattern with day and month before the year, such as [`DateTimePattern::MmDdYyyy`] or
+/// [`DateTimePattern::DdMmYyyy`], is ambiguous when both fields are 12 or less, and the
+/// [`AmbiguityPolicy`] decides. Year-first layouts are read as ISO 8601 and never ambiguous.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::formatter::parser::{AmbiguityPolicy, LenientParser};
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let parser = LenientParser::new([DateTimePattern::YyyyMmDd, DateTimePattern::DdMmYyyy]);
+///
+/// let matched = parser.parse("2024-03-01").unwrap();
+/// assert_eq!(matched.pattern(), DateTimePattern::YyyyMmDd);
+/// assert!(!matched.is_ambiguous());
+///
+/// let matched = parser.parse("01-03-2024").unwrap();
+/// assert_eq!(matched.pattern(), DateTimePattern::DdMmYyyy);
+/// assert_eq!(matched.value().date(), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+/// assert_eq!(
+///     matched.alternative().map(|value| value.date()),
+///     NaiveDate::from_ymd_opt(2024, 1, 3)
+/// );
+///
+/// let parser = parser.with_ambiguity_policy(AmbiguityPolicy::Reject);
+/// assert!(parser.parse("01-03-2024").is_err());
+/// assert!(parser.parse("13-03-2024").is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LenientParser {
+    patterns: Vec<DateTimePattern>,
+    policy: AmbiguityPolicy,
+}
+
+impl Default for LenientParser {
+    /// override
+    fn default() -> Self {
+        LenientParser::auto()
+    }
+}
+
+impl LenientParser {
+    /// Creates a [`LenientParser`] which tries `patterns` in order.
+    pub fn new(patterns: impl IntoIterator<Item = DateTimePattern>) -> Self {
+        LenientParser {
+            patterns: patterns.into_iter().collect(),
+            policy: AmbiguityPolicy::default(),
+        }
+    }
+
+    /// Creates a [`LenientParser`] which detects the common date and date-time layouts:
+    /// the builtin date-time and date patterns, ISO 8601 with `T`, [`DateTimePattern::MmDdYyyy`]
+    /// and [`DateTimePattern::DdMmYyyy`] followed by an optional time, the compact ISO 8601
+    /// `20240301` and `20240301T120000`, and [`DateTimePattern::Timestamp`].
+    ///
+    /// The timestamp is tried last, so eight digits forming a valid date are read as a date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::parser::LenientParser;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    ///
+    /// let parser = LenientParser::auto();
+    ///
+    /// assert_eq!(parser.parse("2024-03-01").unwrap().pattern(), DateTimePattern::YyyyMmDd);
+    /// assert_eq!(parser.parse("03/01/2024").unwrap().pattern(), DateTimePattern::MmDdYyyy);
+    /// assert_eq!(parser.parse("20240301").unwrap().pattern(), DateTimePattern::custom("%Y%m%d").unwrap());
+    /// assert_eq!(
+    ///     parser.parse("01-03-2024 10:00").unwrap().pattern(),
+    ///     DateTimePattern::custom("%d-%m-%Y %H:%M").unwrap()
+    /// );
+    /// ```
+    pub fn auto() -> Self {
+        let custom = |pattern| DateTimePattern::custom(pattern).expect("valid pattern");
+
+        LenientParser::new([
+            DateTimePattern::YyyyMmDdHhMmSs,
+            DateTimePattern::YyyyMmDdHhMmSsSss,
+            DateTimePattern::YyyyMmDdHhMm,
+            custom(AUTO_PATTERNS[0]),
+            DateTimePattern::YyyyMmDd,
+            DateTimePattern::MmDdYyyy,
+            custom(AUTO_PATTERNS[1]),
+            custom(AUTO_PATTERNS[2]),
+            DateTimePattern::DdMmYyyy,
+            custom(AUTO_PATTERNS[3]),
+            custom(AUTO_PATTERNS[4]),
+            custom(AUTO_PATTERNS[5]),
+            custom(AUTO_PATTERNS[6]),
+            DateTimePattern::Timestamp,
+        ])
+    }
+
+    /// Sets the [`AmbiguityPolicy`], [`AmbiguityPolicy::PreferFirst`] by default.
+    pub fn with_ambiguity_policy(mut self, policy: AmbiguityPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The patterns, in the order they are tried.
+    pub fn patterns(&self) -> &[DateTimePattern] {
+        &self.patterns
+    }
+
+    /// The [`AmbiguityPolicy`].
+    pub fn ambiguity_policy(&self) -> AmbiguityPolicy {
+        self.policy
+    }
+
+    /// Parses `value` with the first pattern which matches.
+    ///
+    /// # Returns
+    /// The [`LenientMatch`], or a [`LenientParseError`] if no pattern matches, or if the value
+    /// is ambiguous and rejected by [`AmbiguityPolicy::Reject`].
+    pub fn parse(&self, value: &str) -> Result<LenientMatch, LenientParseError> {
+        let mut errors = Vec::with_capacity(self.patterns.len());
+        for pattern in &self.patterns {
+            match parse_naive_date_time(value, pattern.clone()) {
+                Ok(datetime) => {
+                    let matched = LenientMatch {
+                        value: datetime,
+                        pattern: pattern.clone(),
+                        alternative: day_month_swapped(&pattern.items(), datetime),
+                    };
+                    if matched.is_ambiguous() && self.policy == AmbiguityPolicy::Reject {
+                        return Err(LenientParseError::new(
+                            value,
+                            LenientParseErrorKind::Ambiguous(matched),
+                            errors,
+                        ));
+                    }
+
+                    return Ok(matched);
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Err(LenientParseError::new(
+            value,
+            LenientParseErrorKind::NoMatch,
+            errors,
+        ))
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Reads a signed count since the epoch, `chrono`'s `%s` accepts no sign and no other unit.
+fn epoch_of(value: &str, pattern: &DateTimePattern) -> Result<NaiveDateTime, ParseDateTimeError> {
+    let error = |kind| ParseDateTimeError::new(value, pattern.clone(), kind, None);
+    let nanos = match *pattern {
+        DateTimePattern::EpochFractional(_) => epoch_fractional_nanos_of(value),
+        DateTimePattern::Epoch(unit) => value
+            .parse::<i128>()
+            .ok()
+            .map(|amount| amount.checked_mul(unit.to_nanos(1) as i128)),
+        _ => value
+            .parse::<i128>()
+            .ok()
+            .map(|seconds| seconds.checked_mul(NANOS_PER_SECOND)),
+    }
+    .ok_or_else(|| error(ParseDateTimeErrorKind::Mismatch))?
+    .ok_or_else(|| error(ParseDateTimeErrorKind::OutOfRange))?;
+
+    i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+        .ok()
+        .and_then(|seconds| {
+            DateTime::from_timestamp(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+        })
+        .map(|datetime| datetime.naive_utc())
+        .ok_or_else(|| error(ParseDateTimeErrorKind::OutOfRange))
+}
+
+/// Reads signed epoch seconds with an optional fraction of up to nine digits, e.g. `-0.5`,
+/// as `None` if the value does not match, or `Some(None)` if it overflows.
+fn epoch_fractional_nanos_of(value: &str) -> Option<Option<i128>> {
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (seconds, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |digits: &str| digits.bytes().all(|byte| byte.is_ascii_digit());
+    if seconds.is_empty()
+        || !is_digits(seconds)
+        || fraction.len() > 9
+        || !is_digits(fraction)
+        || unsigned.ends_with('.')
+    {
+        return None;
+    }
+
+    let fraction = format!("{:0<9}", fraction).parse::<i128>().ok()?;
+    let nanos = seconds
+        .parse::<i128>()
+        .ok()
+        .and_then(|seconds| seconds.checked_mul(NANOS_PER_SECOND))
+        .and_then(|nanos| nanos.checked_add(fraction));
+
+    Some(nanos.map(|nanos| if negative { -nanos } else { nanos }))
+}
+
+fn parse(
+    value: &str,
+    pattern: &DateTimePattern,
+    items: &[Item<'static>],
+) -> Result<Parsed, ParseDateTimeError> {
+    let mut parsed = Parsed::new();
+    chrono::format::parse(&mut parsed, value, items.iter())
+        .map_err(|error| error_of(value, pattern, error, ParseDateTimeErrorKind::Mismatch))?;
+
+    Ok(parsed)
+}
+
+fn naive_date_time_of(
+    value: &str,
+    pattern: &DateTimePattern,
+    items: &[Item<'static>],
+    parsed: &Parsed,
+) -> Result<NaiveDateTime, ParseDateTimeError> {
+    match parsed.to_naive_datetime_with_offset(0) {
+        Ok(datetime) => Ok(datetime),
+        Err(error) if error.kind() == ParseErrorKind::NotEnough => {
+            let date = parsed.to_naive_date().map_err(|error| {
+                error_of(value, pattern, error, ParseDateTimeErrorKind::MissingDate)
+            })?;
+
+            // A date-only pattern resolves to midnight, an incomplete time is an error.
+            if has_time_fields(items) {
+                return Err(error_of(
+                    value,
+                    pattern,
+                    error,
+                    ParseDateTimeErrorKind::MissingTime,
+                ));
+            }
+
+            Ok(date.and_time(NaiveTime::MIN))
+        }
+        Err(error) => Err(error_of(
+            value,
+            pattern,
+            error,
+            ParseDateTimeErrorKind::MissingDate,
+        )),
+    }
+}
+
+fn has_time_fields(items: &[Item<'_>]) -> bool {
+    items.iter().any(|item| {
+        matches!(
+            item,
+            Item::Numeric(
+                Numeric::Hour
+                    | Numeric::Hour12
+                    | Numeric::Minute
+                    | Numeric::Second
+                    | Numeric::Nanosecond
+                    | Numeric::Timestamp,
+                _
+            ) | Item::Fixed(
+                Fixed::LowerAmPm
+                    | Fixed::UpperAmPm
+                    | Fixed::Nanosecond
+                    | Fixed::Nanosecond3
+                    | Fixed::Nanosecond6
+                    | Fixed::Nanosecond9
+                    | Fixed::RFC2822
+                    | Fixed::RFC3339
+            )
+        )
+    })
+}
+
+fn has_zone_name(items: &[Item<'_>]) -> bool {
+    items
+        .iter()
+        .any(|item| matches!(item, Item::Fixed(Fixed::TimezoneName)))
+}
+
+/// Maps a `chrono` parse error, `NotEnough` being reported as `missing`.
+fn error_of(
+    value: &str,
+    pattern: &DateTimePattern,
+    error: chrono::ParseError,
+    missing: ParseDateTimeErrorKind,
+) -> ParseDateTimeError {
+    let kind = match error.kind() {
+        ParseErrorKind::NotEnough => missing,
+        ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => {
+            ParseDateTimeErrorKind::OutOfRange
+        }
+        _ => ParseDateTimeErrorKind::Mismatch,
+    };
+
+    ParseDateTimeError::new(value, pattern.clone(), kind, Some(error))
+}
+
+/// Reads `datetime` with day and month swapped, for a layout with both before the year.
+fn day_month_swapped(items: &[Item<'_>], datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+    let position = |fields: &[Numeric]| {
+        items
+            .iter()
+            .position(|item| matches!(item, Item::Numeric(numeric, _) if fields.contains(numeric)))
+    };
+
+    let day = position(&[Numeric::Day])?;
+    let month = position(&[Numeric::Month])?;
+    if let Some(year) = position(&[Numeric::Year, Numeric::YearMod100]) {
+        if year < day && year < month {
+            return None;
+        }
+    }
+
+    let date = NaiveDate::from_ymd_opt(datetime.year(), datetime.day(), datetime.month())?;
+    if date == datetime.date() {
+        return None;
+    }
+
+    Some(date.and_time(datetime.time()))
+}
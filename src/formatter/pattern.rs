@@ -16,8 +16,45 @@
 
 // ----------------------------------------------------------------
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::format::{Fixed, Item, StrftimeItems};
+use chrono::{DateTime, TimeZone, Utc};
+use lazy_static::lazy_static;
+
+use crate::error::{ParseDateTimePatternError, ParseJavaPatternError};
+use crate::formatter::compiled::CompiledPattern;
+use crate::formatter::java;
+use crate::formatter::locale::Locale;
+use crate::formatter::parser::LenientParser;
+use crate::TimeUnit;
+
+// ----------------------------------------------------------------
+
+/// `TIMESTAMP_PLACEHOLDER` the former pattern string of [`DateTimePattern::Timestamp`],
+/// still recognised by [`DateTimePattern::value_of`].
+const TIMESTAMP_PLACEHOLDER: &str = "timestamp";
+
+/// `NANOS_PER_SECOND` the number of nanoseconds in one second.
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+lazy_static! {
+    /// The parser of [`DateTimePattern::detect`], built once.
+    static ref AUTO_PARSER: LenientParser = LenientParser::auto();
+}
+
+// ----------------------------------------------------------------
+
 /// [`DateTimePattern`] date & time pattern.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Not `Copy`: a [`DateTimePattern::Custom`] owns its pattern string and items, freed with
+/// its last clone rather than kept for the life of the process. Cloning it is cheap.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[allow(dead_code)]
 pub enum DateTimePattern {
     /// `YyyyMmDd` `%Y-%m-%d`
@@ -33,6 +70,10 @@ pub enum DateTimePattern {
     YyyyMmDdHhMmSs,
     /// `YyyyMmDdHhMmSsSss` `%Y-%m-%d %H:%M:%S%.3f`
     YyyyMmDdHhMmSsSss,
+    /// `YyyyMmDdHhMmSsOffset` `%Y-%m-%d %H:%M:%S %:z`, e.g. `2024-03-01 09:00:00 +08:00`
+    YyyyMmDdHhMmSsOffset,
+    /// `YyyyMmDdHhMmSsZone` `%Y-%m-%d %H:%M:%S %Z`, e.g. `2024-03-01 09:00:00 CST`
+    YyyyMmDdHhMmSsZone,
 
     /// `HhMm` `%H:%M`
     HhMm,
@@ -52,8 +93,142 @@ pub enum DateTimePattern {
     /// `AmPm` `%p`
     AmPm,
 
-    /// `Timestamp` `unix timestamp`
+    /// `Timestamp` `%s`, the unix timestamp in whole seconds,
+    /// the same as [`DateTimePattern::Epoch`] in [`TimeUnit::Seconds`].
+    /// [`DateTimePattern::value_of`] also reads the former `timestamp` pattern string.
     Timestamp,
+    /// `Epoch` the whole number of units since the unix epoch, e.g. `1709294400000`
+    /// in [`TimeUnit::Milliseconds`]. Instants before 1970 are negative, rounded down.
+    Epoch(TimeUnit),
+    /// `EpochFractional` the seconds since the unix epoch with a fraction,
+    /// e.g. `1709294400.123` with [`Precision::Millis`], or `-0.500` half a second before 1970.
+    EpochFractional(Precision),
+
+    /// `Rfc3339` `%Y-%m-%dT%H:%M:%S%:z`, the ISO 8601 extended format,
+    /// e.g. `2024-03-01T12:00:00.000Z` with [`Precision::Millis`]. UTC is written `Z`.
+    Rfc3339(Precision),
+    /// `Iso8601Basic` `%Y%m%dT%H%M%S%z`, e.g. `20240301T120000Z`. UTC is written `Z`.
+    Iso8601Basic(Precision),
+    /// `IsoWeekDate` `%G-W%V-%u`, e.g. `2024-W09-5`
+    IsoWeekDate,
+    /// `Rfc2822` `%a, %d %b %Y %H:%M:%S %z`, e.g. `Fri, 01 Mar 2024 12:00:00 +0000`
+    Rfc2822,
+    /// `HttpDate` `%a, %d %b %Y %H:%M:%S GMT`, the HTTP IMF-fixdate, always in UTC,
+    /// e.g. `Fri, 01 Mar 2024 12:00:00 GMT`
+    HttpDate,
+
+    /// `Custom` a user `strftime` pattern, see [`DateTimePattern::custom`].
+    Custom(CustomPattern),
+}
+
+/// [`Precision`] the fraction of second of a [`DateTimePattern::Rfc3339`],
+/// [`DateTimePattern::Iso8601Basic`] or [`DateTimePattern::EpochFractional`] pattern.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Precision {
+    /// No fraction, e.g. `12:00:00`.
+    #[default]
+    Seconds,
+    /// Milliseconds, e.g. `12:00:00.000`.
+    Millis,
+    /// Microseconds, e.g. `12:00:00.000000`.
+    Micros,
+    /// Nanoseconds, e.g. `12:00:00.000000000`.
+    Nanos,
+}
+
+/// [`PatternSyntax`] the syntax of a [`CustomPattern`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PatternSyntax {
+    /// `chrono` `strftime` syntax, e.g. `%d.%m.%Y`.
+    Strftime,
+    /// Java `DateTimeFormatter` syntax, e.g. `dd.MM.yyyy`, see [`crate::formatter::java`].
+    Java,
+}
+
+/// [`CustomPattern`] a validated user pattern, with its parsed items.
+///
+/// The pattern string and the items are shared between clones, so cloning a custom pattern,
+/// and the [`DateTimePattern`] holding it, is cheap. They are freed with the last clone.
+#[derive(Clone)]
+pub struct CustomPattern {
+    syntax: PatternSyntax,
+    pattern: Arc<str>,
+    items: Arc<[Item<'static>]>,
+}
+
+impl CustomPattern {
+    fn new(syntax: PatternSyntax, pattern: &str, items: Vec<Item<'static>>) -> Self {
+        CustomPattern {
+            syntax,
+            pattern: Arc::from(pattern),
+            items: Arc::from(items),
+        }
+    }
+
+    /// The [`PatternSyntax`] of the pattern string.
+    pub fn syntax(&self) -> PatternSyntax {
+        self.syntax
+    }
+
+    /// The pattern string, e.g. `%d.%m.%Y`.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The parsed items of the pattern.
+    pub fn items(&self) -> &[Item<'static>] {
+        &self.items
+    }
+
+    /// The parsed items of the pattern, shared with this pattern.
+    pub(crate) fn shared_items(&self) -> Arc<[Item<'static>]> {
+        Arc::clone(&self.items)
+    }
+}
+
+impl fmt::Debug for CustomPattern {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.syntax {
+            PatternSyntax::Strftime => f.debug_tuple("CustomPattern").field(&self.pattern).finish(),
+            PatternSyntax::Java => f
+                .debug_tuple("CustomPattern")
+                .field(&self.syntax)
+                .field(&self.pattern)
+                .finish(),
+        }
+    }
+}
+
+impl PartialEq for CustomPattern {
+    /// override
+    fn eq(&self, other: &Self) -> bool {
+        (self.syntax, &self.pattern) == (other.syntax, &other.pattern)
+    }
+}
+
+impl Eq for CustomPattern {}
+
+impl Hash for CustomPattern {
+    /// override
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.syntax.hash(state);
+        self.pattern.hash(state);
+    }
+}
+
+impl PartialOrd for CustomPattern {
+    /// override
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CustomPattern {
+    /// override
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.syntax, &self.pattern).cmp(&(other.syntax, &other.pattern))
+    }
 }
 
 #[allow(dead_code)]
@@ -71,6 +246,10 @@ impl DateTimePattern {
     pub const YYYY_MM_DD_HH_MM_SS: &'static str = "%Y-%m-%d %H:%M:%S";
     /// `YYYY_MM_DD_HH_MM_SS_SSS` `%Y-%m-%d %H:%M:%S%.3f`
     pub const YYYY_MM_DD_HH_MM_SS_SSS: &'static str = "%Y-%m-%d %H:%M:%S%.3f";
+    /// `YYYY_MM_DD_HH_MM_SS_OFFSET` `%Y-%m-%d %H:%M:%S %:z`
+    pub const YYYY_MM_DD_HH_MM_SS_OFFSET: &'static str = "%Y-%m-%d %H:%M:%S %:z";
+    /// `YYYY_MM_DD_HH_MM_SS_ZONE` `%Y-%m-%d %H:%M:%S %Z`
+    pub const YYYY_MM_DD_HH_MM_SS_ZONE: &'static str = "%Y-%m-%d %H:%M:%S %Z";
 
     /// `HH_MM` `%H:%M`
     pub const HH_MM: &'static str = "%H:%M";
@@ -90,8 +269,61 @@ impl DateTimePattern {
     /// `AM_PM` `%p`
     pub const AM_PM: &'static str = "%p";
 
-    /// `TIMESTAMP` `timestamp`
-    pub const TIMESTAMP: &'static str = "timestamp";
+    /// `TIMESTAMP` `%s`
+    pub const TIMESTAMP: &'static str = "%s";
+
+    /// `EPOCH_NANOS` `%s%9f`, the `strftime` equivalent after 1970
+    pub const EPOCH_NANOS: &'static str = "%s%9f";
+    /// `EPOCH_MICROS` `%s%6f`, the `strftime` equivalent after 1970
+    pub const EPOCH_MICROS: &'static str = "%s%6f";
+    /// `EPOCH_MILLIS` `%s%3f`, the `strftime` equivalent after 1970
+    pub const EPOCH_MILLIS: &'static str = "%s%3f";
+    /// `EPOCH_SECONDS` `epoch-seconds`, the same as [`DateTimePattern::TIMESTAMP`]
+    pub const EPOCH_SECONDS: &'static str = "epoch-seconds";
+    /// `EPOCH_MINUTES` `epoch-minutes`, no `strftime` equivalent
+    pub const EPOCH_MINUTES: &'static str = "epoch-minutes";
+    /// `EPOCH_HOURS` `epoch-hours`, no `strftime` equivalent
+    pub const EPOCH_HOURS: &'static str = "epoch-hours";
+    /// `EPOCH_DAYS` `epoch-days`, no `strftime` equivalent
+    pub const EPOCH_DAYS: &'static str = "epoch-days";
+    /// `EPOCH_WEEKS` `epoch-weeks`, no `strftime` equivalent
+    pub const EPOCH_WEEKS: &'static str = "epoch-weeks";
+
+    /// `EPOCH_FRACTIONAL_SECONDS` `epoch-fractional-seconds`, the seconds since the unix epoch without a fraction, rounded down
+    pub const EPOCH_FRACTIONAL_SECONDS: &'static str = "epoch-fractional-seconds";
+    /// `EPOCH_FRACTIONAL_MILLIS` `%s%.3f`, the `strftime` equivalent after 1970
+    pub const EPOCH_FRACTIONAL_MILLIS: &'static str = "%s%.3f";
+    /// `EPOCH_FRACTIONAL_MICROS` `%s%.6f`, the `strftime` equivalent after 1970
+    pub const EPOCH_FRACTIONAL_MICROS: &'static str = "%s%.6f";
+    /// `EPOCH_FRACTIONAL_NANOS` `%s%.9f`, the `strftime` equivalent after 1970
+    pub const EPOCH_FRACTIONAL_NANOS: &'static str = "%s%.9f";
+
+    /// `RFC_3339` `%Y-%m-%dT%H:%M:%S%:z`
+    pub const RFC_3339: &'static str = "%Y-%m-%dT%H:%M:%S%:z";
+    /// `RFC_3339_MILLIS` `%Y-%m-%dT%H:%M:%S%.3f%:z`
+    pub const RFC_3339_MILLIS: &'static str = "%Y-%m-%dT%H:%M:%S%.3f%:z";
+    /// `RFC_3339_MICROS` `%Y-%m-%dT%H:%M:%S%.6f%:z`
+    pub const RFC_3339_MICROS: &'static str = "%Y-%m-%dT%H:%M:%S%.6f%:z";
+    /// `RFC_3339_NANOS` `%Y-%m-%dT%H:%M:%S%.9f%:z`
+    pub const RFC_3339_NANOS: &'static str = "%Y-%m-%dT%H:%M:%S%.9f%:z";
+
+    /// `ISO_8601_BASIC` `%Y%m%dT%H%M%S%z`
+    pub const ISO_8601_BASIC: &'static str = "%Y%m%dT%H%M%S%z";
+    /// `ISO_8601_BASIC_MILLIS` `%Y%m%dT%H%M%S%.3f%z`
+    pub const ISO_8601_BASIC_MILLIS: &'static str = "%Y%m%dT%H%M%S%.3f%z";
+    /// `ISO_8601_BASIC_MICROS` `%Y%m%dT%H%M%S%.6f%z`
+    pub const ISO_8601_BASIC_MICROS: &'static str = "%Y%m%dT%H%M%S%.6f%z";
+    /// `ISO_8601_BASIC_NANOS` `%Y%m%dT%H%M%S%.9f%z`
+    pub const ISO_8601_BASIC_NANOS: &'static str = "%Y%m%dT%H%M%S%.9f%z";
+
+    /// `ISO_WEEK_DATE` `%G-W%V-%u`
+    pub const ISO_WEEK_DATE: &'static str = "%G-W%V-%u";
+
+    /// `RFC_2822` `%a, %d %b %Y %H:%M:%S %z`
+    pub const RFC_2822: &'static str = "%a, %d %b %Y %H:%M:%S %z";
+
+    /// `HTTP_DATE` `%a, %d %b %Y %H:%M:%S GMT`
+    pub const HTTP_DATE: &'static str = "%a, %d %b %Y %H:%M:%S GMT";
 
     // ----------------------------------------------------------------
 
@@ -108,6 +340,10 @@ impl DateTimePattern {
     pub const YYYY_MM_DD_HH_MM_SS_NAME: &'static str = "YyyyMmDdHhMmSs";
     /// `YYYY_MM_DD_HH_MM_SS_SSS_NAME` `YyyyMmDdHhMmSsSss`
     pub const YYYY_MM_DD_HH_MM_SS_SSS_NAME: &'static str = "YyyyMmDdHhMmSsSss";
+    /// `YYYY_MM_DD_HH_MM_SS_OFFSET_NAME` `YyyyMmDdHhMmSsOffset`
+    pub const YYYY_MM_DD_HH_MM_SS_OFFSET_NAME: &'static str = "YyyyMmDdHhMmSsOffset";
+    /// `YYYY_MM_DD_HH_MM_SS_ZONE_NAME` `YyyyMmDdHhMmSsZone`
+    pub const YYYY_MM_DD_HH_MM_SS_ZONE_NAME: &'static str = "YyyyMmDdHhMmSsZone";
 
     /// `HH_MM_NAME` `HhMm`
     pub const HH_MM_NAME: &'static str = "HhMm";
@@ -130,6 +366,59 @@ impl DateTimePattern {
     /// `TIMESTAMP_NAME` `Timestamp`
     pub const TIMESTAMP_NAME: &'static str = "Timestamp";
 
+    /// `EPOCH_NANOS_NAME` `EpochNanos`
+    pub const EPOCH_NANOS_NAME: &'static str = "EpochNanos";
+    /// `EPOCH_MICROS_NAME` `EpochMicros`
+    pub const EPOCH_MICROS_NAME: &'static str = "EpochMicros";
+    /// `EPOCH_MILLIS_NAME` `EpochMillis`
+    pub const EPOCH_MILLIS_NAME: &'static str = "EpochMillis";
+    /// `EPOCH_SECONDS_NAME` `EpochSeconds`
+    pub const EPOCH_SECONDS_NAME: &'static str = "EpochSeconds";
+    /// `EPOCH_MINUTES_NAME` `EpochMinutes`
+    pub const EPOCH_MINUTES_NAME: &'static str = "EpochMinutes";
+    /// `EPOCH_HOURS_NAME` `EpochHours`
+    pub const EPOCH_HOURS_NAME: &'static str = "EpochHours";
+    /// `EPOCH_DAYS_NAME` `EpochDays`
+    pub const EPOCH_DAYS_NAME: &'static str = "EpochDays";
+    /// `EPOCH_WEEKS_NAME` `EpochWeeks`
+    pub const EPOCH_WEEKS_NAME: &'static str = "EpochWeeks";
+
+    /// `EPOCH_FRACTIONAL_NAME` `EpochFractional`
+    pub const EPOCH_FRACTIONAL_NAME: &'static str = "EpochFractional";
+    /// `EPOCH_FRACTIONAL_MILLIS_NAME` `EpochFractionalMillis`
+    pub const EPOCH_FRACTIONAL_MILLIS_NAME: &'static str = "EpochFractionalMillis";
+    /// `EPOCH_FRACTIONAL_MICROS_NAME` `EpochFractionalMicros`
+    pub const EPOCH_FRACTIONAL_MICROS_NAME: &'static str = "EpochFractionalMicros";
+    /// `EPOCH_FRACTIONAL_NANOS_NAME` `EpochFractionalNanos`
+    pub const EPOCH_FRACTIONAL_NANOS_NAME: &'static str = "EpochFractionalNanos";
+
+    /// `RFC_3339_NAME` `Rfc3339`
+    pub const RFC_3339_NAME: &'static str = "Rfc3339";
+    /// `RFC_3339_MILLIS_NAME` `Rfc3339Millis`
+    pub const RFC_3339_MILLIS_NAME: &'static str = "Rfc3339Millis";
+    /// `RFC_3339_MICROS_NAME` `Rfc3339Micros`
+    pub const RFC_3339_MICROS_NAME: &'static str = "Rfc3339Micros";
+    /// `RFC_3339_NANOS_NAME` `Rfc3339Nanos`
+    pub const RFC_3339_NANOS_NAME: &'static str = "Rfc3339Nanos";
+
+    /// `ISO_8601_BASIC_NAME` `Iso8601Basic`
+    pub const ISO_8601_BASIC_NAME: &'static str = "Iso8601Basic";
+    /// `ISO_8601_BASIC_MILLIS_NAME` `Iso8601BasicMillis`
+    pub const ISO_8601_BASIC_MILLIS_NAME: &'static str = "Iso8601BasicMillis";
+    /// `ISO_8601_BASIC_MICROS_NAME` `Iso8601BasicMicros`
+    pub const ISO_8601_BASIC_MICROS_NAME: &'static str = "Iso8601BasicMicros";
+    /// `ISO_8601_BASIC_NANOS_NAME` `Iso8601BasicNanos`
+    pub const ISO_8601_BASIC_NANOS_NAME: &'static str = "Iso8601BasicNanos";
+
+    /// `ISO_WEEK_DATE_NAME` `IsoWeekDate`
+    pub const ISO_WEEK_DATE_NAME: &'static str = "IsoWeekDate";
+
+    /// `RFC_2822_NAME` `Rfc2822`
+    pub const RFC_2822_NAME: &'static str = "Rfc2822";
+
+    /// `HTTP_DATE_NAME` `HttpDate`
+    pub const HTTP_DATE_NAME: &'static str = "HttpDate";
+
     // ----------------------------------------------------------------
 
     /// Retrieves the string representation of a [`DateTimePattern`].
@@ -140,14 +429,86 @@ impl DateTimePattern {
     /// use chronounit::formatter::pattern::DateTimePattern;
     ///
     /// assert_eq!(DateTimePattern::YyyyMmDdHhMmSs.value(), "YyyyMmDdHhMmSs");
+    /// assert_eq!(DateTimePattern::custom("%d.%m.%Y").unwrap().value(), "%d.%m.%Y");
     /// ```
     pub fn value(&self) -> String {
-        format!("{:?}", self)
+        self.to_string()
+    }
+
+    /// Creates a [`DateTimePattern::Custom`] from a `strftime` pattern string.
+    ///
+    /// The pattern is validated and parsed once, clones of the returned pattern share
+    /// the parsed items.
+    ///
+    /// # Arguments
+    /// `pattern` - A `strftime` pattern, e.g. `%Y%m%d` or `%d.%m.%Y`.
+    ///
+    /// # Returns
+    /// The custom pattern, or a [`ParseDateTimePatternError`] if `pattern` has an invalid
+    /// or incomplete specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use chronounit::formatter;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    ///
+    /// let ndt = NaiveDateTime::parse_from_str("2024-03-12 22:55:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let pattern = DateTimePattern::custom("%d.%m.%Y").unwrap();
+    ///
+    /// assert_eq!(formatter::format_naive_date_time(&ndt, pattern.clone()), "12.03.2024");
+    /// assert_eq!(pattern, DateTimePattern::custom("%d.%m.%Y").unwrap());
+    /// assert!(DateTimePattern::custom("%Y%Q").is_err());
+    /// ```
+    pub fn custom(pattern: &str) -> Result<DateTimePattern, ParseDateTimePatternError> {
+        let items: Vec<Item<'static>> = StrftimeItems::new(pattern).map(Item::to_owned).collect();
+        if items.contains(&Item::Error) {
+            return Err(ParseDateTimePatternError::new(pattern));
+        }
+
+        Ok(DateTimePattern::Custom(CustomPattern::new(
+            PatternSyntax::Strftime,
+            pattern,
+            items,
+        )))
+    }
+
+    /// Creates a [`DateTimePattern::Custom`] from a Java `DateTimeFormatter` pattern string,
+    /// see [`crate::formatter::java`] for the supported letters.
+    ///
+    /// # Arguments
+    /// `pattern` - A Java pattern, e.g. `yyyy-MM-dd HH:mm:ss.SSS`.
+    ///
+    /// # Returns
+    /// The custom pattern, or a [`ParseJavaPatternError`] if `pattern` has an unsupported
+    /// letter, an optional section, a reserved character or an unterminated quote.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use chronounit::formatter;
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    ///
+    /// let ndt = NaiveDateTime::parse_from_str("2024-03-12 22:55:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let pattern = DateTimePattern::java("EEE, dd MMM yyyy 'at' hh:mm a").unwrap();
+    ///
+    /// assert_eq!(formatter::format_naive_date_time(&ndt, pattern), "Tue, 12 Mar 2024 at 10:55 PM");
+    /// ```
+    pub fn java(pattern: &str) -> Result<DateTimePattern, ParseJavaPatternError> {
+        let items = java::compile(pattern)?;
+
+        Ok(DateTimePattern::Custom(CustomPattern::new(
+            PatternSyntax::Java,
+            pattern,
+            items,
+        )))
     }
 }
 
 impl DateTimePattern {
-    /// Retrieves the static string representation of a date and time pattern.
+    /// Retrieves the pattern string of a date and time pattern.
     ///
     /// # Examples
     ///
@@ -157,7 +518,15 @@ impl DateTimePattern {
     /// let pattern = DateTimePattern::YyyyMmDd;
     /// assert_eq!(pattern.pattern_of(), DateTimePattern::YYYY_MM_DD);
     /// ```
-    pub fn pattern_of(&self) -> &'static str {
+    pub fn pattern_of(&self) -> &str {
+        match self {
+            DateTimePattern::Custom(custom) => custom.pattern(),
+            builtin => builtin.builtin_pattern_of(),
+        }
+    }
+
+    /// The pattern string of a builtin pattern, empty for a [`DateTimePattern::Custom`] one.
+    fn builtin_pattern_of(&self) -> &'static str {
         match self {
             DateTimePattern::YyyyMmDd => DateTimePattern::YYYY_MM_DD,
             DateTimePattern::MmDdYyyy => DateTimePattern::MM_DD_YYYY,
@@ -165,6 +534,8 @@ impl DateTimePattern {
             DateTimePattern::YyyyMmDdHhMm => DateTimePattern::YYYY_MM_DD_HH_MM,
             DateTimePattern::YyyyMmDdHhMmSs => DateTimePattern::YYYY_MM_DD_HH_MM_SS,
             DateTimePattern::YyyyMmDdHhMmSsSss => DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS,
+            DateTimePattern::YyyyMmDdHhMmSsOffset => DateTimePattern::YYYY_MM_DD_HH_MM_SS_OFFSET,
+            DateTimePattern::YyyyMmDdHhMmSsZone => DateTimePattern::YYYY_MM_DD_HH_MM_SS_ZONE,
             DateTimePattern::HhMm => DateTimePattern::HH_MM,
             DateTimePattern::HhMmSs => DateTimePattern::HH_MM_SS,
             DateTimePattern::MonthFull => DateTimePattern::MONTH_FULL,
@@ -173,6 +544,143 @@ impl DateTimePattern {
             DateTimePattern::WeekdayAbbr => DateTimePattern::WEEKDAY_ABBR,
             DateTimePattern::AmPm => DateTimePattern::AM_PM,
             DateTimePattern::Timestamp => DateTimePattern::TIMESTAMP,
+            DateTimePattern::Epoch(TimeUnit::Nanoseconds) => DateTimePattern::EPOCH_NANOS,
+            DateTimePattern::Epoch(TimeUnit::Microseconds) => DateTimePattern::EPOCH_MICROS,
+            DateTimePattern::Epoch(TimeUnit::Milliseconds) => DateTimePattern::EPOCH_MILLIS,
+            DateTimePattern::Epoch(TimeUnit::Seconds) => DateTimePattern::EPOCH_SECONDS,
+            DateTimePattern::Epoch(TimeUnit::Minutes) => DateTimePattern::EPOCH_MINUTES,
+            DateTimePattern::Epoch(TimeUnit::Hours) => DateTimePattern::EPOCH_HOURS,
+            DateTimePattern::Epoch(TimeUnit::Days) => DateTimePattern::EPOCH_DAYS,
+            DateTimePattern::Epoch(TimeUnit::Weeks) => DateTimePattern::EPOCH_WEEKS,
+            DateTimePattern::EpochFractional(Precision::Seconds) => {
+                DateTimePattern::EPOCH_FRACTIONAL_SECONDS
+            }
+            DateTimePattern::EpochFractional(Precision::Millis) => {
+                DateTimePattern::EPOCH_FRACTIONAL_MILLIS
+            }
+            DateTimePattern::EpochFractional(Precision::Micros) => {
+                DateTimePattern::EPOCH_FRACTIONAL_MICROS
+            }
+            DateTimePattern::EpochFractional(Precision::Nanos) => {
+                DateTimePattern::EPOCH_FRACTIONAL_NANOS
+            }
+            DateTimePattern::Rfc3339(Precision::Seconds) => DateTimePattern::RFC_3339,
+            DateTimePattern::Rfc3339(Precision::Millis) => DateTimePattern::RFC_3339_MILLIS,
+            DateTimePattern::Rfc3339(Precision::Micros) => DateTimePattern::RFC_3339_MICROS,
+            DateTimePattern::Rfc3339(Precision::Nanos) => DateTimePattern::RFC_3339_NANOS,
+            DateTimePattern::Iso8601Basic(Precision::Seconds) => DateTimePattern::ISO_8601_BASIC,
+            DateTimePattern::Iso8601Basic(Precision::Millis) => {
+                DateTimePattern::ISO_8601_BASIC_MILLIS
+            }
+            DateTimePattern::Iso8601Basic(Precision::Micros) => {
+                DateTimePattern::ISO_8601_BASIC_MICROS
+            }
+            DateTimePattern::Iso8601Basic(Precision::Nanos) => {
+                DateTimePattern::ISO_8601_BASIC_NANOS
+            }
+            DateTimePattern::IsoWeekDate => DateTimePattern::ISO_WEEK_DATE,
+            DateTimePattern::Rfc2822 => DateTimePattern::RFC_2822,
+            DateTimePattern::HttpDate => DateTimePattern::HTTP_DATE,
+            DateTimePattern::Custom(_) => "",
+        }
+    }
+
+    /// The `chrono` format items of the pattern, the offsets of [`DateTimePattern::Rfc3339`] and [`DateTimePattern::Iso8601Basic`]
+    /// writing UTC as `Z`.
+    pub(crate) fn items(&self) -> Cow<'_, [Item<'static>]> {
+        match self {
+            DateTimePattern::Custom(custom) => Cow::Borrowed(custom.items()),
+            DateTimePattern::Rfc3339(_) | DateTimePattern::Iso8601Basic(_) => Cow::Owned(
+                StrftimeItems::new(self.builtin_pattern_of())
+                    .map(|item| match item {
+                        Item::Fixed(Fixed::TimezoneOffsetColon) => {
+                            Item::Fixed(Fixed::TimezoneOffsetColonZ)
+                        }
+                        Item::Fixed(Fixed::TimezoneOffset) => Item::Fixed(Fixed::TimezoneOffsetZ),
+                        item => item,
+                    })
+                    .collect(),
+            ),
+            _ => Cow::Owned(StrftimeItems::new(self.builtin_pattern_of()).collect()),
+        }
+    }
+
+    /// Whether the pattern renders instants in UTC whatever their time zone,
+    /// as [`DateTimePattern::HttpDate`].
+    pub(crate) fn is_utc_only(&self) -> bool {
+        *self == DateTimePattern::HttpDate
+    }
+
+    /// Whether the pattern is a count since the unix epoch, as [`DateTimePattern::Timestamp`],
+    /// [`DateTimePattern::Epoch`] and [`DateTimePattern::EpochFractional`].
+    pub(crate) fn is_epoch(&self) -> bool {
+        matches!(
+            self,
+            DateTimePattern::Timestamp
+                | DateTimePattern::Epoch(_)
+                | DateTimePattern::EpochFractional(_)
+        )
+    }
+
+    /// Whether the pattern is an interchange format with English names whatever the locale,
+    /// as [`DateTimePattern::Rfc2822`] and [`DateTimePattern::HttpDate`].
+    pub(crate) fn is_locale_neutral(&self) -> bool {
+        matches!(self, DateTimePattern::Rfc2822 | DateTimePattern::HttpDate)
+    }
+
+    /// Formats `datetime` in its own time zone, or in UTC if [`DateTimePattern::is_utc_only`],
+    /// with the names of `locale` unless [`DateTimePattern::is_locale_neutral`].
+    pub(crate) fn format_localized<Tz: TimeZone>(
+        &self,
+        datetime: &DateTime<Tz>,
+        locale: Locale,
+    ) -> String
+    where
+        Tz::Offset: fmt::Display,
+    {
+        let mut formatted = String::new();
+        self.write_localized(&mut formatted, datetime, locale)
+            .expect("a formatting trait implementation returned an error");
+
+        formatted
+    }
+
+    /// Writes `datetime` as [`DateTimePattern::format_localized`] into `w`,
+    /// without allocating the formatted string.
+    pub(crate) fn write_localized<Tz: TimeZone>(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Tz>,
+        locale: Locale,
+    ) -> fmt::Result
+    where
+        Tz::Offset: fmt::Display,
+    {
+        match *self {
+            DateTimePattern::Timestamp => return write!(w, "{}", datetime.timestamp()),
+            DateTimePattern::Epoch(unit) => return write_epoch(w, datetime, unit),
+            DateTimePattern::EpochFractional(precision) => {
+                return write_epoch_fractional(w, datetime, precision)
+            }
+            _ => {}
+        }
+
+        if self.is_utc_only() {
+            return datetime
+                .with_timezone(&Utc)
+                .format_with_items(self.items().iter())
+                .write_to(w);
+        }
+        if locale != Locale::EnUs && !self.is_locale_neutral() {
+            let items = locale.localize(&self.items(), datetime);
+            return datetime.format_with_items(items.iter()).write_to(w);
+        }
+
+        match *self {
+            DateTimePattern::YyyyMmDdHhMmSs | DateTimePattern::YyyyMmDdHhMmSsSss => {
+                CompiledPattern::new(self.clone()).write(w, datetime)
+            } // Writes digit by digit
+            _ => datetime.format_with_items(self.items().iter()).write_to(w),
         }
     }
 
@@ -184,6 +692,9 @@ impl DateTimePattern {
     /// # Return Value
     /// An `Option<Self>` containing the matched pattern if one is found; otherwise, returns [`None`]
     ///
+    /// Every builtin pattern is read back from its [`DateTimePattern::pattern_of`]. The former
+    /// `timestamp` pattern string is still read as [`DateTimePattern::Timestamp`], like `%s`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -191,6 +702,7 @@ impl DateTimePattern {
     ///
     /// let pattern = DateTimePattern::YyyyMmDd;
     /// assert_eq!(DateTimePattern::value_of("%Y-%m-%d"), Some(DateTimePattern::YyyyMmDd));
+    /// assert_eq!(DateTimePattern::value_of("timestamp"), Some(DateTimePattern::Timestamp));
     /// assert_eq!(DateTimePattern::value_of("Invalid"), None);
     /// ```
     pub fn value_of(pattern: &str) -> Option<DateTimePattern> {
@@ -201,6 +713,10 @@ impl DateTimePattern {
             DateTimePattern::YYYY_MM_DD_HH_MM => Some(DateTimePattern::YyyyMmDdHhMm),
             DateTimePattern::YYYY_MM_DD_HH_MM_SS => Some(DateTimePattern::YyyyMmDdHhMmSs),
             DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS => Some(DateTimePattern::YyyyMmDdHhMmSsSss),
+            DateTimePattern::YYYY_MM_DD_HH_MM_SS_OFFSET => {
+                Some(DateTimePattern::YyyyMmDdHhMmSsOffset)
+            }
+            DateTimePattern::YYYY_MM_DD_HH_MM_SS_ZONE => Some(DateTimePattern::YyyyMmDdHhMmSsZone),
             DateTimePattern::HH_MM => Some(DateTimePattern::HhMm),
             DateTimePattern::HH_MM_SS => Some(DateTimePattern::HhMmSs),
             DateTimePattern::MONTH_FULL => Some(DateTimePattern::MonthFull),
@@ -208,11 +724,71 @@ impl DateTimePattern {
             DateTimePattern::WEEKDAY_FULL => Some(DateTimePattern::WeekdayFull),
             DateTimePattern::WEEKDAY_ABBR => Some(DateTimePattern::WeekdayAbbr),
             DateTimePattern::AM_PM => Some(DateTimePattern::AmPm),
-            DateTimePattern::TIMESTAMP => Some(DateTimePattern::Timestamp),
+            DateTimePattern::TIMESTAMP | TIMESTAMP_PLACEHOLDER => Some(DateTimePattern::Timestamp),
+            DateTimePattern::EPOCH_NANOS => Some(DateTimePattern::Epoch(TimeUnit::Nanoseconds)),
+            DateTimePattern::EPOCH_MICROS => Some(DateTimePattern::Epoch(TimeUnit::Microseconds)),
+            DateTimePattern::EPOCH_MILLIS => Some(DateTimePattern::Epoch(TimeUnit::Milliseconds)),
+            DateTimePattern::EPOCH_SECONDS => Some(DateTimePattern::Epoch(TimeUnit::Seconds)),
+            DateTimePattern::EPOCH_MINUTES => Some(DateTimePattern::Epoch(TimeUnit::Minutes)),
+            DateTimePattern::EPOCH_HOURS => Some(DateTimePattern::Epoch(TimeUnit::Hours)),
+            DateTimePattern::EPOCH_DAYS => Some(DateTimePattern::Epoch(TimeUnit::Days)),
+            DateTimePattern::EPOCH_WEEKS => Some(DateTimePattern::Epoch(TimeUnit::Weeks)),
+            DateTimePattern::EPOCH_FRACTIONAL_SECONDS => {
+                Some(DateTimePattern::EpochFractional(Precision::Seconds))
+            }
+            DateTimePattern::EPOCH_FRACTIONAL_MILLIS => {
+                Some(DateTimePattern::EpochFractional(Precision::Millis))
+            }
+            DateTimePattern::EPOCH_FRACTIONAL_MICROS => {
+                Some(DateTimePattern::EpochFractional(Precision::Micros))
+            }
+            DateTimePattern::EPOCH_FRACTIONAL_NANOS => {
+                Some(DateTimePattern::EpochFractional(Precision::Nanos))
+            }
+            DateTimePattern::RFC_3339 => Some(DateTimePattern::Rfc3339(Precision::Seconds)),
+            DateTimePattern::RFC_3339_MILLIS => Some(DateTimePattern::Rfc3339(Precision::Millis)),
+            DateTimePattern::RFC_3339_MICROS => Some(DateTimePattern::Rfc3339(Precision::Micros)),
+            DateTimePattern::RFC_3339_NANOS => Some(DateTimePattern::Rfc3339(Precision::Nanos)),
+            DateTimePattern::ISO_8601_BASIC => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Seconds))
+            }
+            DateTimePattern::ISO_8601_BASIC_MILLIS => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Millis))
+            }
+            DateTimePattern::ISO_8601_BASIC_MICROS => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Micros))
+            }
+            DateTimePattern::ISO_8601_BASIC_NANOS => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Nanos))
+            }
+            DateTimePattern::ISO_WEEK_DATE => Some(DateTimePattern::IsoWeekDate),
+            DateTimePattern::RFC_2822 => Some(DateTimePattern::Rfc2822),
+            DateTimePattern::HTTP_DATE => Some(DateTimePattern::HttpDate),
             _ => None,
         }
     }
 
+    /// Detects the pattern of a date-time value, with [`LenientParser::auto`].
+    ///
+    /// Unlike [`DateTimePattern::value_of`], which recognises pattern strings,
+    /// this recognises data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    ///
+    /// assert_eq!(DateTimePattern::detect("2024-03-01 12:00:00"), Some(DateTimePattern::YyyyMmDdHhMmSs));
+    /// assert_eq!(DateTimePattern::detect("01-03-2024"), Some(DateTimePattern::DdMmYyyy));
+    /// assert_eq!(DateTimePattern::detect("Invalid"), None);
+    /// ```
+    pub fn detect(value: &str) -> Option<DateTimePattern> {
+        AUTO_PARSER
+            .parse(value)
+            .ok()
+            .map(|matched| matched.pattern())
+    }
+
     /// Returns the corresponding date-time pattern based on the provided name string.
     ///
     /// # Parameters
@@ -240,6 +816,12 @@ impl DateTimePattern {
             DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS_NAME => {
                 Some(DateTimePattern::YyyyMmDdHhMmSsSss)
             }
+            DateTimePattern::YYYY_MM_DD_HH_MM_SS_OFFSET_NAME => {
+                Some(DateTimePattern::YyyyMmDdHhMmSsOffset)
+            }
+            DateTimePattern::YYYY_MM_DD_HH_MM_SS_ZONE_NAME => {
+                Some(DateTimePattern::YyyyMmDdHhMmSsZone)
+            }
             DateTimePattern::HH_MM_NAME => Some(DateTimePattern::HhMm),
             DateTimePattern::HH_MM_SS_NAME => Some(DateTimePattern::HhMmSs),
             DateTimePattern::MONTH_FULL_NAME => Some(DateTimePattern::MonthFull),
@@ -248,7 +830,227 @@ impl DateTimePattern {
             DateTimePattern::WEEKDAY_ABBR_NAME => Some(DateTimePattern::WeekdayAbbr),
             DateTimePattern::AM_PM_NAME => Some(DateTimePattern::AmPm),
             DateTimePattern::TIMESTAMP_NAME => Some(DateTimePattern::Timestamp),
+            DateTimePattern::EPOCH_NANOS_NAME => {
+                Some(DateTimePattern::Epoch(TimeUnit::Nanoseconds))
+            }
+            DateTimePattern::EPOCH_MICROS_NAME => {
+                Some(DateTimePattern::Epoch(TimeUnit::Microseconds))
+            }
+            DateTimePattern::EPOCH_MILLIS_NAME => {
+                Some(DateTimePattern::Epoch(TimeUnit::Milliseconds))
+            }
+            DateTimePattern::EPOCH_SECONDS_NAME => Some(DateTimePattern::Epoch(TimeUnit::Seconds)),
+            DateTimePattern::EPOCH_MINUTES_NAME => Some(DateTimePattern::Epoch(TimeUnit::Minutes)),
+            DateTimePattern::EPOCH_HOURS_NAME => Some(DateTimePattern::Epoch(TimeUnit::Hours)),
+            DateTimePattern::EPOCH_DAYS_NAME => Some(DateTimePattern::Epoch(TimeUnit::Days)),
+            DateTimePattern::EPOCH_WEEKS_NAME => Some(DateTimePattern::Epoch(TimeUnit::Weeks)),
+            DateTimePattern::EPOCH_FRACTIONAL_NAME => {
+                Some(DateTimePattern::EpochFractional(Precision::Seconds))
+            }
+            DateTimePattern::EPOCH_FRACTIONAL_MILLIS_NAME => {
+                Some(DateTimePattern::EpochFractional(Precision::Millis))
+            }
+            DateTimePattern::EPOCH_FRACTIONAL_MICROS_NAME => {
+                Some(DateTimePattern::EpochFractional(Precision::Micros))
+            }
+            DateTimePattern::EPOCH_FRACTIONAL_NANOS_NAME => {
+                Some(DateTimePattern::EpochFractional(Precision::Nanos))
+            }
+            DateTimePattern::RFC_3339_NAME => Some(DateTimePattern::Rfc3339(Precision::Seconds)),
+            DateTimePattern::RFC_3339_MILLIS_NAME => {
+                Some(DateTimePattern::Rfc3339(Precision::Millis))
+            }
+            DateTimePattern::RFC_3339_MICROS_NAME => {
+                Some(DateTimePattern::Rfc3339(Precision::Micros))
+            }
+            DateTimePattern::RFC_3339_NANOS_NAME => {
+                Some(DateTimePattern::Rfc3339(Precision::Nanos))
+            }
+            DateTimePattern::ISO_8601_BASIC_NAME => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Seconds))
+            }
+            DateTimePattern::ISO_8601_BASIC_MILLIS_NAME => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Millis))
+            }
+            DateTimePattern::ISO_8601_BASIC_MICROS_NAME => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Micros))
+            }
+            DateTimePattern::ISO_8601_BASIC_NANOS_NAME => {
+                Some(DateTimePattern::Iso8601Basic(Precision::Nanos))
+            }
+            DateTimePattern::ISO_WEEK_DATE_NAME => Some(DateTimePattern::IsoWeekDate),
+            DateTimePattern::RFC_2822_NAME => Some(DateTimePattern::Rfc2822),
+            DateTimePattern::HTTP_DATE_NAME => Some(DateTimePattern::HttpDate),
             _ => None,
         }
     }
 }
+
+impl fmt::Display for DateTimePattern {
+    /// Formats the [`DateTimePattern`] by its name, e.g. `YyyyMmDd`,
+    /// or a custom pattern by its pattern string, e.g. `%d.%m.%Y`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    ///
+    /// assert_eq!(DateTimePattern::YyyyMmDd.to_string(), "YyyyMmDd");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DateTimePattern::YyyyMmDd => DateTimePattern::YYYY_MM_DD_NAME,
+            DateTimePattern::MmDdYyyy => DateTimePattern::MM_DD_YYYY_NAME,
+            DateTimePattern::DdMmYyyy => DateTimePattern::DD_MM_YYYY_NAME,
+            DateTimePattern::YyyyMmDdHhMm => DateTimePattern::YYYY_MM_DD_HH_MM_NAME,
+            DateTimePattern::YyyyMmDdHhMmSs => DateTimePattern::YYYY_MM_DD_HH_MM_SS_NAME,
+            DateTimePattern::YyyyMmDdHhMmSsSss => DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS_NAME,
+            DateTimePattern::YyyyMmDdHhMmSsOffset => {
+                DateTimePattern::YYYY_MM_DD_HH_MM_SS_OFFSET_NAME
+            }
+            DateTimePattern::YyyyMmDdHhMmSsZone => DateTimePattern::YYYY_MM_DD_HH_MM_SS_ZONE_NAME,
+            DateTimePattern::HhMm => DateTimePattern::HH_MM_NAME,
+            DateTimePattern::HhMmSs => DateTimePattern::HH_MM_SS_NAME,
+            DateTimePattern::MonthFull => DateTimePattern::MONTH_FULL_NAME,
+            DateTimePattern::MonthAbbr => DateTimePattern::MONTH_ABBR_NAME,
+            DateTimePattern::WeekdayFull => DateTimePattern::WEEKDAY_FULL_NAME,
+            DateTimePattern::WeekdayAbbr => DateTimePattern::WEEKDAY_ABBR_NAME,
+            DateTimePattern::AmPm => DateTimePattern::AM_PM_NAME,
+            DateTimePattern::Timestamp => DateTimePattern::TIMESTAMP_NAME,
+            DateTimePattern::Epoch(TimeUnit::Nanoseconds) => DateTimePattern::EPOCH_NANOS_NAME,
+            DateTimePattern::Epoch(TimeUnit::Microseconds) => DateTimePattern::EPOCH_MICROS_NAME,
+            DateTimePattern::Epoch(TimeUnit::Milliseconds) => DateTimePattern::EPOCH_MILLIS_NAME,
+            DateTimePattern::Epoch(TimeUnit::Seconds) => DateTimePattern::EPOCH_SECONDS_NAME,
+            DateTimePattern::Epoch(TimeUnit::Minutes) => DateTimePattern::EPOCH_MINUTES_NAME,
+            DateTimePattern::Epoch(TimeUnit::Hours) => DateTimePattern::EPOCH_HOURS_NAME,
+            DateTimePattern::Epoch(TimeUnit::Days) => DateTimePattern::EPOCH_DAYS_NAME,
+            DateTimePattern::Epoch(TimeUnit::Weeks) => DateTimePattern::EPOCH_WEEKS_NAME,
+            DateTimePattern::EpochFractional(Precision::Seconds) => {
+                DateTimePattern::EPOCH_FRACTIONAL_NAME
+            }
+            DateTimePattern::EpochFractional(Precision::Millis) => {
+                DateTimePattern::EPOCH_FRACTIONAL_MILLIS_NAME
+            }
+            DateTimePattern::EpochFractional(Precision::Micros) => {
+                DateTimePattern::EPOCH_FRACTIONAL_MICROS_NAME
+            }
+            DateTimePattern::EpochFractional(Precision::Nanos) => {
+                DateTimePattern::EPOCH_FRACTIONAL_NANOS_NAME
+            }
+            DateTimePattern::Rfc3339(Precision::Seconds) => DateTimePattern::RFC_3339_NAME,
+            DateTimePattern::Rfc3339(Precision::Millis) => DateTimePattern::RFC_3339_MILLIS_NAME,
+            DateTimePattern::Rfc3339(Precision::Micros) => DateTimePattern::RFC_3339_MICROS_NAME,
+            DateTimePattern::Rfc3339(Precision::Nanos) => DateTimePattern::RFC_3339_NANOS_NAME,
+            DateTimePattern::Iso8601Basic(Precision::Seconds) => {
+                DateTimePattern::ISO_8601_BASIC_NAME
+            }
+            DateTimePattern::Iso8601Basic(Precision::Millis) => {
+                DateTimePattern::ISO_8601_BASIC_MILLIS_NAME
+            }
+            DateTimePattern::Iso8601Basic(Precision::Micros) => {
+                DateTimePattern::ISO_8601_BASIC_MICROS_NAME
+            }
+            DateTimePattern::Iso8601Basic(Precision::Nanos) => {
+                DateTimePattern::ISO_8601_BASIC_NANOS_NAME
+            }
+            DateTimePattern::IsoWeekDate => DateTimePattern::ISO_WEEK_DATE_NAME,
+            DateTimePattern::Rfc2822 => DateTimePattern::RFC_2822_NAME,
+            DateTimePattern::HttpDate => DateTimePattern::HTTP_DATE_NAME,
+            DateTimePattern::Custom(custom) => custom.pattern(),
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for DateTimePattern {
+    type Err = ParseDateTimePatternError;
+
+    /// Parses a [`DateTimePattern`] from either its name, e.g. `YyyyMmDd`,
+    /// or its pattern string, e.g. `%Y-%m-%d`.
+    ///
+    /// Any other string with a `%` specifier is parsed as a [`DateTimePattern::custom`] pattern,
+    /// owned by the returned value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::pattern::DateTimePattern;
+    ///
+    /// assert_eq!("YyyyMmDd".parse::<DateTimePattern>(), Ok(DateTimePattern::YyyyMmDd));
+    /// assert_eq!("%Y-%m-%d".parse::<DateTimePattern>(), Ok(DateTimePattern::YyyyMmDd));
+    /// assert_eq!("%Y%m%d".parse::<DateTimePattern>(), DateTimePattern::custom("%Y%m%d"));
+    /// assert_eq!(
+    ///     "Invalid".parse::<DateTimePattern>().unwrap_err().to_string(),
+    ///     "invalid date-time pattern: `Invalid`"
+    /// );
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match DateTimePattern::name_of(value).or_else(|| DateTimePattern::value_of(value)) {
+            Some(pattern) => Ok(pattern),
+            None if value.contains('%') => DateTimePattern::custom(value),
+            None => Err(ParseDateTimePatternError::new(value)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Precision {
+    /// The number of fraction digits, e.g. `3` for [`Precision::Millis`].
+    pub(crate) fn digits(&self) -> u32 {
+        match self {
+            Precision::Seconds => 0,
+            Precision::Millis => 3,
+            Precision::Micros => 6,
+            Precision::Nanos => 9,
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The nanoseconds since the unix epoch of `datetime`, negative before 1970.
+fn epoch_nanos_of<Tz: TimeZone>(datetime: &DateTime<Tz>) -> i128 {
+    datetime.timestamp() as i128 * NANOS_PER_SECOND + datetime.timestamp_subsec_nanos() as i128
+}
+
+/// Writes the whole `unit`s since the epoch, rounded down so that `-1ms` is `-1` second.
+fn write_epoch<Tz: TimeZone, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    datetime: &DateTime<Tz>,
+    unit: TimeUnit,
+) -> fmt::Result {
+    write!(
+        w,
+        "{}",
+        epoch_nanos_of(datetime).div_euclid(unit.to_nanos(1) as i128)
+    )
+}
+
+/// Writes the seconds since the epoch with `precision` fraction digits, rounded down
+/// as [`write_epoch`], e.g. `-0.001` one millisecond before 1970.
+fn write_epoch_fractional<Tz: TimeZone, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    datetime: &DateTime<Tz>,
+    precision: Precision,
+) -> fmt::Result {
+    let digits = precision.digits();
+    let units = epoch_nanos_of(datetime).div_euclid(10_i128.pow(9 - digits));
+    if digits == 0 {
+        return write!(w, "{}", units);
+    }
+
+    let scale = 10_u128.pow(digits);
+    let sign = if units < 0 { "-" } else { "" };
+    let (seconds, fraction) = (units.unsigned_abs() / scale, units.unsigned_abs() % scale);
+
+    write!(
+        w,
+        "{}{}.{:0width$}",
+        sign,
+        seconds,
+        fraction,
+        width = digits as usize
+    )
+}
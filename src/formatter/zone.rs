@@ -0,0 +1,272 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+//! Time zones of a [`DateTimeFormatter`](crate::formatter::DateTimeFormatter): UTC, a fixed
+//! offset, or, with the `tz` feature, a named IANA zone such as `Asia/Shanghai`.
+//!
+//! The `tz` feature embeds the tz database in the binary, so named zones resolve identically
+//! everywhere, without network access or a system `zoneinfo`.
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+
+use crate::error::{LocalTimeError, LocalTimeErrorKind, ParseZoneError};
+use crate::formatter::compiled::CompiledPattern;
+use crate::formatter::locale::Locale;
+use crate::formatter::pattern::DateTimePattern;
+
+// ----------------------------------------------------------------
+
+/// [`Zone`] a time zone a formatter renders instants in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Zone {
+    /// `UTC`
+    #[default]
+    Utc,
+    /// A fixed offset from UTC, e.g. `+08:00`.
+    Fixed(FixedOffset),
+    /// A named IANA zone, e.g. `Asia/Shanghai`, with its DST transitions.
+    #[cfg(feature = "tz")]
+    Named(chrono_tz::Tz),
+}
+
+/// [`AmbiguousTimePolicy`] how a local time which occurs twice, when the clocks are set back,
+/// resolves to an instant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AmbiguousTimePolicy {
+    /// The earliest instant, with the offset before the transition.
+    #[default]
+    Earliest,
+    /// The latest instant, with the offset after the transition.
+    Latest,
+    /// An ambiguous local time is an error.
+    Error,
+}
+
+impl Zone {
+    /// `UTC_NAME` `UTC`
+    pub const UTC_NAME: &'static str = "UTC";
+
+    /// Creates a named [`Zone`] from its IANA name, e.g. `America/New_York`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::formatter::zone::Zone;
+    ///
+    /// let zone = Zone::named("Asia/Shanghai").unwrap();
+    /// assert_eq!(zone.to_string(), "Asia/Shanghai");
+    /// assert!(Zone::named("Mars/Olympus_Mons").is_err());
+    /// ```
+    #[cfg(feature = "tz")]
+    pub fn named(name: &str) -> Result<Zone, ParseZoneError> {
+        name.parse::<chrono_tz::Tz>()
+            .map(Zone::Named)
+            .map_err(|_| ParseZoneError::new(name))
+    }
+
+    /// The offset from UTC of this zone at the instant `datetime`.
+    pub fn offset_at(&self, datetime: &DateTime<Utc>) -> FixedOffset {
+        match self {
+            Zone::Utc => datetime.offset().fix(),
+            Zone::Fixed(offset) => *offset,
+            #[cfg(feature = "tz")]
+            Zone::Named(tz) => datetime.with_timezone(tz).offset().fix(),
+        }
+    }
+
+    /// Resolves a local date-time in this zone to an instant.
+    ///
+    /// # Arguments
+    /// `datetime` - The local date-time, as read on a clock in this zone.
+    /// `policy` - How a local time which occurs twice resolves.
+    ///
+    /// # Returns
+    /// The instant, with the offset in effect, or a [`LocalTimeError`] if the local time falls
+    /// in a gap, when the clocks are set forward, or is ambiguous and the policy is
+    /// [`AmbiguousTimePolicy::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{FixedOffset, NaiveDate};
+    /// use chronounit::formatter::zone::{AmbiguousTimePolicy, Zone};
+    ///
+    /// let zone = Zone::Fixed(FixedOffset::east_opt(8 * 3600).unwrap());
+    /// let local = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    ///
+    /// let datetime = zone.resolve(&local, AmbiguousTimePolicy::Error).unwrap();
+    /// assert_eq!(datetime.to_rfc3339(), "2024-03-01T09:00:00+08:00");
+    /// ```
+    pub fn resolve(
+        &self,
+        datetime: &NaiveDateTime,
+        policy: AmbiguousTimePolicy,
+    ) -> Result<DateTime<FixedOffset>, LocalTimeError> {
+        match self {
+            Zone::Utc => Ok(datetime.and_utc().fixed_offset()),
+            Zone::Fixed(offset) => self.resolve_with(offset, datetime, policy),
+            #[cfg(feature = "tz")]
+            Zone::Named(tz) => self.resolve_with(tz, datetime, policy),
+        }
+    }
+
+    fn resolve_with<Tz: TimeZone>(
+        &self,
+        zone: &Tz,
+        datetime: &NaiveDateTime,
+        policy: AmbiguousTimePolicy,
+    ) -> Result<DateTime<FixedOffset>, LocalTimeError> {
+        match zone.from_local_datetime(datetime) {
+            LocalResult::Single(resolved) => Ok(resolved.fixed_offset()),
+            LocalResult::Ambiguous(earliest, latest) => match policy {
+                AmbiguousTimePolicy::Earliest => Ok(earliest.fixed_offset()),
+                AmbiguousTimePolicy::Latest => Ok(latest.fixed_offset()),
+                AmbiguousTimePolicy::Error => Err(LocalTimeError::new(
+                    *datetime,
+                    *self,
+                    LocalTimeErrorKind::Ambiguous(earliest.fixed_offset(), latest.fixed_offset()),
+                )),
+            },
+            LocalResult::None => Err(LocalTimeError::new(
+                *datetime,
+                *self,
+                LocalTimeErrorKind::Nonexistent,
+            )),
+        }
+    }
+
+    /// Writes the instant `datetime` in this zone into `w`, with the names of `locale`.
+    pub(crate) fn write<Tz: TimeZone>(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Tz>,
+        pattern: DateTimePattern,
+        locale: Locale,
+    ) -> fmt::Result {
+        match self {
+            Zone::Utc => pattern.write_localized(w, &datetime.with_timezone(&Utc), locale),
+            Zone::Fixed(offset) => {
+                pattern.write_localized(w, &datetime.with_timezone(offset), locale)
+            }
+            #[cfg(feature = "tz")]
+            Zone::Named(tz) => pattern.write_localized(w, &datetime.with_timezone(tz), locale),
+        }
+    }
+
+    /// Writes the instant `datetime` in this zone into `w`, with the pattern `compiled` once.
+    pub(crate) fn write_compiled<Tz: TimeZone>(
+        &self,
+        w: &mut dyn fmt::Write,
+        datetime: &DateTime<Tz>,
+        compiled: &CompiledPattern,
+    ) -> fmt::Result {
+        match self {
+            Zone::Utc => compiled.write(w, &datetime.with_timezone(&Utc)),
+            Zone::Fixed(offset) => compiled.write(w, &datetime.with_timezone(offset)),
+            #[cfg(feature = "tz")]
+            Zone::Named(tz) => compiled.write(w, &datetime.with_timezone(tz)),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    /// Formats the [`Zone`] as `UTC`, its offset, e.g. `+08:00`, or its IANA name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Utc => f.write_str(Zone::UTC_NAME),
+            Zone::Fixed(offset) => write!(f, "{}", offset),
+            #[cfg(feature = "tz")]
+            Zone::Named(tz) => f.write_str(tz.name()),
+        }
+    }
+}
+
+impl FromStr for Zone {
+    type Err = ParseZoneError;
+
+    /// Parses a [`Zone`] from `UTC` or `Z`, an offset, e.g. `+08:00` or `-0500`,
+    /// or, with the `tz` feature, an IANA name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::FixedOffset;
+    /// use chronounit::formatter::zone::Zone;
+    ///
+    /// assert_eq!("UTC".parse::<Zone>(), Ok(Zone::Utc));
+    /// assert_eq!(
+    ///     "+08:00".parse::<Zone>(),
+    ///     Ok(Zone::Fixed(FixedOffset::east_opt(8 * 3600).unwrap()))
+    /// );
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case(Zone::UTC_NAME) || value == "Z" {
+            return Ok(Zone::Utc);
+        }
+        if value.starts_with(['+', '-']) {
+            // `+HHMM` or `+HH:MM`, nothing after.
+            let digits = &value[1..];
+            let valid = matches!(digits.len(), 4 | 5)
+                && digits
+                    .char_indices()
+                    .all(|(i, c)| c.is_ascii_digit() || (i == 2 && c == ':' && digits.len() == 5));
+            return valid
+                .then(|| value.parse::<FixedOffset>().ok())
+                .flatten()
+                .map(Zone::Fixed)
+                .ok_or_else(|| ParseZoneError::new(value));
+        }
+
+        #[cfg(feature = "tz")]
+        {
+            Zone::named(value)
+        }
+        #[cfg(not(feature = "tz"))]
+        {
+            Err(ParseZoneError::new(value))
+        }
+    }
+}
+
+impl From<Utc> for Zone {
+    /// override
+    fn from(_: Utc) -> Self {
+        Zone::Utc
+    }
+}
+
+impl From<FixedOffset> for Zone {
+    /// override
+    fn from(offset: FixedOffset) -> Self {
+        Zone::Fixed(offset)
+    }
+}
+
+#[cfg(feature = "tz")]
+impl From<chrono_tz::Tz> for Zone {
+    /// override
+    fn from(tz: chrono_tz::Tz) -> Self {
+        Zone::Named(tz)
+    }
+}
@@ -16,12 +16,23 @@
 
 // ----------------------------------------------------------------
 
+use std::fmt;
+use std::str::FromStr;
 use std::thread;
 use std::time::Duration;
 
+use crate::error::{OverflowError, ParseTimeUnitError};
+use crate::ratio::UnitRatio;
+use crate::rounding::RoundingMode;
+
 // ----------------------------------------------------------------
 
+pub mod calendar;
+pub mod duration;
+pub mod error;
 pub mod formatter;
+pub mod ratio;
+pub mod rounding;
 
 /// @since 0.3.0
 #[macro_use]
@@ -34,8 +45,16 @@ mod tests;
 
 // ----------------------------------------------------------------
 
+const DURATION_TARGET: &str = "Duration";
+const CHRONO_DURATION_TARGET: &str = "chrono::Duration";
+
 /// [`TimeUnit`] time unit.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Units are ordered by magnitude, from [`TimeUnit::Nanoseconds`] to [`TimeUnit::Weeks`].
+///
+/// All units have a fixed length, see [`CalendarUnit`](crate::calendar::CalendarUnit)
+/// for months, quarters and years.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[allow(dead_code)]
 pub enum TimeUnit {
     /// Time unit representing one thousandth of a microsecond.
@@ -52,8 +71,73 @@ pub enum TimeUnit {
     Hours,
     /// Time unit representing twenty-four hours.
     Days,
+    /// Time unit representing seven days.
+    Weeks,
+}
+
+/// [`UnitAliasMode`] how strictly [`TimeUnit::parse_alias`] treats ambiguous aliases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum UnitAliasMode {
+    /// Accepts every alias, resolving `m` to [`TimeUnit::Minutes`].
+    #[default]
+    Lenient,
+    /// Rejects aliases which are ambiguous outside of a time context, such as `m` (minutes or months).
+    Strict,
 }
 
+/// Aliases accepted by [`TimeUnit::parse_alias`], matched case-insensitively.
+const ALIASES: &[(&str, TimeUnit)] = &[
+    ("ns", TimeUnit::Nanoseconds),
+    ("nsec", TimeUnit::Nanoseconds),
+    ("nsecs", TimeUnit::Nanoseconds),
+    ("nano", TimeUnit::Nanoseconds),
+    ("nanos", TimeUnit::Nanoseconds),
+    ("nanosecond", TimeUnit::Nanoseconds),
+    ("nanoseconds", TimeUnit::Nanoseconds),
+    ("\u{b5}s", TimeUnit::Microseconds),
+    ("\u{3bc}s", TimeUnit::Microseconds),
+    ("us", TimeUnit::Microseconds),
+    ("usec", TimeUnit::Microseconds),
+    ("usecs", TimeUnit::Microseconds),
+    ("micro", TimeUnit::Microseconds),
+    ("micros", TimeUnit::Microseconds),
+    ("microsecond", TimeUnit::Microseconds),
+    ("microseconds", TimeUnit::Microseconds),
+    ("ms", TimeUnit::Milliseconds),
+    ("msec", TimeUnit::Milliseconds),
+    ("msecs", TimeUnit::Milliseconds),
+    ("milli", TimeUnit::Milliseconds),
+    ("millis", TimeUnit::Milliseconds),
+    ("millisecond", TimeUnit::Milliseconds),
+    ("milliseconds", TimeUnit::Milliseconds),
+    ("s", TimeUnit::Seconds),
+    ("sec", TimeUnit::Seconds),
+    ("secs", TimeUnit::Seconds),
+    ("second", TimeUnit::Seconds),
+    ("seconds", TimeUnit::Seconds),
+    ("m", TimeUnit::Minutes),
+    ("min", TimeUnit::Minutes),
+    ("mins", TimeUnit::Minutes),
+    ("minute", TimeUnit::Minutes),
+    ("minutes", TimeUnit::Minutes),
+    ("h", TimeUnit::Hours),
+    ("hr", TimeUnit::Hours),
+    ("hrs", TimeUnit::Hours),
+    ("hour", TimeUnit::Hours),
+    ("hours", TimeUnit::Hours),
+    ("d", TimeUnit::Days),
+    ("day", TimeUnit::Days),
+    ("days", TimeUnit::Days),
+    ("w", TimeUnit::Weeks),
+    ("wk", TimeUnit::Weeks),
+    ("wks", TimeUnit::Weeks),
+    ("week", TimeUnit::Weeks),
+    ("weeks", TimeUnit::Weeks),
+];
+
+/// Aliases rejected by [`UnitAliasMode::Strict`].
+const AMBIGUOUS_ALIASES: &[&str] = &["m"];
+
 impl TimeUnit {
     /// 0
     pub const ZERO: u64 = 0;
@@ -70,10 +154,13 @@ impl TimeUnit {
     pub const MINUTES_PER_HOUR: u64 = 60;
     /// 24
     pub const HOURS_PER_DAY: u64 = 24;
+    /// 7
+    pub const DAYS_PER_WEEK: u64 = 7;
 
     pub const SECONDS_PER_HOUR: u64 = Self::SECONDS_PER_MINUTE * Self::MINUTES_PER_HOUR;
     pub const SECONDS_PER_DAY: u64 =
         Self::SECONDS_PER_MINUTE * Self::MINUTES_PER_HOUR * Self::HOURS_PER_DAY;
+    pub const SECONDS_PER_WEEK: u64 = Self::SECONDS_PER_DAY * Self::DAYS_PER_WEEK;
 
     // ----------------------------------------------------------------
 
@@ -90,6 +177,7 @@ impl TimeUnit {
     pub const MINUTE_SCALE: u64 = Self::SECONDS_PER_MINUTE * Self::SECOND_SCALE;
     pub const HOUR_SCALE: u64 = Self::MINUTES_PER_HOUR * Self::MINUTE_SCALE;
     pub const DAY_SCALE: u64 = Self::HOURS_PER_DAY * Self::HOUR_SCALE;
+    pub const WEEK_SCALE: u64 = Self::DAYS_PER_WEEK * Self::DAY_SCALE;
 
     // ----------------------------------------------------------------
 
@@ -99,6 +187,7 @@ impl TimeUnit {
     pub const NANOS_PER_MINUTE: u64 = Self::MINUTE_SCALE;
     pub const NANOS_PER_HOUR: u64 = Self::HOUR_SCALE;
     pub const NANOS_PER_DAY: u64 = Self::DAY_SCALE;
+    pub const NANOS_PER_WEEK: u64 = Self::WEEK_SCALE;
 
     // ----------------------------------------------------------------
 
@@ -109,6 +198,7 @@ impl TimeUnit {
     pub const MINUTES_NAME: &'static str = "Minutes";
     pub const HOURS_NAME: &'static str = "Hours";
     pub const DAYS_NAME: &'static str = "Days";
+    pub const WEEKS_NAME: &'static str = "Weeks";
 
     pub const NANOSECONDS_LOWER_CASE_NAME: &'static str = "nanoseconds";
     pub const MICROSECONDS_LOWER_CASE_NAME: &'static str = "microseconds";
@@ -117,6 +207,7 @@ impl TimeUnit {
     pub const MINUTES_LOWER_CASE_NAME: &'static str = "minutes";
     pub const HOURS_LOWER_CASE_NAME: &'static str = "hours";
     pub const DAYS_LOWER_CASE_NAME: &'static str = "days";
+    pub const WEEKS_LOWER_CASE_NAME: &'static str = "weeks";
 
     // ----------------------------------------------------------------
 
@@ -140,6 +231,7 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Minutes.to_nanos(1024), 1024 * (1000 * 1000 * 1000 * 60));
     /// assert_eq!(TimeUnit::Hours.to_nanos(1024), 1024 * (1000 * 1000 * 1000 * 60 * 60));
     /// assert_eq!(TimeUnit::Days.to_nanos(1024), 1024 * (1000 * 1000 * 1000 * 60 * 60 * 24));
+    /// assert_eq!(TimeUnit::Weeks.to_nanos(1024), 1024 * (1000 * 1000 * 1000 * 60 * 60 * 24 * 7));
     /// ```
     pub fn to_nanos(&self, amount: u64) -> u64 {
         match self {
@@ -150,6 +242,7 @@ impl TimeUnit {
             TimeUnit::Minutes => amount * Self::NANOS_PER_MINUTE,
             TimeUnit::Hours => amount * Self::NANOS_PER_HOUR,
             TimeUnit::Days => amount * Self::NANOS_PER_DAY,
+            TimeUnit::Weeks => amount * Self::NANOS_PER_WEEK,
         }
     }
 
@@ -175,7 +268,7 @@ impl TimeUnit {
     /// assert_eq!( TimeUnit::Days.to_micros(1024), 1024 * (1000 * 1000 * 60 * 60 * 24));
     /// ```
     pub fn to_micros(&self, amount: u64) -> u64 {
-        self.to_nanos(amount) / Self::NANOS_PER_MICROSECOND
+        self.to(amount, TimeUnit::Microseconds)
     }
 
     /// Converts the given time amount to milliseconds.
@@ -200,7 +293,7 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Days.to_millis(1024), 1024 * (1000 * 60 * 60 * 24));
     /// ```
     pub fn to_millis(&self, amount: u64) -> u64 {
-        self.to_nanos(amount) / Self::NANOS_PER_MILLISECOND
+        self.to(amount, TimeUnit::Milliseconds)
     }
 
     /// Converts the given time amount to seconds.
@@ -225,7 +318,7 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Days.to_seconds(1024), 1024 * (60 * 60 * 24));
     /// ```
     pub fn to_seconds(&self, amount: u64) -> u64 {
-        self.to_nanos(amount) / Self::NANOS_PER_SECOND
+        self.to(amount, TimeUnit::Seconds)
     }
 
     /// Converts the given time amount to minutes.
@@ -250,7 +343,7 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Days.to_minutes(1024), 1024 * (60 * 24));
     /// ```
     pub fn to_minutes(&self, amount: u64) -> u64 {
-        self.to_seconds(amount) / Self::SECONDS_PER_MINUTE
+        self.to(amount, TimeUnit::Minutes)
     }
 
     /// Converts the given time amount to hours.
@@ -275,7 +368,7 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Days.to_hours(1024), 1024 * 24);
     /// ```
     pub fn to_hours(&self, amount: u64) -> u64 {
-        self.to_minutes(amount) / Self::MINUTES_PER_HOUR
+        self.to(amount, TimeUnit::Hours)
     }
 
     /// Converts the given time amount to days.
@@ -300,7 +393,29 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Days.to_days(1024), 1024);
     /// ```
     pub fn to_days(&self, amount: u64) -> u64 {
-        self.to_hours(amount) / Self::HOURS_PER_DAY
+        self.to(amount, TimeUnit::Days)
+    }
+
+    /// Converts the given time amount to weeks.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in weeks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.to_weeks(1024), 1024 / 24 / 7);
+    /// assert_eq!(TimeUnit::Days.to_weeks(1024), 1024 / 7);
+    /// assert_eq!(TimeUnit::Weeks.to_weeks(1024), 1024);
+    /// assert_eq!(TimeUnit::Weeks.to_days(1024), 1024 * 7);
+    /// ```
+    pub fn to_weeks(&self, amount: u64) -> u64 {
+        self.to(amount, TimeUnit::Weeks)
     }
 
     /// Converts the given time amount to a `std` [`Duration`].
@@ -324,6 +439,7 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Minutes.to_duration(1), Duration::from_secs(60));
     /// assert_eq!(TimeUnit::Hours.to_duration(1), Duration::from_secs(60 * 60));
     /// assert_eq!(TimeUnit::Days.to_duration(1), Duration::from_secs(60 * 60 * 24));
+    /// assert_eq!(TimeUnit::Weeks.to_duration(1), Duration::from_secs(60 * 60 * 24 * 7));
     /// ```
     pub fn to_duration(&self, amount: u64) -> Duration {
         match self {
@@ -334,6 +450,7 @@ impl TimeUnit {
             TimeUnit::Minutes => Duration::from_secs(amount * TimeUnit::SECONDS_PER_MINUTE),
             TimeUnit::Hours => Duration::from_secs(amount * TimeUnit::SECONDS_PER_HOUR),
             TimeUnit::Days => Duration::from_secs(amount * TimeUnit::SECONDS_PER_DAY),
+            TimeUnit::Weeks => Duration::from_secs(amount * TimeUnit::SECONDS_PER_WEEK),
         }
     }
 
@@ -358,17 +475,15 @@ impl TimeUnit {
     /// assert_eq!(TimeUnit::Minutes.to_chrono_duration(1), Duration::minutes(1));
     /// assert_eq!(TimeUnit::Hours.to_chrono_duration(1), Duration::hours(1));
     /// assert_eq!(TimeUnit::Days.to_chrono_duration(1), Duration::days(1));
+    /// assert_eq!(TimeUnit::Weeks.to_chrono_duration(1), Duration::weeks(1));
     /// ```
+    ///
+    /// # Panics
+    /// Panics if the amount is out of the range supported by `chrono`,
+    /// see [`TimeUnit::checked_to_chrono_duration`].
     pub fn to_chrono_duration(&self, amount: u64) -> chrono::Duration {
-        match self {
-            TimeUnit::Nanoseconds => chrono::Duration::nanoseconds(amount as i64),
-            TimeUnit::Microseconds => chrono::Duration::microseconds(amount as i64),
-            TimeUnit::Milliseconds => chrono::Duration::milliseconds(amount as i64),
-            TimeUnit::Seconds => chrono::Duration::seconds(amount as i64),
-            TimeUnit::Minutes => chrono::Duration::minutes(amount as i64),
-            TimeUnit::Hours => chrono::Duration::hours(amount as i64),
-            TimeUnit::Days => chrono::Duration::days(amount as i64),
-        }
+        self.checked_to_chrono_duration(amount)
+            .unwrap_or_else(|err| panic!("{}", err))
     }
 
     /// Retrieves the string representation of this [`TimeUnit`].
@@ -488,84 +603,1669 @@ impl TimeUnit {
 }
 
 impl TimeUnit {
-    /// Returns the corresponding [`TimeUnit`] enum based on the provided [`TimeUnit`] value/name.
+    /// Converts the given time amount in the given source unit to this unit,
+    /// like `java.util.concurrent.TimeUnit#convert`.
+    ///
+    /// The amount is scaled directly by the ratio between both units instead of going through nanoseconds.
+    /// Conversions from finer to coarser units truncate,
+    /// conversions from coarser to finer units saturate at `u64::MAX` instead of overflowing.
     ///
     /// # Arguments
-    /// `value` - A string slice representing the value/name of the [`TimeUnit`].
+    /// `amount` - The time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
     ///
     /// # Returns
-    /// An `Option<Self>` containing the matched [`TimeUnit`] if a valid value/name is given, otherwise returns `None`.
+    /// The converted time amount in this unit.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// use chronounit::TimeUnit;
-    /// assert_eq!(TimeUnit::value_of("Nanoseconds"), Some(TimeUnit::Nanoseconds));
-    /// assert_eq!(TimeUnit::value_of("Microseconds"), Some(TimeUnit::Microseconds));
-    /// assert_eq!(TimeUnit::value_of("Milliseconds"), Some(TimeUnit::Milliseconds));
-    /// assert_eq!(TimeUnit::value_of("Seconds"), Some(TimeUnit::Seconds));
-    /// assert_eq!(TimeUnit::value_of("Minutes"), Some(TimeUnit::Minutes));
-    /// assert_eq!(TimeUnit::value_of("Hours"), Some(TimeUnit::Hours));
-    /// assert_eq!(TimeUnit::value_of("Days"), Some(TimeUnit::Days));
-    /// assert_eq!(TimeUnit::value_of("Invalid"), None);
+    ///
+    /// assert_eq!(TimeUnit::Milliseconds.convert(2, TimeUnit::Hours), 2 * 60 * 60 * 1000);
+    /// assert_eq!(TimeUnit::Minutes.convert(119, TimeUnit::Seconds), 1);
+    /// assert_eq!(TimeUnit::Nanoseconds.convert(u64::MAX, TimeUnit::Days), u64::MAX);
+    /// assert_eq!(TimeUnit::Seconds.convert(u64::MAX / 86_400, TimeUnit::Days), u64::MAX / 86_400 * 86_400);
     /// ```
-    pub fn value_of(value: &str) -> Option<Self> {
-        match value {
-            TimeUnit::NANOSECONDS_NAME => Some(TimeUnit::Nanoseconds),
-            TimeUnit::MICROSECONDS_NAME => Some(TimeUnit::Microseconds),
-            TimeUnit::MILLISECONDS_NAME => Some(TimeUnit::Milliseconds),
-            TimeUnit::SECONDS_NAME => Some(TimeUnit::Seconds),
-            TimeUnit::MINUTES_NAME => Some(TimeUnit::Minutes),
-            TimeUnit::HOURS_NAME => Some(TimeUnit::Hours),
-            TimeUnit::DAYS_NAME => Some(TimeUnit::Days),
-            _ => None,
-        }
+    pub fn convert(&self, amount: u64, source: TimeUnit) -> u64 {
+        self.checked_convert(amount, source).unwrap_or(u64::MAX)
     }
 
-    /// Returns the corresponding [`TimeUnit`] enum based on the provided [`TimeUnit`] value/name.
-    /// performing a case-insensitive match.
+    /// Checked variant of [`TimeUnit::convert`].
     ///
     /// # Arguments
-    /// `value` - A string slice representing the value/name of the [`TimeUnit`].
+    /// `amount` - The time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
     ///
     /// # Returns
-    /// An `Option<Self>` containing the matched [`TimeUnit`] if a valid value/name is given, otherwise returns `None`.
+    /// The converted time amount in this unit, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// use chronounit::TimeUnit;
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Nanoseconds"), Some(TimeUnit::Nanoseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Microseconds"), Some(TimeUnit::Microseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Milliseconds"), Some(TimeUnit::Milliseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Seconds"), Some(TimeUnit::Seconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Minutes"), Some(TimeUnit::Minutes));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Hours"), Some(TimeUnit::Hours));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Days"), Some(TimeUnit::Days));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("Invalid"), None);
     ///
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("NANOSECONDS"), Some(TimeUnit::Nanoseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("MICROSECONDS"), Some(TimeUnit::Microseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("MILLISECONDS"), Some(TimeUnit::Milliseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("SECONDS"), Some(TimeUnit::Seconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("MINUTES"), Some(TimeUnit::Minutes));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("HOURS"), Some(TimeUnit::Hours));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("DAYS"), Some(TimeUnit::Days));
+    /// assert_eq!(TimeUnit::Seconds.checked_convert(3, TimeUnit::Minutes), Ok(180));
+    /// assert!(TimeUnit::Nanoseconds.checked_convert(u64::MAX, TimeUnit::Days).is_err());
+    /// ```
+    pub fn checked_convert(&self, amount: u64, source: TimeUnit) -> Result<u64, OverflowError> {
+        source.checked_to(amount, *self)
+    }
+
+    /// Converts the given [`Duration`] to this unit.
     ///
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("nanoseconds"), Some(TimeUnit::Nanoseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("microseconds"), Some(TimeUnit::Microseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("milliseconds"), Some(TimeUnit::Milliseconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("seconds"), Some(TimeUnit::Seconds));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("minutes"), Some(TimeUnit::Minutes));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("hours"), Some(TimeUnit::Hours));
-    /// assert_eq!(TimeUnit::insensitive_case_value_of("days"), Some(TimeUnit::Days));
+    /// Sub-unit remainders are truncated, and the result saturates at `u64::MAX`.
+    ///
+    /// # Arguments
+    /// `duration` - The [`Duration`] to convert.
+    ///
+    /// # Returns
+    /// The amount of whole units in `duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.convert_duration(Duration::from_secs(150)), 2);
+    /// assert_eq!(TimeUnit::Milliseconds.convert_duration(Duration::new(1, 500_000_000)), 1500);
+    /// assert_eq!(TimeUnit::Nanoseconds.convert_duration(Duration::MAX), u64::MAX);
     /// ```
-    pub fn insensitive_case_value_of(value: &str) -> Option<Self> {
-        match value.to_lowercase().as_str() {
-            TimeUnit::NANOSECONDS_LOWER_CASE_NAME => Some(TimeUnit::Nanoseconds),
-            TimeUnit::MICROSECONDS_LOWER_CASE_NAME => Some(TimeUnit::Microseconds),
-            TimeUnit::MILLISECONDS_LOWER_CASE_NAME => Some(TimeUnit::Milliseconds),
-            TimeUnit::SECONDS_LOWER_CASE_NAME => Some(TimeUnit::Seconds),
-            TimeUnit::MINUTES_LOWER_CASE_NAME => Some(TimeUnit::Minutes),
-            TimeUnit::HOURS_LOWER_CASE_NAME => Some(TimeUnit::Hours),
-            TimeUnit::DAYS_LOWER_CASE_NAME => Some(TimeUnit::Days),
-            _ => None,
+    pub fn convert_duration(&self, duration: Duration) -> u64 {
+        let amount = duration.as_nanos() / self.scale() as u128;
+        u64::try_from(amount).unwrap_or(u64::MAX)
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Checked conversion of the given time amount to nanoseconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in nanoseconds, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.checked_to_nanos(1024), Ok(1024 * (1000 * 1000 * 1000)));
+    /// assert!(TimeUnit::Days.checked_to_nanos(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_nanos(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Nanoseconds)
+    }
+
+    /// Checked conversion of the given time amount to microseconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in microseconds, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Nanoseconds.checked_to_micros(1024), Ok(1024 / 1000));
+    /// assert_eq!(TimeUnit::Seconds.checked_to_micros(1024), Ok(1024 * (1000 * 1000)));
+    /// assert!(TimeUnit::Days.checked_to_micros(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_micros(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Microseconds)
+    }
+
+    /// Checked conversion of the given time amount to milliseconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in milliseconds, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Microseconds.checked_to_millis(1024), Ok(1024 / 1000));
+    /// assert_eq!(TimeUnit::Seconds.checked_to_millis(1024), Ok(1024 * 1000));
+    /// assert!(TimeUnit::Days.checked_to_millis(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_millis(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Milliseconds)
+    }
+
+    /// Checked conversion of the given time amount to seconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in seconds, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Milliseconds.checked_to_seconds(1024), Ok(1024 / 1000));
+    /// assert_eq!(TimeUnit::Minutes.checked_to_seconds(1024), Ok(1024 * 60));
+    /// assert!(TimeUnit::Days.checked_to_seconds(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_seconds(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Seconds)
+    }
+
+    /// Checked conversion of the given time amount to minutes.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in minutes, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.checked_to_minutes(1024), Ok(1024 / 60));
+    /// assert_eq!(TimeUnit::Hours.checked_to_minutes(1024), Ok(1024 * 60));
+    /// assert!(TimeUnit::Days.checked_to_minutes(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_minutes(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Minutes)
+    }
+
+    /// Checked conversion of the given time amount to hours.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in hours, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.checked_to_hours(1024), Ok(1024 / 60));
+    /// assert_eq!(TimeUnit::Days.checked_to_hours(1024), Ok(1024 * 24));
+    /// assert!(TimeUnit::Days.checked_to_hours(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_hours(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Hours)
+    }
+
+    /// Checked conversion of the given time amount to days.
+    ///
+    /// Down-conversions never overflow, so this only fails for [`TimeUnit::Weeks`].
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in days, or an [`OverflowError`] if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.checked_to_days(1024), Ok(1024 / 24));
+    /// assert_eq!(TimeUnit::Nanoseconds.checked_to_days(u64::MAX), Ok(u64::MAX / 86_400_000_000_000));
+    /// assert!(TimeUnit::Weeks.checked_to_days(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_days(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Days)
+    }
+
+    /// Checked conversion of the given time amount to weeks.
+    ///
+    /// Weeks are the largest unit, so this never fails.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in weeks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.checked_to_weeks(1024), Ok(1024 / 7));
+    /// assert_eq!(TimeUnit::Weeks.checked_to_weeks(u64::MAX), Ok(u64::MAX));
+    /// ```
+    pub fn checked_to_weeks(&self, amount: u64) -> Result<u64, OverflowError> {
+        self.checked_to(amount, TimeUnit::Weeks)
+    }
+
+    /// Checked conversion of the given time amount to a `std` [`Duration`].
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in [`Duration`], or an [`OverflowError`] if the seconds do not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.checked_to_duration(1), Ok(Duration::from_secs(60)));
+    /// assert_eq!(TimeUnit::Seconds.checked_to_duration(u64::MAX), Ok(Duration::from_secs(u64::MAX)));
+    /// assert!(TimeUnit::Days.checked_to_duration(u64::MAX).is_err());
+    /// ```
+    pub fn checked_to_duration(&self, amount: u64) -> Result<Duration, OverflowError> {
+        let seconds = |per: u64| {
+            amount
+                .checked_mul(per)
+                .map(Duration::from_secs)
+                .ok_or_else(|| self.overflow(amount as i128, DURATION_TARGET))
+        };
+
+        match self {
+            TimeUnit::Minutes => seconds(TimeUnit::SECONDS_PER_MINUTE),
+            TimeUnit::Hours => seconds(TimeUnit::SECONDS_PER_HOUR),
+            TimeUnit::Days => seconds(TimeUnit::SECONDS_PER_DAY),
+            TimeUnit::Weeks => seconds(TimeUnit::SECONDS_PER_WEEK),
+            _ => Ok(self.to_duration(amount)),
         }
     }
+
+    /// Checked conversion of the given time amount to a [`chrono::Duration`].
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in [`chrono::Duration`],
+    /// or an [`OverflowError`] if it is out of the range supported by `chrono`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.checked_to_chrono_duration(1), Ok(chrono::Duration::hours(1)));
+    /// assert!(TimeUnit::Nanoseconds.checked_to_chrono_duration(u64::MAX).is_err());
+    /// assert!(TimeUnit::Days.checked_to_chrono_duration(u64::MAX / 2).is_err());
+    /// ```
+    pub fn checked_to_chrono_duration(
+        &self,
+        amount: u64,
+    ) -> Result<chrono::Duration, OverflowError> {
+        let amount = i64::try_from(amount)
+            .map_err(|_| self.overflow(amount as i128, CHRONO_DURATION_TARGET))?;
+        self.checked_to_chrono_duration_i64(amount)
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Saturating conversion of the given time amount to nanoseconds.
+    ///
+    /// # Returns
+    /// The converted time amount in nanoseconds, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.saturating_to_nanos(1024), 1024 * (1000 * 1000 * 1000));
+    /// assert_eq!(TimeUnit::Days.saturating_to_nanos(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_nanos(&self, amount: u64) -> u64 {
+        self.checked_to_nanos(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to microseconds.
+    ///
+    /// # Returns
+    /// The converted time amount in microseconds, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.saturating_to_micros(1024), 1024 * (1000 * 1000));
+    /// assert_eq!(TimeUnit::Days.saturating_to_micros(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_micros(&self, amount: u64) -> u64 {
+        self.checked_to_micros(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to milliseconds.
+    ///
+    /// # Returns
+    /// The converted time amount in milliseconds, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.saturating_to_millis(1024), 1024 * 1000);
+    /// assert_eq!(TimeUnit::Days.saturating_to_millis(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_millis(&self, amount: u64) -> u64 {
+        self.checked_to_millis(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to seconds.
+    ///
+    /// # Returns
+    /// The converted time amount in seconds, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.saturating_to_seconds(1024), 1024 * 60);
+    /// assert_eq!(TimeUnit::Days.saturating_to_seconds(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_seconds(&self, amount: u64) -> u64 {
+        self.checked_to_seconds(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to minutes.
+    ///
+    /// # Returns
+    /// The converted time amount in minutes, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.saturating_to_minutes(1024), 1024 * 60);
+    /// assert_eq!(TimeUnit::Days.saturating_to_minutes(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_minutes(&self, amount: u64) -> u64 {
+        self.checked_to_minutes(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to hours.
+    ///
+    /// # Returns
+    /// The converted time amount in hours, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.saturating_to_hours(1024), 1024 * 24);
+    /// assert_eq!(TimeUnit::Days.saturating_to_hours(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_hours(&self, amount: u64) -> u64 {
+        self.checked_to_hours(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to days.
+    ///
+    /// # Returns
+    /// The converted time amount in days, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.saturating_to_days(1024), 1024 / 24);
+    /// assert_eq!(TimeUnit::Days.saturating_to_days(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_days(&self, amount: u64) -> u64 {
+        self.checked_to_days(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to weeks.
+    ///
+    /// # Returns
+    /// The converted time amount in weeks, or `u64::MAX` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.saturating_to_weeks(1024), 1024 / 7);
+    /// assert_eq!(TimeUnit::Weeks.saturating_to_weeks(u64::MAX), u64::MAX);
+    /// ```
+    pub fn saturating_to_weeks(&self, amount: u64) -> u64 {
+        self.checked_to_weeks(amount).unwrap_or(u64::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to a `std` [`Duration`].
+    ///
+    /// # Returns
+    /// The converted time amount in [`Duration`], or [`Duration::MAX`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.saturating_to_duration(1), Duration::from_secs(60 * 60));
+    /// assert_eq!(TimeUnit::Days.saturating_to_duration(u64::MAX), Duration::MAX);
+    /// ```
+    pub fn saturating_to_duration(&self, amount: u64) -> Duration {
+        self.checked_to_duration(amount).unwrap_or(Duration::MAX)
+    }
+
+    /// Saturating conversion of the given time amount to a [`chrono::Duration`].
+    ///
+    /// # Returns
+    /// The converted time amount in [`chrono::Duration`], or [`chrono::Duration::MAX`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.saturating_to_chrono_duration(1), chrono::Duration::hours(1));
+    /// assert_eq!(TimeUnit::Days.saturating_to_chrono_duration(u64::MAX), chrono::Duration::MAX);
+    /// ```
+    pub fn saturating_to_chrono_duration(&self, amount: u64) -> chrono::Duration {
+        self.checked_to_chrono_duration(amount)
+            .unwrap_or(chrono::Duration::MAX)
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Wrapping conversion of the given time amount to nanoseconds.
+    ///
+    /// # Returns
+    /// The converted time amount in nanoseconds, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.wrapping_to_nanos(1024), 1024 * (1000 * 1000 * 1000));
+    /// assert_eq!(TimeUnit::Microseconds.wrapping_to_nanos(u64::MAX), u64::MAX.wrapping_mul(1000));
+    /// ```
+    pub fn wrapping_to_nanos(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Nanoseconds)
+    }
+
+    /// Wrapping conversion of the given time amount to microseconds.
+    ///
+    /// # Returns
+    /// The converted time amount in microseconds, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.wrapping_to_micros(1024), 1024 * (1000 * 1000));
+    /// assert_eq!(TimeUnit::Milliseconds.wrapping_to_micros(u64::MAX), u64::MAX.wrapping_mul(1000));
+    /// ```
+    pub fn wrapping_to_micros(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Microseconds)
+    }
+
+    /// Wrapping conversion of the given time amount to milliseconds.
+    ///
+    /// # Returns
+    /// The converted time amount in milliseconds, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.wrapping_to_millis(1024), 1024 * 1000);
+    /// assert_eq!(TimeUnit::Seconds.wrapping_to_millis(u64::MAX), u64::MAX.wrapping_mul(1000));
+    /// ```
+    pub fn wrapping_to_millis(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Milliseconds)
+    }
+
+    /// Wrapping conversion of the given time amount to seconds.
+    ///
+    /// # Returns
+    /// The converted time amount in seconds, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.wrapping_to_seconds(1024), 1024 * 60);
+    /// assert_eq!(TimeUnit::Minutes.wrapping_to_seconds(u64::MAX), u64::MAX.wrapping_mul(60));
+    /// ```
+    pub fn wrapping_to_seconds(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Seconds)
+    }
+
+    /// Wrapping conversion of the given time amount to minutes.
+    ///
+    /// # Returns
+    /// The converted time amount in minutes, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.wrapping_to_minutes(1024), 1024 * 60);
+    /// assert_eq!(TimeUnit::Hours.wrapping_to_minutes(u64::MAX), u64::MAX.wrapping_mul(60));
+    /// ```
+    pub fn wrapping_to_minutes(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Minutes)
+    }
+
+    /// Wrapping conversion of the given time amount to hours.
+    ///
+    /// # Returns
+    /// The converted time amount in hours, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.wrapping_to_hours(1024), 1024 * 24);
+    /// assert_eq!(TimeUnit::Days.wrapping_to_hours(u64::MAX), u64::MAX.wrapping_mul(24));
+    /// ```
+    pub fn wrapping_to_hours(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Hours)
+    }
+
+    /// Wrapping conversion of the given time amount to days.
+    ///
+    /// # Returns
+    /// The converted time amount in days, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.wrapping_to_days(1024), 1024 / 24);
+    /// assert_eq!(TimeUnit::Days.wrapping_to_days(u64::MAX), u64::MAX);
+    /// ```
+    pub fn wrapping_to_days(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Days)
+    }
+
+    /// Wrapping conversion of the given time amount to weeks.
+    ///
+    /// # Returns
+    /// The converted time amount in weeks, wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.wrapping_to_weeks(1024), 1024 / 7);
+    /// assert_eq!(TimeUnit::Weeks.wrapping_to_weeks(u64::MAX), u64::MAX);
+    /// ```
+    pub fn wrapping_to_weeks(&self, amount: u64) -> u64 {
+        self.wrapping_to(amount, TimeUnit::Weeks)
+    }
+
+    /// Wrapping conversion of the given time amount to a `std` [`Duration`].
+    ///
+    /// # Returns
+    /// The converted time amount in [`Duration`], with the whole seconds wrapping around at the boundary of `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.wrapping_to_duration(1), Duration::from_secs(60 * 60));
+    /// assert_eq!(
+    ///     TimeUnit::Minutes.wrapping_to_duration(u64::MAX),
+    ///     Duration::from_secs(u64::MAX.wrapping_mul(60))
+    /// );
+    /// ```
+    pub fn wrapping_to_duration(&self, amount: u64) -> Duration {
+        match self {
+            TimeUnit::Minutes => {
+                Duration::from_secs(amount.wrapping_mul(TimeUnit::SECONDS_PER_MINUTE))
+            }
+            TimeUnit::Hours => Duration::from_secs(amount.wrapping_mul(TimeUnit::SECONDS_PER_HOUR)),
+            TimeUnit::Days => Duration::from_secs(amount.wrapping_mul(TimeUnit::SECONDS_PER_DAY)),
+            TimeUnit::Weeks => Duration::from_secs(amount.wrapping_mul(TimeUnit::SECONDS_PER_WEEK)),
+            _ => self.to_duration(amount),
+        }
+    }
+
+    /// Wrapping conversion of the given time amount to a [`chrono::Duration`].
+    ///
+    /// The span is computed in nanoseconds with wrapping `i64` arithmetic,
+    /// so it wraps around after roughly 292 years in either direction.
+    ///
+    /// # Returns
+    /// The converted time amount in [`chrono::Duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.wrapping_to_chrono_duration(1), chrono::Duration::hours(1));
+    /// assert_eq!(
+    ///     TimeUnit::Nanoseconds.wrapping_to_chrono_duration(u64::MAX),
+    ///     chrono::Duration::nanoseconds(-1)
+    /// );
+    /// ```
+    pub fn wrapping_to_chrono_duration(&self, amount: u64) -> chrono::Duration {
+        chrono::Duration::nanoseconds(self.wrapping_to_nanos(amount) as i64)
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Converts the given signed time amount to nanoseconds.
+    ///
+    /// Negative amounts are converted symmetrically to positive ones,
+    /// and the result saturates at `i64::MIN`/`i64::MAX` instead of overflowing.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.to_nanos_i64(-5), -5 * (1000 * 1000 * 1000));
+    /// assert_eq!(TimeUnit::Days.to_nanos_i64(i64::MIN), i64::MIN);
+    /// ```
+    pub fn to_nanos_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Nanoseconds.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to microseconds.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Nanoseconds.to_micros_i64(-1999), -1);
+    /// assert_eq!(TimeUnit::Seconds.to_micros_i64(-5), -5 * (1000 * 1000));
+    /// ```
+    pub fn to_micros_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Microseconds.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to milliseconds.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Microseconds.to_millis_i64(-1999), -1);
+    /// assert_eq!(TimeUnit::Minutes.to_millis_i64(-5), -5 * (1000 * 60));
+    /// ```
+    pub fn to_millis_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Milliseconds.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to seconds.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Milliseconds.to_seconds_i64(-1999), -1);
+    /// assert_eq!(TimeUnit::Minutes.to_seconds_i64(-5), -5 * 60);
+    /// ```
+    pub fn to_seconds_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Seconds.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to minutes.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in minutes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.to_minutes_i64(-119), -1);
+    /// assert_eq!(TimeUnit::Hours.to_minutes_i64(-5), -5 * 60);
+    /// ```
+    pub fn to_minutes_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Minutes.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to hours.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in hours.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.to_hours_i64(-119), -1);
+    /// assert_eq!(TimeUnit::Days.to_hours_i64(-5), -5 * 24);
+    /// ```
+    pub fn to_hours_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Hours.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to days.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.to_days_i64(-47), -1);
+    /// assert_eq!(TimeUnit::Days.to_days_i64(-5), -5);
+    /// ```
+    pub fn to_days_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Days.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to weeks.
+    ///
+    /// Down-conversions truncate toward zero, and the result saturates at `i64::MIN`/`i64::MAX`.
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in weeks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.to_weeks_i64(-13), -1);
+    /// assert_eq!(TimeUnit::Weeks.to_weeks_i64(-5), -5);
+    /// ```
+    pub fn to_weeks_i64(&self, amount: i64) -> i64 {
+        TimeUnit::Weeks.convert_i64(amount, *self)
+    }
+
+    /// Converts the given signed time amount to a [`chrono::Duration`].
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in [`chrono::Duration`], negative for negative amounts.
+    ///
+    /// # Panics
+    /// Panics if the amount is out of the range supported by `chrono`,
+    /// see [`TimeUnit::checked_to_chrono_duration_i64`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.to_chrono_duration_i64(-5), Duration::minutes(-5));
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    /// let five_minutes_ago = now + TimeUnit::Minutes.to_chrono_duration_i64(-5);
+    /// assert_eq!(five_minutes_ago, Utc.with_ymd_and_hms(2024, 3, 1, 11, 55, 0).unwrap());
+    /// ```
+    pub fn to_chrono_duration_i64(&self, amount: i64) -> chrono::Duration {
+        self.checked_to_chrono_duration_i64(amount)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Checked conversion of the given signed time amount to a [`chrono::Duration`].
+    ///
+    /// # Arguments
+    /// `amount` - The original signed time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in [`chrono::Duration`],
+    /// or an [`OverflowError`] if it is out of the range supported by `chrono`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.checked_to_chrono_duration_i64(-1), Ok(chrono::Duration::hours(-1)));
+    /// assert!(TimeUnit::Days.checked_to_chrono_duration_i64(i64::MIN).is_err());
+    /// ```
+    pub fn checked_to_chrono_duration_i64(
+        &self,
+        amount: i64,
+    ) -> Result<chrono::Duration, OverflowError> {
+        match self {
+            TimeUnit::Nanoseconds => Some(chrono::Duration::nanoseconds(amount)),
+            TimeUnit::Microseconds => Some(chrono::Duration::microseconds(amount)),
+            TimeUnit::Milliseconds => chrono::Duration::try_milliseconds(amount),
+            TimeUnit::Seconds => chrono::Duration::try_seconds(amount),
+            TimeUnit::Minutes => chrono::Duration::try_minutes(amount),
+            TimeUnit::Hours => chrono::Duration::try_hours(amount),
+            TimeUnit::Days => chrono::Duration::try_days(amount),
+            TimeUnit::Weeks => chrono::Duration::try_weeks(amount),
+        }
+        .ok_or_else(|| self.overflow(amount as i128, CHRONO_DURATION_TARGET))
+    }
+
+    /// Saturating conversion of the given signed time amount to a [`chrono::Duration`].
+    ///
+    /// # Returns
+    /// The converted time amount in [`chrono::Duration`],
+    /// or [`chrono::Duration::MIN`]/[`chrono::Duration::MAX`] on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.saturating_to_chrono_duration_i64(-1), chrono::Duration::days(-1));
+    /// assert_eq!(TimeUnit::Days.saturating_to_chrono_duration_i64(i64::MIN), chrono::Duration::MIN);
+    /// ```
+    pub fn saturating_to_chrono_duration_i64(&self, amount: i64) -> chrono::Duration {
+        self.checked_to_chrono_duration_i64(amount)
+            .unwrap_or(if amount < 0 {
+                chrono::Duration::MIN
+            } else {
+                chrono::Duration::MAX
+            })
+    }
+
+    /// Signed variant of [`TimeUnit::convert`].
+    ///
+    /// Down-conversions truncate toward zero, so `-119` seconds is `-1` minute,
+    /// and the result saturates at `i64::MIN`/`i64::MAX` instead of overflowing.
+    ///
+    /// # Arguments
+    /// `amount` - The signed time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// The converted time amount in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.convert_i64(-119, TimeUnit::Seconds), -1);
+    /// assert_eq!(TimeUnit::Seconds.convert_i64(-2, TimeUnit::Minutes), -120);
+    /// assert_eq!(TimeUnit::Nanoseconds.convert_i64(i64::MIN, TimeUnit::Days), i64::MIN);
+    /// ```
+    pub fn convert_i64(&self, amount: i64, source: TimeUnit) -> i64 {
+        self.checked_convert_i64(amount, source)
+            .unwrap_or(if amount < 0 { i64::MIN } else { i64::MAX })
+    }
+
+    /// Checked variant of [`TimeUnit::convert_i64`].
+    ///
+    /// # Arguments
+    /// `amount` - The signed time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// The converted time amount in this unit, or an [`OverflowError`] if it does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.checked_convert_i64(-3, TimeUnit::Minutes), Ok(-180));
+    /// assert!(TimeUnit::Nanoseconds.checked_convert_i64(i64::MIN, TimeUnit::Days).is_err());
+    /// ```
+    pub fn checked_convert_i64(&self, amount: i64, source: TimeUnit) -> Result<i64, OverflowError> {
+        let converted = self.checked_convert_i128(amount as i128, source)?;
+        i64::try_from(converted)
+            .map_err(|_| source.overflow(amount as i128, self.lower_case_name()))
+    }
+
+    /// Wide signed variant of [`TimeUnit::convert`].
+    ///
+    /// Down-conversions truncate toward zero,
+    /// and the result saturates at `i128::MIN`/`i128::MAX` instead of overflowing.
+    ///
+    /// # Arguments
+    /// `amount` - The signed time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// The converted time amount in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(
+    ///     TimeUnit::Nanoseconds.convert_i128(i64::MIN as i128, TimeUnit::Days),
+    ///     i64::MIN as i128 * 86_400_000_000_000
+    /// );
+    /// assert_eq!(TimeUnit::Days.convert_i128(-86_399, TimeUnit::Seconds), 0);
+    /// ```
+    pub fn convert_i128(&self, amount: i128, source: TimeUnit) -> i128 {
+        self.checked_convert_i128(amount, source)
+            .unwrap_or(if amount < 0 { i128::MIN } else { i128::MAX })
+    }
+
+    /// Checked variant of [`TimeUnit::convert_i128`].
+    ///
+    /// # Arguments
+    /// `amount` - The signed time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// The converted time amount in this unit, or an [`OverflowError`] if it does not fit in an `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.checked_convert_i128(-1, TimeUnit::Days), Ok(-1440));
+    /// assert!(TimeUnit::Nanoseconds.checked_convert_i128(i128::MIN, TimeUnit::Seconds).is_err());
+    /// ```
+    pub fn checked_convert_i128(
+        &self,
+        amount: i128,
+        source: TimeUnit,
+    ) -> Result<i128, OverflowError> {
+        source.checked_to_i128(amount, *self)
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Converts the given time amount in the given source unit to this unit,
+    /// rounding lossy down-conversions according to the given [`RoundingMode`].
+    ///
+    /// Up-conversions are exact and saturate at `u64::MAX` like [`TimeUnit::convert`].
+    ///
+    /// # Arguments
+    /// `amount` - The time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    /// `mode` - The [`RoundingMode`] applied to the quotient.
+    ///
+    /// # Returns
+    /// The rounded time amount in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::rounding::RoundingMode;
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded(119, TimeUnit::Seconds, RoundingMode::Truncate), 1);
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded(61, TimeUnit::Seconds, RoundingMode::Ceil), 2);
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded(90, TimeUnit::Seconds, RoundingMode::HalfUp), 2);
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded(150, TimeUnit::Seconds, RoundingMode::HalfEven), 2);
+    /// ```
+    pub fn convert_rounded(&self, amount: u64, source: TimeUnit, mode: RoundingMode) -> u64 {
+        let rounded = self.convert_rounded_i128(amount as i128, source, mode);
+        u64::try_from(rounded).unwrap_or(u64::MAX)
+    }
+
+    /// Signed variant of [`TimeUnit::convert_rounded`].
+    ///
+    /// The result saturates at `i64::MIN`/`i64::MAX` instead of overflowing.
+    ///
+    /// # Arguments
+    /// `amount` - The signed time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    /// `mode` - The [`RoundingMode`] applied to the quotient.
+    ///
+    /// # Returns
+    /// The rounded time amount in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::rounding::RoundingMode;
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded_i64(-61, TimeUnit::Seconds, RoundingMode::Floor), -2);
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded_i64(-119, TimeUnit::Seconds, RoundingMode::Ceil), -1);
+    /// assert_eq!(TimeUnit::Minutes.convert_rounded_i64(-90, TimeUnit::Seconds, RoundingMode::HalfUp), -2);
+    /// ```
+    pub fn convert_rounded_i64(&self, amount: i64, source: TimeUnit, mode: RoundingMode) -> i64 {
+        let rounded = self.convert_rounded_i128(amount as i128, source, mode);
+        i64::try_from(rounded).unwrap_or(if amount < 0 { i64::MIN } else { i64::MAX })
+    }
+
+    /// Converts the given time amount in the given source unit to this unit,
+    /// returning the truncated quotient together with the remainder, in the `source` unit.
+    ///
+    /// # Arguments
+    /// `amount` - The time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// A `(quotient, remainder)` pair, where `quotient` is in this unit and `remainder` in `source`.
+    /// Up-conversions are exact, so their remainder is always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.convert_with_remainder(119, TimeUnit::Seconds), (1, 59));
+    /// assert_eq!(TimeUnit::Seconds.convert_with_remainder(2, TimeUnit::Minutes), (120, 0));
+    /// ```
+    pub fn convert_with_remainder(&self, amount: u64, source: TimeUnit) -> (u64, u64) {
+        let (source_scale, target_scale) = (source.scale(), self.scale());
+        if source_scale >= target_scale {
+            (self.convert(amount, source), 0)
+        } else {
+            let divisor = target_scale / source_scale;
+            (amount / divisor, amount % divisor)
+        }
+    }
+
+    /// Signed variant of [`TimeUnit::convert_with_remainder`].
+    ///
+    /// The quotient is truncated toward zero, so the remainder carries the sign of `amount`.
+    ///
+    /// # Arguments
+    /// `amount` - The signed time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// A `(quotient, remainder)` pair, where `quotient` is in this unit and `remainder` in `source`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Minutes.convert_with_remainder_i64(-119, TimeUnit::Seconds), (-1, -59));
+    /// ```
+    pub fn convert_with_remainder_i64(&self, amount: i64, source: TimeUnit) -> (i64, i64) {
+        let (source_scale, target_scale) = (source.scale(), self.scale());
+        if source_scale >= target_scale {
+            (self.convert_i64(amount, source), 0)
+        } else {
+            let divisor = (target_scale / source_scale) as i64;
+            (amount / divisor, amount % divisor)
+        }
+    }
+
+    // ----------------------------------------------------------------
+
+    /// Converts the given time amount to fractional nanoseconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in nanoseconds, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Microseconds.to_nanos_f64(1.5), 1500.0);
+    /// ```
+    pub fn to_nanos_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Nanoseconds.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional microseconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in microseconds, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Nanoseconds.to_micros_f64(1500.0), 1.5);
+    /// ```
+    pub fn to_micros_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Microseconds.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional milliseconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in milliseconds, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Microseconds.to_millis_f64(1500.0), 1.5);
+    /// ```
+    pub fn to_millis_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Milliseconds.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional seconds.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in seconds, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Milliseconds.to_seconds_f64(1500.0), 1.5);
+    /// ```
+    pub fn to_seconds_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Seconds.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional minutes.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in minutes, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.to_minutes_f64(90.0), 1.5);
+    /// ```
+    pub fn to_minutes_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Minutes.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional hours.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in hours, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Milliseconds.to_hours_f64(5_400_000.0), 1.5);
+    /// ```
+    pub fn to_hours_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Hours.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional days.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in days, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.to_days_f64(36.0), 1.5);
+    /// ```
+    pub fn to_days_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Days.convert_f64(amount, *self)
+    }
+
+    /// Converts the given time amount to fractional weeks.
+    ///
+    /// # Arguments
+    /// `amount` - The original time amount, with the unit specified by the caller.
+    ///
+    /// # Returns
+    /// The converted time amount in weeks, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Days.to_weeks_f64(3.5), 0.5);
+    /// ```
+    pub fn to_weeks_f64(&self, amount: f64) -> f64 {
+        TimeUnit::Weeks.convert_f64(amount, *self)
+    }
+
+    /// Fractional variant of [`TimeUnit::convert`].
+    ///
+    /// # Arguments
+    /// `amount` - The time amount, in the `source` unit.
+    /// `source` - The [`TimeUnit`] of `amount`.
+    ///
+    /// # Returns
+    /// The converted time amount in this unit, without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Hours.convert_f64(5_400_000.0, TimeUnit::Milliseconds), 1.5);
+    /// assert_eq!(TimeUnit::Seconds.convert_f64(-0.25, TimeUnit::Minutes), -15.0);
+    /// ```
+    pub fn convert_f64(&self, amount: f64, source: TimeUnit) -> f64 {
+        let ratio = source.ratio(*self);
+        amount * ratio.numerator() as f64 / ratio.denominator() as f64
+    }
+
+    /// The exact ratio between this unit and the `target` unit,
+    /// i.e. how many `target` units make up one unit of this [`TimeUnit`].
+    ///
+    /// # Arguments
+    /// `target` - The [`TimeUnit`] to compare against.
+    ///
+    /// # Returns
+    /// The [`UnitRatio`] in lowest terms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// let ratio = TimeUnit::Hours.ratio(TimeUnit::Seconds);
+    /// assert_eq!((ratio.numerator(), ratio.denominator()), (3600, 1));
+    ///
+    /// let ratio = TimeUnit::Seconds.ratio(TimeUnit::Hours);
+    /// assert_eq!((ratio.numerator(), ratio.denominator()), (1, 3600));
+    /// ```
+    pub fn ratio(&self, target: TimeUnit) -> UnitRatio {
+        UnitRatio::new(self.scale(), target.scale())
+    }
+
+    // ----------------------------------------------------------------
+
+    /// The number of nanoseconds in one unit of this [`TimeUnit`].
+    fn scale(&self) -> u64 {
+        match self {
+            TimeUnit::Nanoseconds => Self::NANOS_SCALE,
+            TimeUnit::Microseconds => Self::MICROSECOND_SCALE,
+            TimeUnit::Milliseconds => Self::MILLISECOND_SCALE,
+            TimeUnit::Seconds => Self::SECOND_SCALE,
+            TimeUnit::Minutes => Self::MINUTE_SCALE,
+            TimeUnit::Hours => Self::HOUR_SCALE,
+            TimeUnit::Days => Self::DAY_SCALE,
+            TimeUnit::Weeks => Self::WEEK_SCALE,
+        }
+    }
+
+    /// The lower case name of this [`TimeUnit`], e.g. `seconds`.
+    fn lower_case_name(&self) -> &'static str {
+        match self {
+            TimeUnit::Nanoseconds => Self::NANOSECONDS_LOWER_CASE_NAME,
+            TimeUnit::Microseconds => Self::MICROSECONDS_LOWER_CASE_NAME,
+            TimeUnit::Milliseconds => Self::MILLISECONDS_LOWER_CASE_NAME,
+            TimeUnit::Seconds => Self::SECONDS_LOWER_CASE_NAME,
+            TimeUnit::Minutes => Self::MINUTES_LOWER_CASE_NAME,
+            TimeUnit::Hours => Self::HOURS_LOWER_CASE_NAME,
+            TimeUnit::Days => Self::DAYS_LOWER_CASE_NAME,
+            TimeUnit::Weeks => Self::WEEKS_LOWER_CASE_NAME,
+        }
+    }
+
+    fn overflow(&self, amount: i128, target: &'static str) -> OverflowError {
+        OverflowError::new(*self, amount, target)
+    }
+
+    /// Converts `amount` of this unit into `target` directly by the ratio of both scales,
+    /// instead of going through nanoseconds.
+    fn checked_to(&self, amount: u64, target: TimeUnit) -> Result<u64, OverflowError> {
+        let (source_scale, target_scale) = (self.scale(), target.scale());
+        if source_scale >= target_scale {
+            amount
+                .checked_mul(source_scale / target_scale)
+                .ok_or_else(|| self.overflow(amount as i128, target.lower_case_name()))
+        } else {
+            Ok(amount / (target_scale / source_scale))
+        }
+    }
+
+    fn to(&self, amount: u64, target: TimeUnit) -> u64 {
+        let (source_scale, target_scale) = (self.scale(), target.scale());
+        if source_scale >= target_scale {
+            amount * (source_scale / target_scale)
+        } else {
+            amount / (target_scale / source_scale)
+        }
+    }
+
+    /// Signed variant of [`TimeUnit::checked_to`], truncating toward zero.
+    fn checked_to_i128(&self, amount: i128, target: TimeUnit) -> Result<i128, OverflowError> {
+        let (source_scale, target_scale) = (self.scale() as i128, target.scale() as i128);
+        if source_scale >= target_scale {
+            amount
+                .checked_mul(source_scale / target_scale)
+                .ok_or_else(|| self.overflow(amount, target.lower_case_name()))
+        } else {
+            Ok(amount / (target_scale / source_scale))
+        }
+    }
+
+    fn convert_rounded_i128(&self, amount: i128, source: TimeUnit, mode: RoundingMode) -> i128 {
+        let (source_scale, target_scale) = (source.scale() as i128, self.scale() as i128);
+        if source_scale >= target_scale {
+            self.convert_i128(amount, source)
+        } else {
+            mode.divide(amount, target_scale / source_scale)
+        }
+    }
+
+    fn wrapping_to(&self, amount: u64, target: TimeUnit) -> u64 {
+        let (source_scale, target_scale) = (self.scale(), target.scale());
+        if source_scale >= target_scale {
+            amount.wrapping_mul(source_scale / target_scale)
+        } else {
+            amount / (target_scale / source_scale)
+        }
+    }
+}
+
+impl TimeUnit {
+    /// Returns the corresponding [`TimeUnit`] enum based on the provided [`TimeUnit`] value/name.
+    ///
+    /// # Arguments
+    /// `value` - A string slice representing the value/name of the [`TimeUnit`].
+    ///
+    /// # Returns
+    /// An `Option<Self>` containing the matched [`TimeUnit`] if a valid value/name is given, otherwise returns `None`.
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    /// assert_eq!(TimeUnit::value_of("Nanoseconds"), Some(TimeUnit::Nanoseconds));
+    /// assert_eq!(TimeUnit::value_of("Microseconds"), Some(TimeUnit::Microseconds));
+    /// assert_eq!(TimeUnit::value_of("Milliseconds"), Some(TimeUnit::Milliseconds));
+    /// assert_eq!(TimeUnit::value_of("Seconds"), Some(TimeUnit::Seconds));
+    /// assert_eq!(TimeUnit::value_of("Minutes"), Some(TimeUnit::Minutes));
+    /// assert_eq!(TimeUnit::value_of("Hours"), Some(TimeUnit::Hours));
+    /// assert_eq!(TimeUnit::value_of("Days"), Some(TimeUnit::Days));
+    /// assert_eq!(TimeUnit::value_of("Invalid"), None);
+    /// ```
+    pub fn value_of(value: &str) -> Option<Self> {
+        match value {
+            TimeUnit::NANOSECONDS_NAME => Some(TimeUnit::Nanoseconds),
+            TimeUnit::MICROSECONDS_NAME => Some(TimeUnit::Microseconds),
+            TimeUnit::MILLISECONDS_NAME => Some(TimeUnit::Milliseconds),
+            TimeUnit::SECONDS_NAME => Some(TimeUnit::Seconds),
+            TimeUnit::MINUTES_NAME => Some(TimeUnit::Minutes),
+            TimeUnit::HOURS_NAME => Some(TimeUnit::Hours),
+            TimeUnit::DAYS_NAME => Some(TimeUnit::Days),
+            TimeUnit::WEEKS_NAME => Some(TimeUnit::Weeks),
+            _ => None,
+        }
+    }
+
+    /// Returns the corresponding [`TimeUnit`] enum based on the provided [`TimeUnit`] value/name.
+    /// performing a case-insensitive match.
+    ///
+    /// # Arguments
+    /// `value` - A string slice representing the value/name of the [`TimeUnit`].
+    ///
+    /// # Returns
+    /// An `Option<Self>` containing the matched [`TimeUnit`] if a valid value/name is given, otherwise returns `None`.
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Nanoseconds"), Some(TimeUnit::Nanoseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Microseconds"), Some(TimeUnit::Microseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Milliseconds"), Some(TimeUnit::Milliseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Seconds"), Some(TimeUnit::Seconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Minutes"), Some(TimeUnit::Minutes));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Hours"), Some(TimeUnit::Hours));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Days"), Some(TimeUnit::Days));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("Invalid"), None);
+    ///
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("NANOSECONDS"), Some(TimeUnit::Nanoseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("MICROSECONDS"), Some(TimeUnit::Microseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("MILLISECONDS"), Some(TimeUnit::Milliseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("SECONDS"), Some(TimeUnit::Seconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("MINUTES"), Some(TimeUnit::Minutes));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("HOURS"), Some(TimeUnit::Hours));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("DAYS"), Some(TimeUnit::Days));
+    ///
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("nanoseconds"), Some(TimeUnit::Nanoseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("microseconds"), Some(TimeUnit::Microseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("milliseconds"), Some(TimeUnit::Milliseconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("seconds"), Some(TimeUnit::Seconds));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("minutes"), Some(TimeUnit::Minutes));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("hours"), Some(TimeUnit::Hours));
+    /// assert_eq!(TimeUnit::insensitive_case_value_of("days"), Some(TimeUnit::Days));
+    /// ```
+    pub fn insensitive_case_value_of(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            TimeUnit::NANOSECONDS_LOWER_CASE_NAME => Some(TimeUnit::Nanoseconds),
+            TimeUnit::MICROSECONDS_LOWER_CASE_NAME => Some(TimeUnit::Microseconds),
+            TimeUnit::MILLISECONDS_LOWER_CASE_NAME => Some(TimeUnit::Milliseconds),
+            TimeUnit::SECONDS_LOWER_CASE_NAME => Some(TimeUnit::Seconds),
+            TimeUnit::MINUTES_LOWER_CASE_NAME => Some(TimeUnit::Minutes),
+            TimeUnit::HOURS_LOWER_CASE_NAME => Some(TimeUnit::Hours),
+            TimeUnit::DAYS_LOWER_CASE_NAME => Some(TimeUnit::Days),
+            TimeUnit::WEEKS_LOWER_CASE_NAME => Some(TimeUnit::Weeks),
+            _ => None,
+        }
+    }
+
+    /// Returns the corresponding [`TimeUnit`] enum based on the provided name, abbreviation or alias,
+    /// performing a case-insensitive match.
+    ///
+    /// Besides the full names, accepted aliases are the singular names and common abbreviations,
+    /// e.g. `ns`, `µs`/`us`, `ms`, `s`/`sec`/`secs`, `m`/`min`/`mins`, `h`/`hr`/`hrs`, `d`/`day`
+    /// and `w`/`wk`/`week`.
+    ///
+    /// # Arguments
+    /// `value` - A string slice representing the name or alias of the [`TimeUnit`].
+    /// `mode` - Whether ambiguous aliases, such as `m`, are accepted.
+    ///
+    /// # Returns
+    /// The matched [`TimeUnit`], or a [`ParseTimeUnitError`] for unknown or rejected aliases.
+    ///
+    /// ```rust
+    /// use chronounit::{TimeUnit, UnitAliasMode};
+    ///
+    /// assert_eq!(TimeUnit::parse_alias("ms", UnitAliasMode::Lenient), Ok(TimeUnit::Milliseconds));
+    /// assert_eq!(TimeUnit::parse_alias("µs", UnitAliasMode::Lenient), Ok(TimeUnit::Microseconds));
+    /// assert_eq!(TimeUnit::parse_alias("Secs", UnitAliasMode::Lenient), Ok(TimeUnit::Seconds));
+    /// assert_eq!(TimeUnit::parse_alias("hr", UnitAliasMode::Strict), Ok(TimeUnit::Hours));
+    ///
+    /// assert_eq!(TimeUnit::parse_alias("m", UnitAliasMode::Lenient), Ok(TimeUnit::Minutes));
+    /// assert!(TimeUnit::parse_alias("m", UnitAliasMode::Strict).unwrap_err().is_ambiguous());
+    /// ```
+    pub fn parse_alias(value: &str, mode: UnitAliasMode) -> Result<Self, ParseTimeUnitError> {
+        let alias = value.trim().to_lowercase();
+        if mode == UnitAliasMode::Strict && AMBIGUOUS_ALIASES.contains(&alias.as_str()) {
+            return Err(ParseTimeUnitError::ambiguous(value));
+        }
+
+        ALIASES
+            .iter()
+            .find(|(candidate, _)| *candidate == alias)
+            .map(|(_, unit)| *unit)
+            .ok_or_else(|| ParseTimeUnitError::new(value))
+    }
+
+    /// Retrieves the symbol of this [`TimeUnit`], e.g. `ms` or `min`.
+    ///
+    /// Symbols follow the SI conventions, and are never ambiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Nanoseconds.symbol(), "ns");
+    /// assert_eq!(TimeUnit::Microseconds.symbol(), "µs");
+    /// assert_eq!(TimeUnit::Minutes.symbol(), "min");
+    /// assert_eq!(TimeUnit::Days.symbol(), "d");
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TimeUnit::Nanoseconds => "ns",
+            TimeUnit::Microseconds => "\u{b5}s",
+            TimeUnit::Milliseconds => "ms",
+            TimeUnit::Seconds => "s",
+            TimeUnit::Minutes => "min",
+            TimeUnit::Hours => "h",
+            TimeUnit::Days => "d",
+            TimeUnit::Weeks => "wk",
+        }
+    }
+
+    /// Retrieves the compact ASCII abbreviation of this [`TimeUnit`], e.g. `ms` or `m`,
+    /// as used in duration strings like `1h30m`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Microseconds.abbreviation(), "us");
+    /// assert_eq!(TimeUnit::Minutes.abbreviation(), "m");
+    /// assert_eq!(TimeUnit::Hours.abbreviation(), "h");
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            TimeUnit::Nanoseconds => "ns",
+            TimeUnit::Microseconds => "us",
+            TimeUnit::Milliseconds => "ms",
+            TimeUnit::Seconds => "s",
+            TimeUnit::Minutes => "m",
+            TimeUnit::Hours => "h",
+            TimeUnit::Days => "d",
+            TimeUnit::Weeks => "w",
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    /// Formats the [`TimeUnit`] by its name, e.g. `Seconds`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.to_string(), "Seconds");
+    /// assert_eq!(format!("{}", TimeUnit::Days), "Days");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TimeUnit::Nanoseconds => TimeUnit::NANOSECONDS_NAME,
+            TimeUnit::Microseconds => TimeUnit::MICROSECONDS_NAME,
+            TimeUnit::Milliseconds => TimeUnit::MILLISECONDS_NAME,
+            TimeUnit::Seconds => TimeUnit::SECONDS_NAME,
+            TimeUnit::Minutes => TimeUnit::MINUTES_NAME,
+            TimeUnit::Hours => TimeUnit::HOURS_NAME,
+            TimeUnit::Days => TimeUnit::DAYS_NAME,
+            TimeUnit::Weeks => TimeUnit::WEEKS_NAME,
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for TimeUnit {
+    type Err = ParseTimeUnitError;
+
+    /// Parses a [`TimeUnit`] from its name or alias, performing a case-insensitive match,
+    /// see [`TimeUnit::parse_alias`] with [`UnitAliasMode::Lenient`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!("Seconds".parse::<TimeUnit>(), Ok(TimeUnit::Seconds));
+    /// assert_eq!("DAYS".parse::<TimeUnit>(), Ok(TimeUnit::Days));
+    /// assert_eq!("ms".parse::<TimeUnit>(), Ok(TimeUnit::Milliseconds));
+    /// assert_eq!(
+    ///     "fortnights".parse::<TimeUnit>().unwrap_err().to_string(),
+    ///     "invalid time unit: `fortnights`"
+    /// );
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        TimeUnit::parse_alias(value, UnitAliasMode::Lenient)
+    }
 }
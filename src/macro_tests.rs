@@ -16,7 +16,7 @@
 
 // ---------------------------------------------------------------- formatter fn
 
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
 
 use crate::formatter::pattern::DateTimePattern;
 
@@ -113,3 +113,111 @@ fn test_formatter_macro_format_naive_date_time() {
         "22:55:00"
     );
 }
+
+#[test]
+fn test_formatter_macro_format_custom() {
+    let now = "2024-03-12 22:55:00";
+    let ndt = NaiveDateTime::parse_from_str(now, "%Y-%m-%d %H:%M:%S").expect("Parse error");
+    let datetime_utc: DateTime<Utc> = Utc.from_utc_datetime(&ndt);
+    let pattern = DateTimePattern::custom("%d.%m.%Y").unwrap();
+
+    assert_eq!(
+        format_date_time_utc!(&datetime_utc, pattern.clone()),
+        "12.03.2024"
+    );
+    assert_eq!(
+        format_naive_date_time_utc!(&ndt, pattern.clone()),
+        "12.03.2024"
+    );
+    assert_eq!(format_naive_date_time!(&ndt, pattern), "12.03.2024");
+}
+
+#[test]
+fn test_formatter_macro_parse() {
+    let ndt = NaiveDateTime::parse_from_str("2024-03-12 22:55:00", "%Y-%m-%d %H:%M:%S")
+        .expect("Parse error");
+    let datetime_utc: DateTime<Utc> = Utc.from_utc_datetime(&ndt);
+
+    assert_eq!(
+        parse_date_time_utc_default!("2024-03-12 22:55:00"),
+        Ok(datetime_utc)
+    );
+    assert_eq!(
+        parse_naive_date_time_utc_default!("2024-03-12 22:55:00"),
+        Ok(ndt)
+    );
+    assert_eq!(
+        parse_naive_date_time_default!("2024-03-12 22:55:00"),
+        Ok(ndt)
+    );
+    assert_eq!(
+        parse_naive_date_default!("2024-03-12 22:55:00"),
+        Ok(ndt.date())
+    );
+    assert_eq!(
+        parse_naive_time_default!("2024-03-12 22:55:00"),
+        Ok(ndt.time())
+    );
+
+    assert_eq!(
+        parse_date_time_utc!("1710284100", DateTimePattern::Timestamp),
+        Ok(datetime_utc)
+    );
+    assert_eq!(
+        parse_naive_date_time_utc!("2024-03-12 22:55", DateTimePattern::YyyyMmDdHhMm),
+        Ok(ndt)
+    );
+    assert_eq!(
+        parse_naive_date_time!(
+            "2024-03-12 22:55:00.000",
+            DateTimePattern::YyyyMmDdHhMmSsSss
+        ),
+        Ok(ndt)
+    );
+    assert_eq!(
+        parse_naive_date!("12-03-2024", DateTimePattern::DdMmYyyy),
+        Ok(ndt.date())
+    );
+    assert_eq!(
+        parse_naive_time!("22:55", DateTimePattern::HhMm),
+        Ok(ndt.time())
+    );
+}
+
+#[test]
+fn test_formatter_macro_format_time_zone() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    let datetime = datetime_utc.with_timezone(&shanghai);
+
+    assert_eq!(format_date_time_default!(&datetime), "2024-03-01 09:00:00");
+    assert_eq!(
+        format_date_time!(&datetime, DateTimePattern::YyyyMmDdHhMmSsOffset),
+        "2024-03-01 09:00:00 +08:00"
+    );
+    assert_eq!(
+        format_date_time_in_default!(&datetime_utc, &shanghai),
+        "2024-03-01 09:00:00"
+    );
+    assert_eq!(
+        format_date_time_in!(&datetime, &Utc, DateTimePattern::YyyyMmDdHhMmSsZone),
+        "2024-03-01 01:00:00 UTC"
+    );
+}
+
+#[test]
+fn test_formatter_macro_write() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    let ndt = datetime_utc.naive_utc();
+
+    let mut buffer = String::new();
+    write_date_time_utc_default!(&mut buffer, &datetime_utc).unwrap();
+    write_date_time_utc!(&mut buffer, &datetime_utc, DateTimePattern::HhMm).unwrap();
+    write_naive_date_time_default!(&mut buffer, &ndt).unwrap();
+    write_naive_date_time!(&mut buffer, &ndt, DateTimePattern::YyyyMmDd).unwrap();
+
+    assert_eq!(
+        buffer,
+        "2024-03-01 01:00:0001:002024-03-01 01:00:002024-03-01"
+    );
+}
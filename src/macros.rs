@@ -16,7 +16,7 @@
 
 // ----------------------------------------------------------------
 
-/// Formats a [`DateTime<Utc>`] date and time according to the builtin formatter default pattern([`DateTimePattern::YyyyMmDdHhMmSs`]).
+/// Formats a [`DateTime<Utc>`] date and time according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
 ///
 /// # Examples
 ///
@@ -40,7 +40,7 @@ macro_rules! format_date_time_utc_default {
     };
 }
 
-/// Formats a [`NaiveDateTime`] according to the builtin formatter default pattern([`DateTimePattern::YyyyMmDdHhMmSs`]).
+/// Formats a [`NaiveDateTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
 ///
 /// # Examples
 ///
@@ -63,7 +63,7 @@ macro_rules! format_naive_date_time_utc_default {
     };
 }
 
-/// Formats a [`NaiveDateTime`] according to the builtin formatter default pattern([`DateTimePattern::YyyyMmDdHhMmSs`]).
+/// Formats a [`NaiveDateTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
 ///
 /// # Examples
 ///
@@ -184,3 +184,362 @@ macro_rules! format_naive_date_time {
         $crate::formatter::format_naive_date_time($datetime, $pattern)
     };
 }
+
+/// Formats a [`DateTime<Tz>`] in its own time zone according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone};
+/// use chronounit::format_date_time_default;
+///
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let datetime = shanghai.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+///
+/// assert_eq!(format_date_time_default!(&datetime), "2024-03-01 09:00:00");
+/// ```
+#[macro_export]
+macro_rules! format_date_time_default {
+    ($datetime:expr) => {
+        $crate::formatter::format_date_time_default($datetime)
+    };
+}
+
+/// Formats a [`DateTime<Tz>`] in its own time zone according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone};
+/// use chronounit::format_date_time;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let datetime = shanghai.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     format_date_time!(&datetime, DateTimePattern::YyyyMmDdHhMmSsOffset),
+///     "2024-03-01 09:00:00 +08:00"
+/// );
+/// ```
+#[macro_export]
+macro_rules! format_date_time {
+    ($datetime:expr, $pattern:expr) => {
+        $crate::formatter::format_date_time($datetime, $pattern)
+    };
+}
+
+/// Converts a [`DateTime<Tz>`] to the time zone `zone`, then formats it according to
+/// the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use chronounit::format_date_time_in_default;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+///
+/// assert_eq!(
+///     format_date_time_in_default!(&datetime_utc, &shanghai),
+///     "2024-03-01 09:00:00"
+/// );
+/// ```
+#[macro_export]
+macro_rules! format_date_time_in_default {
+    ($datetime:expr, $zone:expr) => {
+        $crate::formatter::format_date_time_in_default($datetime, $zone)
+    };
+}
+
+/// Converts a [`DateTime<Tz>`] to the time zone `zone`, then formats it according to
+/// the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// use chronounit::format_date_time_in;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+/// let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+///
+/// assert_eq!(
+///     format_date_time_in!(&datetime_utc, &shanghai, DateTimePattern::YyyyMmDdHhMmSsOffset),
+///     "2024-03-01 09:00:00 +08:00"
+/// );
+/// ```
+#[macro_export]
+macro_rules! format_date_time_in {
+    ($datetime:expr, $zone:expr, $pattern:expr) => {
+        $crate::formatter::format_date_time_in($datetime, $zone, $pattern)
+    };
+}
+
+/// Writes a [`DateTime<Utc>`] into a [`fmt::Write`](std::fmt::Write) according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::write_date_time_utc_default;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+///
+/// let mut buffer = String::new();
+/// write_date_time_utc_default!(&mut buffer, &datetime_utc).unwrap();
+///
+/// assert_eq!(buffer, "2024-03-12 22:55:00");
+/// ```
+#[macro_export]
+macro_rules! write_date_time_utc_default {
+    ($w:expr, $datetime:expr) => {
+        $crate::formatter::write_date_time_utc_default($w, $datetime)
+    };
+}
+
+/// Writes a [`DateTime<Utc>`] into a [`fmt::Write`](std::fmt::Write) according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::formatter::pattern::DateTimePattern;
+/// use chronounit::write_date_time_utc;
+///
+/// let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap();
+///
+/// let mut buffer = String::new();
+/// write_date_time_utc!(&mut buffer, &datetime_utc, DateTimePattern::YyyyMmDd).unwrap();
+///
+/// assert_eq!(buffer, "2024-03-12");
+/// ```
+#[macro_export]
+macro_rules! write_date_time_utc {
+    ($w:expr, $datetime:expr, $pattern:expr) => {
+        $crate::formatter::write_date_time_utc($w, $datetime, $pattern)
+    };
+}
+
+/// Writes a [`NaiveDateTime`] into a [`fmt::Write`](std::fmt::Write) according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+#[macro_export]
+macro_rules! write_naive_date_time_default {
+    ($w:expr, $datetime:expr) => {
+        $crate::formatter::write_naive_date_time_default($w, $datetime)
+    };
+}
+
+/// Writes a [`NaiveDateTime`] into a [`fmt::Write`](std::fmt::Write) according to the specified pattern.
+#[macro_export]
+macro_rules! write_naive_date_time {
+    ($w:expr, $datetime:expr, $pattern:expr) => {
+        $crate::formatter::write_naive_date_time($w, $datetime, $pattern)
+    };
+}
+
+/// Parses a [`DateTime<Utc>`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::parse_date_time_utc_default;
+///
+/// assert_eq!(
+///     parse_date_time_utc_default!("2024-03-12 22:55:00"),
+///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_date_time_utc_default {
+    ($value:expr) => {
+        $crate::formatter::parse_date_time_utc_default($value)
+    };
+}
+
+/// Parses a [`NaiveDateTime`] in UTC according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::parse_naive_date_time_utc_default;
+///
+/// assert_eq!(
+///     parse_naive_date_time_utc_default!("2024-03-12 22:55:00"),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap().and_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_date_time_utc_default {
+    ($value:expr) => {
+        $crate::formatter::parse_naive_date_time_utc_default($value)
+    };
+}
+
+/// Parses a [`NaiveDateTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::parse_naive_date_time_default;
+///
+/// assert_eq!(
+///     parse_naive_date_time_default!("2024-03-12 22:55:00"),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap().and_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_date_time_default {
+    ($value:expr) => {
+        $crate::formatter::parse_naive_date_time_default($value)
+    };
+}
+
+/// Parses a [`NaiveDate`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::parse_naive_date_default;
+///
+/// assert_eq!(
+///     parse_naive_date_default!("2024-03-12 22:55:00"),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_date_default {
+    ($value:expr) => {
+        $crate::formatter::parse_naive_date_default($value)
+    };
+}
+
+/// Parses a [`NaiveTime`] according to the default formatter pattern([`DateTimePattern::YyyyMmDdHhMmSs`] unless set).
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use chronounit::parse_naive_time_default;
+///
+/// assert_eq!(
+///     parse_naive_time_default!("2024-03-12 22:55:00"),
+///     Ok(NaiveTime::from_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_time_default {
+    ($value:expr) => {
+        $crate::formatter::parse_naive_time_default($value)
+    };
+}
+
+/// Parses a [`DateTime<Utc>`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use chronounit::parse_date_time_utc;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     parse_date_time_utc!("1710284100", DateTimePattern::Timestamp),
+///     Ok(Utc.with_ymd_and_hms(2024, 3, 12, 22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_date_time_utc {
+    ($value:expr, $pattern:expr) => {
+        $crate::formatter::parse_date_time_utc($value, $pattern)
+    };
+}
+
+/// Parses a [`NaiveDateTime`] in UTC according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::parse_naive_date_time_utc;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     parse_naive_date_time_utc!("2024-03-12 22:55:00", DateTimePattern::YyyyMmDdHhMmSs),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap().and_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_date_time_utc {
+    ($value:expr, $pattern:expr) => {
+        $crate::formatter::parse_naive_date_time_utc($value, $pattern)
+    };
+}
+
+/// Parses a [`NaiveDateTime`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::parse_naive_date_time;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     parse_naive_date_time!("2024-03-12 22:55:00", DateTimePattern::YyyyMmDdHhMmSs),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap().and_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_date_time {
+    ($value:expr, $pattern:expr) => {
+        $crate::formatter::parse_naive_date_time($value, $pattern)
+    };
+}
+
+/// Parses a [`NaiveDate`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use chronounit::parse_naive_date;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     parse_naive_date!("2024-03-12", DateTimePattern::YyyyMmDd),
+///     Ok(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_date {
+    ($value:expr, $pattern:expr) => {
+        $crate::formatter::parse_naive_date($value, $pattern)
+    };
+}
+
+/// Parses a [`NaiveTime`] according to the specified pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use chronounit::parse_naive_time;
+/// use chronounit::formatter::pattern::DateTimePattern;
+///
+/// assert_eq!(
+///     parse_naive_time!("22:55:00", DateTimePattern::HhMmSs),
+///     Ok(NaiveTime::from_hms_opt(22, 55, 0).unwrap())
+/// );
+/// ```
+#[macro_export]
+macro_rules! parse_naive_time {
+    ($value:expr, $pattern:expr) => {
+        $crate::formatter::parse_naive_time($value, $pattern)
+    };
+}
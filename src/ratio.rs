@@ -0,0 +1,110 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+
+// ----------------------------------------------------------------
+
+/// [`UnitRatio`] the exact ratio between two time units, in lowest terms.
+///
+/// # Examples
+///
+/// ```rust
+/// use chronounit::TimeUnit;
+///
+/// let ratio = TimeUnit::Milliseconds.ratio(TimeUnit::Hours);
+///
+/// assert_eq!(ratio.numerator(), 1);
+/// assert_eq!(ratio.denominator(), 3_600_000);
+/// assert_eq!(ratio.to_string(), "1/3600000");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnitRatio {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl UnitRatio {
+    /// Creates a new [`UnitRatio`], reduced to lowest terms.
+    pub(crate) fn new(numerator: u64, denominator: u64) -> Self {
+        let divisor = gcd(numerator, denominator);
+        UnitRatio {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    /// The numerator of the ratio.
+    pub fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    /// The denominator of the ratio.
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    /// The inverse ratio, i.e. the conversion in the other direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(
+    ///     TimeUnit::Milliseconds.ratio(TimeUnit::Hours).reciprocal(),
+    ///     TimeUnit::Hours.ratio(TimeUnit::Milliseconds)
+    /// );
+    /// ```
+    pub fn reciprocal(&self) -> UnitRatio {
+        UnitRatio {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    /// The ratio as a floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chronounit::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Seconds.ratio(TimeUnit::Minutes).to_f64(), 1.0 / 60.0);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl fmt::Display for UnitRatio {
+    /// override
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+// ----------------------------------------------------------------
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
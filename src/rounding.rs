@@ -0,0 +1,56 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+/// [`RoundingMode`] how a lossy down-conversion between time units is rounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds toward negative infinity, `119s` -> `1m`, `-61s` -> `-2m`.
+    Floor,
+    /// Rounds toward positive infinity, `61s` -> `2m`, `-119s` -> `-1m`.
+    Ceil,
+    /// Rounds to the nearest unit, ties away from zero, `90s` -> `2m`, `-90s` -> `-2m`.
+    HalfUp,
+    /// Rounds to the nearest unit, ties to the even neighbour, `90s` -> `2m`, `150s` -> `2m`.
+    HalfEven,
+    /// Rounds toward zero, the same as the plain `to_*` conversions, `119s` -> `1m`, `-119s` -> `-1m`.
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Divides `dividend` by the positive `divisor`, rounding the quotient according to this mode.
+    pub(crate) fn divide(&self, dividend: i128, divisor: i128) -> i128 {
+        let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+        if remainder == 0 {
+            return quotient;
+        }
+
+        let away = quotient + dividend.signum();
+        let twice = remainder.abs() * 2;
+        match self {
+            RoundingMode::Floor => dividend.div_euclid(divisor),
+            RoundingMode::Ceil => -(-dividend).div_euclid(divisor),
+            RoundingMode::HalfUp if twice >= divisor => away,
+            RoundingMode::HalfEven
+                if twice > divisor || (twice == divisor && quotient % 2 != 0) =>
+            {
+                away
+            }
+            _ => quotient,
+        }
+    }
+}
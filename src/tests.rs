@@ -16,15 +16,46 @@
 
 // ----------------------------------------------------------------
 
+// The expected values spell out the unit arithmetic, even when it truncates to zero.
+#![allow(clippy::erasing_op)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{mpsc, Arc};
+use std::task::{Context, Poll, Wake};
 use std::thread;
 use std::time::Duration;
 
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
 
+use crate::calendar::CalendarUnit;
+use crate::duration::{
+    self,
+    formatter::{DurationFormatter, DurationStyle},
+    iso8601::IsoDuration,
+    parser::DurationParser,
+};
+#[cfg(feature = "tz")]
+use crate::error::LocalTimeErrorKind;
+use crate::error::{
+    LenientParseErrorKind, OverflowError, ParseDateTimeErrorKind, ParseDurationErrorKind,
+    ParseJavaPatternErrorKind, ParseLocaleError,
+};
 use crate::formatter::{
-    self, pattern::DateTimePattern, DateTimeFormatter, DefaultDateTimeFormatter,
+    self,
+    compiled::CompiledPattern,
+    java,
+    locale::Locale,
+    parser::{AmbiguityPolicy, LenientParser},
+    pattern::{DateTimePattern, Precision},
+    zone::{AmbiguousTimePolicy, Zone},
+    DateTimeFormatter, DefaultDateTimeFormatter, DisplayDateTime, SharedDateTimeFormatter,
 };
-use crate::TimeUnit;
+use crate::rounding::RoundingMode;
+use crate::{TimeUnit, UnitAliasMode};
 
 // ---------------------------------------------------------------- date-time pattern
 
@@ -411,7 +442,7 @@ fn test_date_time_formatter_format_timestamp() {
     let ndt = NaiveDateTime::parse_from_str(now, "%Y-%m-%d %H:%M:%S").expect("Parse error");
     let datetime_utc: DateTime<Utc> = Utc.from_utc_datetime(&ndt);
 
-    let timestamp_ndt = ndt.timestamp();
+    let timestamp_ndt = ndt.and_utc().timestamp();
     let timestamp_utc = datetime_utc.timestamp();
 
     let dtf = DefaultDateTimeFormatter::new(DateTimePattern::Timestamp);
@@ -641,7 +672,7 @@ fn test_date_time_formatter_naive_date_time_format_am_pm_pm() {
 fn test_date_time_formatter_naive_date_time_format_timestamp() {
     let now = "2024-03-01 12:03:04";
     let ndt = NaiveDateTime::parse_from_str(now, "%Y-%m-%d %H:%M:%S").expect("Parse error");
-    let timestamp_ndt = ndt.timestamp();
+    let timestamp_ndt = ndt.and_utc().timestamp();
 
     let dtf = DefaultDateTimeFormatter::new(DateTimePattern::Timestamp);
 
@@ -927,296 +958,2752 @@ fn test_date_time_pattern_name_of() {
     assert_eq!(DateTimePattern::name_of("Invalid"), None);
 }
 
-// ---------------------------------------------------------------- time-unit
-
 #[test]
-fn test_time_unit_to_nanos() {
-    assert_eq!(TimeUnit::Nanoseconds.to_nanos(1024), 1024);
-    assert_eq!(TimeUnit::Microseconds.to_nanos(1024), 1024 * 1000);
-    assert_eq!(TimeUnit::Milliseconds.to_nanos(1024), 1024 * (1000 * 1000));
+fn test_date_time_pattern_display_from_str() {
     assert_eq!(
-        TimeUnit::Seconds.to_nanos(1024),
-        1024 * (1000 * 1000 * 1000)
+        DateTimePattern::YyyyMmDdHhMmSs.to_string(),
+        "YyyyMmDdHhMmSs"
     );
+    assert_eq!(DateTimePattern::Timestamp.to_string(), "Timestamp");
     assert_eq!(
-        TimeUnit::Minutes.to_nanos(1024),
-        1024 * (1000 * 1000 * 1000 * 60)
+        DateTimePattern::WeekdayAbbr.to_string(),
+        DateTimePattern::WeekdayAbbr.value()
     );
+
     assert_eq!(
-        TimeUnit::Hours.to_nanos(1024),
-        1024 * (1000 * 1000 * 1000 * 60 * 60)
+        "YyyyMmDdHhMmSsSss".parse::<DateTimePattern>(),
+        Ok(DateTimePattern::YyyyMmDdHhMmSsSss)
     );
     assert_eq!(
-        TimeUnit::Days.to_nanos(1024),
-        1024 * (1000 * 1000 * 1000 * 60 * 60 * 24)
+        "%H:%M".parse::<DateTimePattern>(),
+        Ok(DateTimePattern::HhMm)
     );
+
+    let err = "%Q".parse::<DateTimePattern>().unwrap_err();
+    assert_eq!(err.value(), "%Q");
+    assert_eq!(err.to_string(), "invalid date-time pattern: `%Q`");
+
+    let mut formats = HashSet::new();
+    formats.insert(DateTimePattern::YyyyMmDd);
+    formats.insert(DateTimePattern::YyyyMmDd);
+    assert_eq!(formats.len(), 1);
 }
 
 #[test]
-fn test_time_unit_to_micros() {
-    assert_eq!(TimeUnit::Nanoseconds.to_micros(1024), 1024 / 1000);
-    assert_eq!(TimeUnit::Microseconds.to_micros(1024), 1024);
-    assert_eq!(TimeUnit::Milliseconds.to_micros(1024), 1024 * 1000);
-    assert_eq!(TimeUnit::Seconds.to_micros(1024), 1024 * (1000 * 1000));
-    assert_eq!(TimeUnit::Minutes.to_micros(1024), 1024 * (1000 * 1000 * 60));
+fn test_date_time_pattern_custom() {
+    let ndt = NaiveDateTime::parse_from_str("2024-03-12 22:55:00", "%Y-%m-%d %H:%M:%S")
+        .expect("Parse error");
+    let datetime_utc: DateTime<Utc> = Utc.from_utc_datetime(&ndt);
+
+    let compact = DateTimePattern::custom("%Y%m%d").unwrap();
+    let german = DateTimePattern::custom("%d.%m.%Y").unwrap();
+    let zoned = DateTimePattern::custom("%Y-%m-%dT%H:%M:%S%:z").unwrap();
+
+    let dtf = DefaultDateTimeFormatter::new(german.clone());
+    assert_eq!(dtf.activated_pattern(), german);
     assert_eq!(
-        TimeUnit::Hours.to_micros(1024),
-        1024 * (1000 * 1000 * 60 * 60)
+        dtf.format_date_time_utc_default(&datetime_utc),
+        "12.03.2024"
     );
+    assert_eq!(dtf.format_naive_date_time_default(&ndt), "12.03.2024");
+    assert_eq!(dtf.format_naive_date_time_utc_default(&ndt), "12.03.2024");
     assert_eq!(
-        TimeUnit::Days.to_micros(1024),
-        1024 * (1000 * 1000 * 60 * 60 * 24)
+        dtf.of_pattern(compact.clone())
+            .format_naive_date_time_default(&ndt),
+        "20240312"
+    );
+
+    assert_eq!(
+        formatter::format_date_time_utc(&datetime_utc, compact.clone()),
+        "20240312"
+    );
+    assert_eq!(
+        formatter::format_naive_date_time(&ndt, compact.clone()),
+        "20240312"
+    );
+    assert_eq!(
+        formatter::format_naive_date_time_utc(&ndt, german.clone()),
+        "12.03.2024"
     );
+    assert_eq!(
+        formatter::format_naive_date_time(&ndt, zoned),
+        "2024-03-12T22:55:00+00:00"
+    );
+
+    // Compared by pattern string, and each value owns its items.
+    let again = DateTimePattern::custom("%d.%m.%Y").unwrap();
+    assert_eq!(again, german);
+    match (&again, &german) {
+        (DateTimePattern::Custom(a), DateTimePattern::Custom(b)) => {
+            assert_eq!(a, b);
+            assert_eq!(a.pattern(), "%d.%m.%Y");
+            assert_eq!(a.items().len(), 5);
+        }
+        _ => panic!("expected custom patterns"),
+    }
+    assert_ne!(compact, german);
+    assert!(compact < german || german < compact);
+
+    assert_eq!(german.to_string(), "%d.%m.%Y");
+    assert_eq!(german.pattern_of(), "%d.%m.%Y");
+    assert_eq!("%d.%m.%Y".parse::<DateTimePattern>(), Ok(german.clone()));
+    assert_eq!(
+        format!("{:?}", german),
+        "Custom(CustomPattern(\"%d.%m.%Y\"))"
+    );
+
+    for invalid in ["%Q", "%Y-%", "%.9Q"] {
+        assert_eq!(
+            DateTimePattern::custom(invalid).unwrap_err().value(),
+            invalid
+        );
+    }
 }
 
 #[test]
-fn test_time_unit_to_millis() {
-    assert_eq!(TimeUnit::Nanoseconds.to_millis(1024), 1024 / 1000 / 1000);
-    assert_eq!(TimeUnit::Microseconds.to_millis(1024), 1024 / 1000);
-    assert_eq!(TimeUnit::Milliseconds.to_millis(1024), 1024);
-    assert_eq!(TimeUnit::Seconds.to_millis(1024), 1024 * 1000);
-    assert_eq!(TimeUnit::Minutes.to_millis(1024), 1024 * (1000 * 60));
-    assert_eq!(TimeUnit::Hours.to_millis(1024), 1024 * (1000 * 60 * 60));
-    assert_eq!(TimeUnit::Days.to_millis(1024), 1024 * (1000 * 60 * 60 * 24));
+fn test_date_time_pattern_java() {
+    let ndt = NaiveDateTime::parse_from_str("2024-03-05 07:04:09.250", "%Y-%m-%d %H:%M:%S%.3f")
+        .expect("Parse error");
+    let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+    let datetime = offset.from_utc_datetime(&ndt);
+
+    let cases = [
+        ("yyyy-MM-dd HH:mm:ss.SSS", "2024-03-05 07:04:09.250"),
+        ("yyyy-MM-dd HH:mm:ss.SSSSSS", "2024-03-05 07:04:09.250000"),
+        ("uuuu/M/d H:m:s", "2024/3/5 7:4:9"),
+        ("yy yyy y", "24 2024 2024"),
+        ("YYYY-'W'ww", "2024-W10"),
+        ("D DDD QQQ Q", "65 065 Q1 1"),
+        ("EEE, dd MMM yyyy", "Tue, 05 Mar 2024"),
+        ("EEEE d MMMM", "Tuesday 5 March"),
+        ("hh:mm a", "07:04 AM"),
+        ("h 'o''clock'", "7 o'clock"),
+        ("'It''s' HH'h'", "It's 07h"),
+        ("''yy", "'24"),
+        ("HH:mm:ss.S", "07:04:09.250"),
+        ("HH:mm:ss.SS", "07:04:09.250"),
+        ("HH:mm:ss.SSSS", "07:04:09.250000"),
+        ("HH:mm:ss.SSSSSSS", "07:04:09.250000000"),
+        ("n", "250000000"),
+        // An ICU skeleton is read as a plain pattern.
+        ("yMMMd", "2024Mar5"),
+    ];
+    for (pattern, expected) in cases {
+        let items = java::compile(pattern).unwrap();
+        assert_eq!(
+            ndt.format_with_items(items.iter()).to_string(),
+            expected,
+            "{}",
+            pattern
+        );
+    }
+
+    let offsets = [
+        ("XXX", "+08:00", "Z"),
+        ("XX", "+0800", "Z"),
+        ("xxx", "+08:00", "+00:00"),
+        ("xx", "+0800", "+0000"),
+        ("Z", "+0800", "+0000"),
+        ("ZZZZZ", "+08:00", "Z"),
+    ];
+    for (pattern, zoned, utc) in offsets {
+        let items = java::compile(pattern).unwrap();
+        assert_eq!(datetime.format_with_items(items.iter()).to_string(), zoned);
+        assert_eq!(
+            ndt.and_utc().format_with_items(items.iter()).to_string(),
+            utc
+        );
+    }
+
+    let pattern = DateTimePattern::java("yyyy-MM-dd'T'HH:mm:ss.SSSXXX").unwrap();
+    assert_eq!(
+        formatter::format_naive_date_time(&ndt, pattern.clone()),
+        "2024-03-05T07:04:09.250Z"
+    );
+    assert_eq!(
+        formatter::format_date_time_utc(&ndt.and_utc(), pattern.clone()),
+        "2024-03-05T07:04:09.250Z"
+    );
+    assert_eq!(pattern.to_string(), "yyyy-MM-dd'T'HH:mm:ss.SSSXXX");
+    assert_eq!(
+        pattern,
+        DateTimePattern::java("yyyy-MM-dd'T'HH:mm:ss.SSSXXX").unwrap()
+    );
+
+    // The same string is a different pattern in each syntax.
+    let java = DateTimePattern::java("yyyy").unwrap();
+    let strftime = DateTimePattern::custom("yyyy").unwrap();
+    assert_ne!(java, strftime);
+    assert_eq!(formatter::format_naive_date_time(&ndt, java), "2024");
+    assert_eq!(formatter::format_naive_date_time(&ndt, strftime), "yyyy");
 }
 
 #[test]
-fn test_time_unit_to_seconds() {
-    assert_eq!(
-        TimeUnit::Nanoseconds.to_seconds(1024),
-        1024 / 1000 / 1000 / 1000
+fn test_date_time_pattern_java_error() {
+    let cases = [
+        (
+            "yyyy.MM.dd G",
+            ParseJavaPatternErrorKind::UnsupportedLetter('G', 1),
+            11,
+        ),
+        (
+            "MMMMM",
+            ParseJavaPatternErrorKind::UnsupportedLetter('M', 5),
+            0,
+        ),
+        (
+            "HH:mm:ss.SSSSSSSSSS",
+            ParseJavaPatternErrorKind::UnsupportedLetter('S', 10),
+            9,
+        ),
+        (
+            "yyyyy",
+            ParseJavaPatternErrorKind::UnsupportedLetter('y', 5),
+            0,
+        ),
+        (
+            "kk:mm",
+            ParseJavaPatternErrorKind::UnsupportedLetter('k', 2),
+            0,
+        ),
+        (
+            "VV",
+            ParseJavaPatternErrorKind::UnsupportedLetter('V', 2),
+            0,
+        ),
+        ("X", ParseJavaPatternErrorKind::UnsupportedLetter('X', 1), 0),
+        ("yyyy 'T", ParseJavaPatternErrorKind::UnterminatedQuote, 5),
+        (
+            "yyyy#",
+            ParseJavaPatternErrorKind::ReservedCharacter('#'),
+            4,
+        ),
+        (
+            "yyyy-MM-dd['T'HH:mm]",
+            ParseJavaPatternErrorKind::OptionalSection,
+            10,
+        ),
+        ("yyyy]", ParseJavaPatternErrorKind::OptionalSection, 4),
+    ];
+    for (pattern, kind, position) in cases {
+        let err = DateTimePattern::java(pattern).unwrap_err();
+        assert_eq!(err.kind(), &kind, "{}", pattern);
+        assert_eq!(err.position(), position, "{}", pattern);
+        assert_eq!(err.pattern(), pattern);
+    }
+
+    assert_eq!(
+        java::compile("HH:mm:ss.SSSSSSSSSS")
+            .unwrap_err()
+            .to_string(),
+        "unsupported pattern letter `SSSSSSSSSS` at position 9 in `HH:mm:ss.SSSSSSSSSS`"
+    );
+    assert_eq!(
+        java::compile("yyyy[ HH]").unwrap_err().to_string(),
+        "unsupported optional section at position 4 in `yyyy[ HH]`"
     );
-    assert_eq!(TimeUnit::Microseconds.to_seconds(1024), 1024 / 1000 / 1000);
-    assert_eq!(TimeUnit::Milliseconds.to_seconds(1024), 1024 / 1000);
-    assert_eq!(TimeUnit::Seconds.to_seconds(1024), 1024);
-    assert_eq!(TimeUnit::Minutes.to_seconds(1024), 1024 * 60);
-    assert_eq!(TimeUnit::Hours.to_seconds(1024), 1024 * (60 * 60));
-    assert_eq!(TimeUnit::Days.to_seconds(1024), 1024 * (60 * 60 * 24));
 }
 
+// ---------------------------------------------------------------- date-time parse
+
 #[test]
-fn test_time_unit_to_minutes() {
-    assert_eq!(
-        TimeUnit::Nanoseconds.to_minutes(1024),
-        1024 / 1000 / 1000 / 1000 / 60
-    );
-    assert_eq!(
-        TimeUnit::Microseconds.to_minutes(1024),
-        1024 / 1000 / 1000 / 60
+fn test_date_time_formatter_parse_round_trip() {
+    let ndt = NaiveDate::from_ymd_opt(2024, 3, 12)
+        .unwrap()
+        .and_hms_milli_opt(22, 55, 7, 125)
+        .unwrap();
+    let datetime_utc: DateTime<Utc> = Utc.from_utc_datetime(&ndt);
+    let seconds = ndt.with_nanosecond(0).unwrap();
+
+    let cases = [
+        (
+            DateTimePattern::YyyyMmDdHhMm,
+            seconds.with_second(0).unwrap(),
+        ),
+        (DateTimePattern::YyyyMmDdHhMmSs, seconds),
+        (DateTimePattern::YyyyMmDdHhMmSsSss, ndt),
+        (DateTimePattern::Timestamp, seconds),
+        (
+            DateTimePattern::java("yyyy-MM-dd'T'HH:mm:ss.SSSXXX").unwrap(),
+            ndt,
+        ),
+        (
+            DateTimePattern::custom("%d.%m.%Y %H:%M:%S%.f").unwrap(),
+            ndt,
+        ),
+    ];
+    for (pattern, expected) in cases {
+        let dtf = DefaultDateTimeFormatter::new(pattern);
+        let value = dtf.format_naive_date_time_default(&ndt);
+        assert_eq!(dtf.parse_naive_date_time_default(&value), Ok(expected));
+        assert_eq!(dtf.parse_naive_date_time_utc_default(&value), Ok(expected));
+
+        let value = dtf.format_date_time_utc_default(&datetime_utc);
+        assert_eq!(
+            dtf.parse_date_time_utc_default(&value),
+            Ok(Utc.from_utc_datetime(&expected))
+        );
+    }
+
+    // Date-only patterns resolve to midnight.
+    for pattern in [
+        DateTimePattern::YyyyMmDd,
+        DateTimePattern::MmDdYyyy,
+        DateTimePattern::DdMmYyyy,
+    ] {
+        let value = formatter::format_naive_date_time(&ndt, pattern.clone());
+        assert_eq!(
+            formatter::parse_naive_date(&value, pattern.clone()),
+            Ok(ndt.date())
+        );
+        assert_eq!(
+            formatter::parse_naive_date_time(&value, pattern),
+            Ok(ndt.date().and_time(NaiveTime::MIN))
+        );
+    }
+
+    // Time-only patterns.
+    assert_eq!(
+        formatter::parse_naive_time("22:55:07", DateTimePattern::HhMmSs),
+        Ok(seconds.time())
+    );
+    assert_eq!(
+        formatter::parse_naive_time("22:55", DateTimePattern::HhMm),
+        Ok(NaiveTime::from_hms_opt(22, 55, 0).unwrap())
+    );
+
+    // Fields of the other kind are validated and dropped.
+    assert_eq!(
+        formatter::parse_naive_date("2024-03-12 22:55:07", DateTimePattern::YyyyMmDdHhMmSs),
+        Ok(ndt.date())
+    );
+    assert_eq!(
+        formatter::parse_naive_time("2024-03-12 22:55:07", DateTimePattern::YyyyMmDdHhMmSs),
+        Ok(seconds.time())
+    );
+    assert_eq!(
+        formatter::parse_naive_date("1710284107", DateTimePattern::Timestamp),
+        Ok(ndt.date())
+    );
+    assert_eq!(
+        formatter::parse_naive_time("1710284107", DateTimePattern::Timestamp),
+        Ok(seconds.time())
     );
-    assert_eq!(TimeUnit::Milliseconds.to_minutes(1024), 1024 / 1000 / 60);
-    assert_eq!(TimeUnit::Seconds.to_minutes(1024), 1024 / 60);
-    assert_eq!(TimeUnit::Minutes.to_minutes(1024), 1024);
-    assert_eq!(TimeUnit::Hours.to_minutes(1024), 1024 * 60);
-    assert_eq!(TimeUnit::Days.to_minutes(1024), 1024 * (60 * 24));
 }
 
 #[test]
-fn test_time_unit_to_hours() {
+fn test_date_time_formatter_parse_offset() {
+    let pattern = DateTimePattern::custom("%Y-%m-%d %H:%M:%S %:z").unwrap();
+    let value = "2024-03-01 09:00:00 +08:00";
+
     assert_eq!(
-        TimeUnit::Nanoseconds.to_hours(1024),
-        1024 / 1000 / 1000 / 1000 / 60 / 60
+        formatter::parse_date_time_utc(value, pattern.clone()),
+        Ok(Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap())
     );
     assert_eq!(
-        TimeUnit::Microseconds.to_hours(1024),
-        1024 / 1000 / 1000 / 60 / 60
+        formatter::parse_naive_date_time_utc(value, pattern.clone()),
+        Ok(NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(1, 0, 0)
+            .unwrap())
     );
-    assert_eq!(TimeUnit::Milliseconds.to_hours(1024), 1024 / 1000 / 60 / 60);
-    assert_eq!(TimeUnit::Seconds.to_hours(1024), 1024 / 60 / 60);
-    assert_eq!(TimeUnit::Minutes.to_hours(1024), 1024 / 60);
-    assert_eq!(TimeUnit::Hours.to_hours(1024), 1024);
-    assert_eq!(TimeUnit::Days.to_hours(1024), 1024 * 24);
-}
-
-#[test]
-fn test_time_unit_to_days() {
+    // The naive value is read as written.
     assert_eq!(
-        TimeUnit::Nanoseconds.to_days(1024),
-        1024 / 1000 / 1000 / 1000 / 60 / 60 / 24
+        formatter::parse_naive_date_time(value, pattern),
+        Ok(NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap())
     );
+
     assert_eq!(
-        TimeUnit::Microseconds.to_days(1024),
-        1024 / 1000 / 1000 / 60 / 60 / 24
+        formatter::parse_date_time_utc("-86400", DateTimePattern::Timestamp),
+        Ok(Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap())
     );
     assert_eq!(
-        TimeUnit::Milliseconds.to_days(1024),
-        1024 / 1000 / 60 / 60 / 24
+        formatter::parse_naive_date_time(&i64::MAX.to_string(), DateTimePattern::Timestamp)
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::OutOfRange
     );
-    assert_eq!(TimeUnit::Seconds.to_days(1024), 1024 / 60 / 60 / 24);
-    assert_eq!(TimeUnit::Minutes.to_days(1024), 1024 / 60 / 24);
-    assert_eq!(TimeUnit::Hours.to_days(1024), 1024 / 24);
-    assert_eq!(TimeUnit::Days.to_days(1024), 1024);
 }
 
 #[test]
-fn test_to_duration() {
+fn test_date_time_formatter_parse_error() {
+    let dtf: Box<dyn DateTimeFormatter> =
+        DefaultDateTimeFormatter::builtin().of_pattern(DateTimePattern::YyyyMmDd);
+
+    let err = dtf.parse_naive_date_default("2024/03/12").unwrap_err();
+    assert_eq!(err.kind(), ParseDateTimeErrorKind::Mismatch);
+    assert_eq!(err.value(), "2024/03/12");
+    assert_eq!(err.pattern(), DateTimePattern::YyyyMmDd);
+    assert!(std::error::Error::source(&err).is_some());
+    assert_eq!(
+        err.to_string(),
+        "cannot parse `2024/03/12` with pattern `YyyyMmDd`: input does not match the pattern"
+    );
+
+    let cases = [
+        (
+            "2024-03-12 22:55",
+            DateTimePattern::YyyyMmDdHhMmSs,
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            "2024-03-12 22:55:00 ",
+            DateTimePattern::YyyyMmDdHhMmSs,
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            "2024-02-30",
+            DateTimePattern::YyyyMmDd,
+            ParseDateTimeErrorKind::OutOfRange,
+        ),
+        (
+            "2024-03-12 24:00:00",
+            DateTimePattern::YyyyMmDdHhMmSs,
+            ParseDateTimeErrorKind::OutOfRange,
+        ),
+        (
+            "22:55:00",
+            DateTimePattern::HhMmSs,
+            ParseDateTimeErrorKind::MissingDate,
+        ),
+        (
+            "March",
+            DateTimePattern::MonthFull,
+            ParseDateTimeErrorKind::MissingDate,
+        ),
+        (
+            "timestamp",
+            DateTimePattern::Timestamp,
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+    ];
+    for (value, pattern, kind) in cases {
+        let err = formatter::parse_naive_date_time(value, pattern).unwrap_err();
+        assert_eq!(err.kind(), kind, "{}", value);
+    }
+
+    assert_eq!(
+        formatter::parse_naive_time("PM", DateTimePattern::AmPm)
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::MissingTime
+    );
+    assert_eq!(
+        formatter::parse_naive_time("2024-03-12", DateTimePattern::YyyyMmDd)
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::MissingTime
+    );
+    // An incomplete time is not completed with midnight.
+    let pattern = DateTimePattern::custom("%Y-%m-%d %p").unwrap();
+    assert_eq!(
+        formatter::parse_naive_date_time("2024-03-12 PM", pattern)
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::MissingTime
+    );
+}
+
+#[test]
+fn test_lenient_parser() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let parser = LenientParser::auto();
+
+    let cases = [
+        (
+            "2024-03-01",
+            DateTimePattern::YyyyMmDd,
+            date(2024, 3, 1).and_hms_opt(0, 0, 0).unwrap(),
+        ),
+        (
+            "2024-03-01 12:00:00",
+            DateTimePattern::YyyyMmDdHhMmSs,
+            date(2024, 3, 1).and_hms_opt(12, 0, 0).unwrap(),
+        ),
+        (
+            "2024-03-01 12:00:00.250",
+            DateTimePattern::YyyyMmDdHhMmSsSss,
+            date(2024, 3, 1).and_hms_milli_opt(12, 0, 0, 250).unwrap(),
+        ),
+        (
+            "2024-03-01T12:00:00",
+            DateTimePattern::custom("%Y-%m-%dT%H:%M:%S%.f").unwrap(),
+            date(2024, 3, 1).and_hms_opt(12, 0, 0).unwrap(),
+        ),
+        (
+            "03/01/2024",
+            DateTimePattern::MmDdYyyy,
+            date(2024, 3, 1).and_hms_opt(0, 0, 0).unwrap(),
+        ),
+        (
+            "03/01/2024 10:00",
+            DateTimePattern::custom("%m/%d/%Y %H:%M").unwrap(),
+            date(2024, 3, 1).and_hms_opt(10, 0, 0).unwrap(),
+        ),
+        (
+            "01-03-2024 10:00",
+            DateTimePattern::custom("%d-%m-%Y %H:%M").unwrap(),
+            date(2024, 3, 1).and_hms_opt(10, 0, 0).unwrap(),
+        ),
+        (
+            "20240301",
+            DateTimePattern::custom("%Y%m%d").unwrap(),
+            date(2024, 3, 1).and_hms_opt(0, 0, 0).unwrap(),
+        ),
+        (
+            "20240301T120000.250",
+            DateTimePattern::custom("%Y%m%dT%H%M%S%.f").unwrap(),
+            date(2024, 3, 1).and_hms_milli_opt(12, 0, 0, 250).unwrap(),
+        ),
+        (
+            "1709294400",
+            DateTimePattern::Timestamp,
+            date(2024, 3, 1).and_hms_opt(12, 0, 0).unwrap(),
+        ),
+        // Eight digits which are not a date are still a timestamp.
+        (
+            "99999999",
+            DateTimePattern::Timestamp,
+            date(1973, 3, 3).and_hms_opt(9, 46, 39).unwrap(),
+        ),
+    ];
+    for (value, pattern, expected) in cases {
+        let matched = parser.parse(value).unwrap();
+        assert_eq!(matched.pattern(), pattern, "{}", value);
+        assert_eq!(matched.value(), expected, "{}", value);
+        assert_eq!(DateTimePattern::detect(value), Some(pattern));
+    }
+
+    // The order of the patterns decides.
+    let parser = LenientParser::new([DateTimePattern::YyyyMmDdHhMm, DateTimePattern::YyyyMmDd]);
+    assert_eq!(
+        parser.patterns(),
+        [DateTimePattern::YyyyMmDdHhMm, DateTimePattern::YyyyMmDd]
+    );
+    assert_eq!(
+        parser.parse("2024-03-01").unwrap().pattern(),
+        DateTimePattern::YyyyMmDd
+    );
+
+    let err = parser.parse("03/01/2024").unwrap_err();
+    assert_eq!(err.kind(), LenientParseErrorKind::NoMatch);
+    assert_eq!(err.value(), "03/01/2024");
+    assert_eq!(err.errors().len(), 2);
+    assert_eq!(err.errors()[1].pattern(), DateTimePattern::YyyyMmDd);
+    assert_eq!(err.to_string(), "no date-time pattern matches `03/01/2024`");
+
+    assert_eq!(
+        LenientParser::new([])
+            .parse("2024-03-01")
+            .unwrap_err()
+            .kind(),
+        LenientParseErrorKind::NoMatch
+    );
+    assert_eq!(DateTimePattern::detect("2024-13-01"), None);
+}
+
+#[test]
+fn test_lenient_parser_ambiguity() {
+    let date = |y, m, d| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    let us = DateTimePattern::custom("%m/%d/%Y").unwrap();
+    let eu = DateTimePattern::custom("%d/%m/%Y").unwrap();
+    let parser = LenientParser::new([us.clone(), eu.clone()]);
+    assert_eq!(parser.ambiguity_policy(), AmbiguityPolicy::PreferFirst);
+
+    let matched = parser.parse("03/01/2024").unwrap();
+    assert_eq!(matched.pattern(), us);
+    assert_eq!(matched.value(), date(2024, 3, 1));
+    assert_eq!(matched.alternative(), Some(date(2024, 1, 3)));
+    assert!(matched.is_ambiguous());
+
+    // The day is above 12, or the day equals the month: one reading only.
+    let matched = parser.parse("03/13/2024").unwrap();
+    assert_eq!(matched.pattern(), us);
+    assert!(!matched.is_ambiguous());
+    let matched = parser.parse("13/03/2024").unwrap();
+    assert_eq!(matched.pattern(), eu);
+    assert!(!matched.is_ambiguous());
+    assert!(!parser.parse("03/03/2024").unwrap().is_ambiguous());
+
+    // Year-first layouts are ISO 8601.
+    assert!(!LenientParser::auto()
+        .parse("2024-03-01")
+        .unwrap()
+        .is_ambiguous());
+    assert!(LenientParser::auto()
+        .parse("01-03-2024 10:00")
+        .unwrap()
+        .is_ambiguous());
+
+    let parser = parser.with_ambiguity_policy(AmbiguityPolicy::Reject);
+    let err = parser.parse("03/01/2024").unwrap_err();
+    match err.kind() {
+        LenientParseErrorKind::Ambiguous(matched) => {
+            assert_eq!(matched.pattern(), us);
+            assert_eq!(matched.alternative(), Some(date(2024, 1, 3)));
+        }
+        kind => panic!("unexpected {:?}", kind),
+    }
+    assert_eq!(
+        err.to_string(),
+        "ambiguous date-time `03/01/2024`: `2024-03-01 00:00:00` with pattern `%m/%d/%Y`, or `2024-01-03 00:00:00`"
+    );
+    assert_eq!(
+        parser.parse("13/03/2024").unwrap().value(),
+        date(2024, 3, 13)
+    );
+}
+
+#[test]
+fn test_date_time_formatter_format_time_zone() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    let datetime = datetime_utc.with_timezone(&shanghai);
+
+    let dtf = DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSsOffset);
     assert_eq!(
-        TimeUnit::Nanoseconds.to_duration(100),
-        Duration::from_nanos(100)
+        dtf.format_date_time_default(&datetime),
+        "2024-03-01 09:00:00 +08:00"
     );
     assert_eq!(
-        TimeUnit::Microseconds.to_duration(100),
-        Duration::from_micros(100)
+        dtf.format_date_time_default(&datetime_utc),
+        "2024-03-01 01:00:00 +00:00"
     );
     assert_eq!(
-        TimeUnit::Milliseconds.to_duration(100),
-        Duration::from_millis(100)
+        dtf.format_date_time_in_default(&datetime, &new_york),
+        "2024-02-29 20:00:00 -05:00"
     );
-    assert_eq!(TimeUnit::Seconds.to_duration(1), Duration::from_secs(1));
-    assert_eq!(TimeUnit::Minutes.to_duration(1), Duration::from_secs(60));
-    assert_eq!(TimeUnit::Hours.to_duration(1), Duration::from_secs(60 * 60));
     assert_eq!(
-        TimeUnit::Days.to_duration(1),
-        Duration::from_secs(60 * 60 * 24)
+        dtf.format_date_time(&datetime, DateTimePattern::YyyyMmDdHhMmSs),
+        "2024-03-01 09:00:00"
     );
-}
-
-#[test]
-fn test_to_chrono_duration() {
     assert_eq!(
-        TimeUnit::Nanoseconds.to_chrono_duration(1024),
-        chrono::Duration::nanoseconds(1024)
+        dtf.format_date_time(&datetime, DateTimePattern::Timestamp),
+        datetime_utc.timestamp().to_string()
     );
     assert_eq!(
-        TimeUnit::Microseconds.to_chrono_duration(1024),
-        chrono::Duration::microseconds(1024)
+        dtf.format_date_time(&datetime, DateTimePattern::YyyyMmDdHhMmSsZone),
+        "2024-03-01 09:00:00 +08:00"
     );
     assert_eq!(
-        TimeUnit::Milliseconds.to_chrono_duration(1024),
-        chrono::Duration::milliseconds(1024)
+        dtf.format_date_time(&datetime_utc, DateTimePattern::YyyyMmDdHhMmSsZone),
+        "2024-03-01 01:00:00 UTC"
     );
+
+    // The local time zone formats like any other.
+    let local = datetime_utc.with_timezone(&chrono::Local);
     assert_eq!(
-        TimeUnit::Seconds.to_chrono_duration(1024),
-        chrono::Duration::seconds(1024)
+        formatter::format_date_time(&local, DateTimePattern::YyyyMmDdHhMmSsOffset),
+        local.format("%Y-%m-%d %H:%M:%S %:z").to_string()
     );
     assert_eq!(
-        TimeUnit::Minutes.to_chrono_duration(1024),
-        chrono::Duration::minutes(1024)
+        formatter::format_date_time_in(&local, &shanghai, DateTimePattern::YyyyMmDdHhMmSsOffset),
+        "2024-03-01 09:00:00 +08:00"
     );
+
+    // Java and custom offset specifiers.
+    let pattern = DateTimePattern::java("yyyy-MM-dd'T'HH:mm:ssXXX").unwrap();
     assert_eq!(
-        TimeUnit::Hours.to_chrono_duration(1024),
-        chrono::Duration::hours(1024)
+        formatter::format_date_time(&datetime, pattern.clone()),
+        "2024-03-01T09:00:00+08:00"
     );
     assert_eq!(
-        TimeUnit::Days.to_chrono_duration(1024),
-        chrono::Duration::days(1024)
+        formatter::format_date_time(&datetime_utc, pattern),
+        "2024-03-01T01:00:00Z"
     );
-}
 
-// ----------------------------------------------------------------
+    // UTC and naive values render offsets in UTC.
+    assert_eq!(
+        formatter::format_date_time_utc(&datetime_utc, DateTimePattern::YyyyMmDdHhMmSsOffset),
+        "2024-03-01 01:00:00 +00:00"
+    );
+    assert_eq!(
+        formatter::format_naive_date_time(
+            &datetime_utc.naive_utc(),
+            DateTimePattern::YyyyMmDdHhMmSsZone
+        ),
+        "2024-03-01 01:00:00 UTC"
+    );
+}
 
 #[test]
-fn test_time_unit_sleep() {
-    let start = std::time::Instant::now();
-    TimeUnit::Milliseconds.sleep(1024);
-    let duration = start.elapsed();
-    assert!(duration >= Duration::from_millis(1024));
+fn test_date_time_pattern_time_zone() {
+    for (pattern, value, name) in [
+        (
+            DateTimePattern::YyyyMmDdHhMmSsOffset,
+            "%Y-%m-%d %H:%M:%S %:z",
+            "YyyyMmDdHhMmSsOffset",
+        ),
+        (
+            DateTimePattern::YyyyMmDdHhMmSsZone,
+            "%Y-%m-%d %H:%M:%S %Z",
+            "YyyyMmDdHhMmSsZone",
+        ),
+    ] {
+        assert_eq!(pattern.pattern_of(), value);
+        assert_eq!(pattern.to_string(), name);
+        assert_eq!(DateTimePattern::value_of(value), Some(pattern.clone()));
+        assert_eq!(DateTimePattern::name_of(name), Some(pattern.clone()));
+        assert_eq!(name.parse::<DateTimePattern>(), Ok(pattern));
+    }
 }
 
 #[test]
-fn test_time_unit_closure_sleep() {
-    let start = std::time::Instant::now();
+fn test_date_time_formatter_parse_time_zone() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
 
-    TimeUnit::Milliseconds.closure_sleep(1024, |x| {
-        assert_eq!(x, Duration::from_millis(1024));
-        thread::sleep(x);
-    });
+    let value = formatter::format_date_time_in(
+        &datetime_utc,
+        &shanghai,
+        DateTimePattern::YyyyMmDdHhMmSsOffset,
+    );
+    assert_eq!(
+        formatter::parse_date_time_utc(&value, DateTimePattern::YyyyMmDdHhMmSsOffset),
+        Ok(datetime_utc)
+    );
 
-    let duration = start.elapsed();
-    assert!(duration >= Duration::from_millis(1024));
+    // A zone name is read as written, but not converted.
+    let value = "2024-03-01 09:00:00 CST";
+    assert_eq!(
+        formatter::parse_naive_date_time(value, DateTimePattern::YyyyMmDdHhMmSsZone),
+        Ok(datetime_utc.with_timezone(&shanghai).naive_local())
+    );
+    assert_eq!(
+        formatter::parse_date_time_utc(value, DateTimePattern::YyyyMmDdHhMmSsZone)
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::MissingOffset
+    );
 }
 
 #[test]
-fn test_time_unit_closure_chrono_sleep() {
-    let start = std::time::Instant::now();
-
-    TimeUnit::Milliseconds.closure_chrono_sleep(1024, |x| {
-        assert_eq!(x, chrono::Duration::milliseconds(1024));
-        thread::sleep(Duration::from_millis(x.num_milliseconds() as u64));
-    });
+fn test_date_time_formatter_zone() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
 
-    let duration = start.elapsed();
-    assert!(duration >= Duration::from_millis(1024));
-}
+    let dtf = DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSsOffset);
+    assert_eq!(dtf.activated_zone(), Zone::Utc);
+    assert_eq!(dtf.ambiguous_time_policy(), AmbiguousTimePolicy::Earliest);
 
-// ----------------------------------------------------------------
+    let dtf = dtf.with_zone(shanghai);
+    assert_eq!(dtf.activated_zone(), Zone::Fixed(shanghai));
+    assert_eq!(
+        dtf.format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 09:00:00 +08:00"
+    );
+    assert_eq!(
+        dtf.format_naive_date_time_utc_default(&datetime_utc.naive_utc()),
+        "2024-03-01 09:00:00 +08:00"
+    );
+    // Naive values are formatted as written.
+    assert_eq!(
+        dtf.format_naive_date_time(&datetime_utc.naive_utc(), DateTimePattern::YyyyMmDdHhMmSs),
+        "2024-03-01 01:00:00"
+    );
 
-#[test]
-fn test_time_unit_nanoseconds() {
-    assert_eq!(TimeUnit::Nanoseconds.to_nanos(1024), 1024);
-    assert_eq!(TimeUnit::Nanoseconds.to_micros(1024), 1024 / 1000);
-    assert_eq!(TimeUnit::Nanoseconds.to_millis(1024), 1024 / 1000 / 1000);
+    // Values without offset are local times in the zone.
     assert_eq!(
-        TimeUnit::Nanoseconds.to_seconds(1024),
-        1024 / 1000 / 1000 / 1000
+        dtf.parse_date_time_utc("2024-03-01 09:00:00", DateTimePattern::YyyyMmDdHhMmSs),
+        Ok(datetime_utc)
     );
     assert_eq!(
-        TimeUnit::Nanoseconds.to_minutes(1024),
-        1024 / 1000 / 1000 / 1000 / 60
+        dtf.parse_date_time_utc_default("2024-03-01 10:00:00 +09:00"),
+        Ok(datetime_utc)
     );
     assert_eq!(
-        TimeUnit::Nanoseconds.to_hours(1024),
-        1024 / 1000 / 1000 / 1000 / 60 / 60
+        dtf.parse_naive_date_time("2024-03-01 09:00:00", DateTimePattern::YyyyMmDdHhMmSs),
+        Ok(datetime_utc.with_timezone(&shanghai).naive_local())
     );
+
+    // The zone is kept by `of_pattern`.
+    let dtf = dtf.of_pattern(DateTimePattern::YyyyMmDdHhMmSs);
+    assert_eq!(dtf.activated_zone(), Zone::Fixed(shanghai));
     assert_eq!(
-        TimeUnit::Nanoseconds.to_days(1024),
-        1024 / 1000 / 1000 / 1000 / 60 / 60 / 24
+        dtf.format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 09:00:00"
     );
 }
 
 #[test]
-fn test_time_unit_microseconds() {
-    assert_eq!(TimeUnit::Microseconds.to_nanos(1024), 1024 * 1000);
-    assert_eq!(TimeUnit::Microseconds.to_micros(1024), 1024);
-    assert_eq!(TimeUnit::Microseconds.to_millis(1024), 1024 / 1000);
-    assert_eq!(TimeUnit::Microseconds.to_seconds(1024), 1024 / 1000 / 1000);
+fn test_zone_traits() {
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+
+    assert_eq!("UTC".parse::<Zone>(), Ok(Zone::Utc));
+    assert_eq!("utc".parse::<Zone>(), Ok(Zone::Utc));
+    assert_eq!("Z".parse::<Zone>(), Ok(Zone::Utc));
+    assert_eq!("+08:00".parse::<Zone>(), Ok(Zone::Fixed(shanghai)));
+    assert_eq!("+0800".parse::<Zone>(), Ok(Zone::Fixed(shanghai)));
+    assert_eq!("-0500".parse::<Zone>(), Ok(Zone::Fixed(new_york)));
+    for value in ["+08:00x", "+8", "-05", "+08:0", "+0800:", "", "-"] {
+        assert_eq!(
+            value.parse::<Zone>().unwrap_err().value(),
+            value,
+            "{}",
+            value
+        );
+    }
+    assert_eq!(
+        "+08:00x".parse::<Zone>().unwrap_err().to_string(),
+        "invalid time zone: `+08:00x`"
+    );
+
+    assert_eq!(Zone::Utc.to_string(), "UTC");
+    assert_eq!(Zone::Fixed(shanghai).to_string(), "+08:00");
+    assert_eq!(Zone::from(Utc), Zone::Utc);
+    assert_eq!(Zone::from(new_york), Zone::Fixed(new_york));
+    assert_eq!(Zone::default(), Zone::Utc);
+
+    let local = NaiveDate::from_ymd_opt(2024, 3, 1)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    assert_eq!(
+        Zone::Utc
+            .resolve(&local, AmbiguousTimePolicy::Error)
+            .unwrap()
+            .to_rfc3339(),
+        "2024-03-01T09:00:00+00:00"
+    );
+    assert_eq!(Zone::Fixed(new_york).offset_at(&Utc::now()), new_york);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_zone_named() {
+    let new_york = Zone::named("America/New_York").unwrap();
+    assert_eq!(new_york, Zone::Named(chrono_tz::America::New_York));
+    assert_eq!("America/New_York".parse::<Zone>(), Ok(new_york));
+    assert_eq!(new_york.to_string(), "America/New_York");
+    assert!(Zone::named("America/Gotham").is_err());
+
+    // DST: EST in winter, EDT in summer.
+    let dtf =
+        DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSsZone).with_zone(new_york);
     assert_eq!(
-        TimeUnit::Microseconds.to_minutes(1024),
-        1024 / 1000 / 1000 / 60
+        dtf.format_date_time_utc_default(&Utc.with_ymd_and_hms(2024, 1, 15, 17, 0, 0).unwrap()),
+        "2024-01-15 12:00:00 EST"
     );
     assert_eq!(
-        TimeUnit::Microseconds.to_hours(1024),
-        1024 / 1000 / 1000 / 60 / 60
+        dtf.format_date_time_utc(
+            &Utc.with_ymd_and_hms(2024, 7, 15, 16, 0, 0).unwrap(),
+            DateTimePattern::YyyyMmDdHhMmSsOffset
+        ),
+        "2024-07-15 12:00:00 -04:00"
     );
     assert_eq!(
-        TimeUnit::Microseconds.to_days(1024),
-        1024 / 1000 / 1000 / 60 / 60 / 24
+        new_york.offset_at(&Utc.with_ymd_and_hms(2024, 7, 15, 16, 0, 0).unwrap()),
+        FixedOffset::west_opt(4 * 3600).unwrap()
+    );
+
+    let shanghai = Zone::named("Asia/Shanghai").unwrap();
+    assert_eq!(
+        formatter::format_date_time_in(
+            &Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap(),
+            &chrono_tz::Asia::Shanghai,
+            DateTimePattern::YyyyMmDdHhMmSsZone
+        ),
+        "2024-03-01 09:00:00 CST"
+    );
+    assert_eq!(
+        DefaultDateTimeFormatter::builtin()
+            .with_zone(shanghai)
+            .parse_date_time_utc_default("2024-03-01 09:00:00"),
+        Ok(Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap())
     );
 }
 
+#[cfg(feature = "tz")]
 #[test]
-fn test_time_unit_milliseconds() {
-    assert_eq!(TimeUnit::Milliseconds.to_nanos(1024), 1024 * 1000 * 1000);
-    assert_eq!(TimeUnit::Milliseconds.to_micros(1024), 1024 * 1000);
-    assert_eq!(TimeUnit::Milliseconds.to_millis(1024), 1024);
-    assert_eq!(TimeUnit::Milliseconds.to_seconds(1024), 1024 / 1000);
-    assert_eq!(TimeUnit::Milliseconds.to_minutes(1024), 1024 / 1000 / 60);
-    assert_eq!(TimeUnit::Milliseconds.to_hours(1024), 1024 / 1000 / 60 / 60);
-    assert_eq!(
-        TimeUnit::Milliseconds.to_days(1024),
-        1024 / 1000 / 60 / 60 / 24
+fn test_zone_named_transitions() {
+    let new_york = Zone::named("America/New_York").unwrap();
+    let local = |d, h, m| {
+        NaiveDate::from_ymd_opt(2024, 11, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    };
+
+    // 2024-11-03 01:30 occurs twice, EDT then EST.
+    let ambiguous = local(3, 1, 30);
+    assert_eq!(
+        new_york
+            .resolve(&ambiguous, AmbiguousTimePolicy::Earliest)
+            .unwrap()
+            .to_rfc3339(),
+        "2024-11-03T01:30:00-04:00"
+    );
+    assert_eq!(
+        new_york
+            .resolve(&ambiguous, AmbiguousTimePolicy::Latest)
+            .unwrap()
+            .to_rfc3339(),
+        "2024-11-03T01:30:00-05:00"
+    );
+    let err = new_york
+        .resolve(&ambiguous, AmbiguousTimePolicy::Error)
+        .unwrap_err();
+    assert!(matches!(err.kind(), LocalTimeErrorKind::Ambiguous(..)));
+    assert_eq!(err.datetime(), ambiguous);
+    assert_eq!(err.zone(), new_york);
+    assert_eq!(
+        err.to_string(),
+        "local time `2024-11-03 01:30:00` is ambiguous in `America/New_York`: `2024-11-03 01:30:00 -04:00` or `2024-11-03 01:30:00 -05:00`"
+    );
+
+    // 2024-03-10 02:30 is skipped.
+    let nonexistent = NaiveDate::from_ymd_opt(2024, 3, 10)
+        .unwrap()
+        .and_hms_opt(2, 30, 0)
+        .unwrap();
+    let err = new_york
+        .resolve(&nonexistent, AmbiguousTimePolicy::Earliest)
+        .unwrap_err();
+    assert_eq!(err.kind(), LocalTimeErrorKind::Nonexistent);
+    assert_eq!(
+        err.to_string(),
+        "local time `2024-03-10 02:30:00` does not exist in `America/New_York`"
+    );
+
+    // The formatter applies its policy when parsing.
+    let dtf = DefaultDateTimeFormatter::builtin().with_zone(new_york);
+    assert_eq!(
+        dtf.parse_date_time_utc_default("2024-11-03 01:30:00"),
+        Ok(Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap())
+    );
+    let dtf = dtf.with_ambiguous_time_policy(AmbiguousTimePolicy::Latest);
+    assert_eq!(
+        dtf.parse_date_time_utc_default("2024-11-03 01:30:00"),
+        Ok(Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap())
+    );
+    let dtf = dtf.with_ambiguous_time_policy(AmbiguousTimePolicy::Error);
+    assert!(matches!(
+        dtf.parse_date_time_utc_default("2024-11-03 01:30:00")
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::LocalTime(LocalTimeErrorKind::Ambiguous(..))
+    ));
+    assert_eq!(
+        dtf.parse_date_time_utc_default("2024-03-10 02:30:00")
+            .unwrap_err()
+            .kind(),
+        ParseDateTimeErrorKind::LocalTime(LocalTimeErrorKind::Nonexistent)
+    );
+    assert_eq!(
+        dtf.parse_date_time_utc_default("2024-03-10 02:30:00")
+            .unwrap_err()
+            .to_string(),
+        "cannot parse `2024-03-10 02:30:00` with pattern `YyyyMmDdHhMmSs`: the local time does not exist in the zone"
     );
 }
 
 #[test]
-fn test_time_unit_seconds() {
-    assert_eq!(TimeUnit::Seconds.to_nanos(1024), 1024 * 1000 * 1000 * 1000);
-    assert_eq!(TimeUnit::Seconds.to_micros(1024), 1024 * 1000 * 1000);
-    assert_eq!(TimeUnit::Seconds.to_millis(1024), 1024 * 1000);
-    assert_eq!(TimeUnit::Seconds.to_seconds(1024), 1024);
-    assert_eq!(TimeUnit::Seconds.to_minutes(1024), 1024 / 60);
-    assert_eq!(TimeUnit::Seconds.to_hours(1024), 1024 / 60 / 60);
-    assert_eq!(TimeUnit::Seconds.to_days(1024), 1024 / 60 / 60 / 24);
+fn test_date_time_pattern_interchange() {
+    let datetime_utc = Utc
+        .with_ymd_and_hms(2024, 3, 1, 12, 0, 0)
+        .unwrap()
+        .with_nanosecond(123_456_789)
+        .unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    let datetime = datetime_utc.with_timezone(&shanghai);
+
+    let cases = [
+        (
+            DateTimePattern::Rfc3339(Precision::Seconds),
+            "2024-03-01T12:00:00Z",
+            "2024-03-01T20:00:00+08:00",
+        ),
+        (
+            DateTimePattern::Rfc3339(Precision::Millis),
+            "2024-03-01T12:00:00.123Z",
+            "2024-03-01T20:00:00.123+08:00",
+        ),
+        (
+            DateTimePattern::Rfc3339(Precision::Micros),
+            "2024-03-01T12:00:00.123456Z",
+            "2024-03-01T20:00:00.123456+08:00",
+        ),
+        (
+            DateTimePattern::Rfc3339(Precision::Nanos),
+            "2024-03-01T12:00:00.123456789Z",
+            "2024-03-01T20:00:00.123456789+08:00",
+        ),
+        (
+            DateTimePattern::Iso8601Basic(Precision::Seconds),
+            "20240301T120000Z",
+            "20240301T200000+0800",
+        ),
+        (
+            DateTimePattern::Iso8601Basic(Precision::Millis),
+            "20240301T120000.123Z",
+            "20240301T200000.123+0800",
+        ),
+        (
+            DateTimePattern::Rfc2822,
+            "Fri, 01 Mar 2024 12:00:00 +0000",
+            "Fri, 01 Mar 2024 20:00:00 +0800",
+        ),
+        (
+            DateTimePattern::HttpDate,
+            "Fri, 01 Mar 2024 12:00:00 GMT",
+            "Fri, 01 Mar 2024 12:00:00 GMT",
+        ),
+        (DateTimePattern::IsoWeekDate, "2024-W09-5", "2024-W09-5"),
+    ];
+    for (pattern, utc, offset) in cases {
+        assert_eq!(
+            formatter::format_date_time_utc(&datetime_utc, pattern.clone()),
+            utc,
+            "{}",
+            pattern
+        );
+        assert_eq!(
+            formatter::format_naive_date_time(&datetime_utc.naive_utc(), pattern.clone()),
+            utc,
+            "{}",
+            pattern
+        );
+        assert_eq!(
+            formatter::format_date_time(&datetime, pattern.clone()),
+            offset
+        );
+        assert_eq!(
+            DefaultDateTimeFormatter::new(pattern.clone())
+                .with_zone(shanghai)
+                .format_date_time_utc_default(&datetime_utc),
+            offset
+        );
+
+        assert_eq!(
+            DateTimePattern::name_of(&pattern.to_string()),
+            Some(pattern.clone())
+        );
+        assert_eq!(
+            DateTimePattern::value_of(pattern.pattern_of()),
+            Some(pattern)
+        );
+    }
+
+    // Parsing, to the precision of the pattern.
+    let truncated = |nanos| datetime_utc.with_nanosecond(nanos).unwrap();
+    let cases = [
+        (
+            DateTimePattern::Rfc3339(Precision::Seconds),
+            "2024-03-01T20:00:00+08:00",
+            truncated(0),
+        ),
+        (
+            DateTimePattern::Rfc3339(Precision::Millis),
+            "2024-03-01T12:00:00.123Z",
+            truncated(123_000_000),
+        ),
+        (
+            DateTimePattern::Rfc3339(Precision::Nanos),
+            "2024-03-01T20:00:00.123456789+08:00",
+            datetime_utc,
+        ),
+        (
+            DateTimePattern::Iso8601Basic(Precision::Micros),
+            "20240301T120000.123456Z",
+            truncated(123_456_000),
+        ),
+        (
+            DateTimePattern::Rfc2822,
+            "Fri, 1 Mar 2024 20:00:00 +0800",
+            truncated(0),
+        ),
+        (
+            DateTimePattern::HttpDate,
+            "Fri, 01 Mar 2024 12:00:00 GMT",
+            truncated(0),
+        ),
+    ];
+    for (pattern, value, expected) in cases {
+        assert_eq!(
+            formatter::parse_date_time_utc(value, pattern),
+            Ok(expected),
+            "{}",
+            value
+        );
+    }
+
+    // An HTTP date is always in UTC, whatever the zone of the formatter.
+    assert_eq!(
+        DefaultDateTimeFormatter::new(DateTimePattern::HttpDate)
+            .with_zone(shanghai)
+            .parse_date_time_utc_default("Fri, 01 Mar 2024 12:00:00 GMT"),
+        Ok(truncated(0))
+    );
+    assert_eq!(
+        formatter::parse_naive_date("2024-W09-5", DateTimePattern::IsoWeekDate),
+        Ok(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+    );
+
+    for (value, pattern) in [
+        (
+            "2024-03-01T12:00:00.123Z",
+            DateTimePattern::Rfc3339(Precision::Seconds),
+        ),
+        (
+            "2024-03-01T12:00:00.12Z",
+            DateTimePattern::Rfc3339(Precision::Millis),
+        ),
+        (
+            "2024-03-01 12:00:00Z",
+            DateTimePattern::Rfc3339(Precision::Seconds),
+        ),
+        ("Sat, 01 Mar 2024 12:00:00 GMT", DateTimePattern::HttpDate),
+    ] {
+        assert!(
+            formatter::parse_date_time_utc(value, pattern).is_err(),
+            "{}",
+            value
+        );
+    }
+    assert_eq!(
+        "Rfc3339Millis".parse::<DateTimePattern>(),
+        Ok(DateTimePattern::Rfc3339(Precision::Millis))
+    );
+    assert_eq!(Precision::default(), Precision::Seconds);
 }
 
+// ---------------------------------------------------------------- date-time epoch
+
 #[test]
-fn test_time_unit_minutes() {
-    assert_eq!(
-        TimeUnit::Minutes.to_nanos(1024),
-        1024 * 1000 * 1000 * 1000 * 60
+fn test_date_time_pattern_epoch() {
+    let datetime_utc = Utc
+        .with_ymd_and_hms(2024, 3, 1, 12, 0, 0)
+        .unwrap()
+        .with_nanosecond(123_456_789)
+        .unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+
+    let cases = [
+        (
+            DateTimePattern::Epoch(TimeUnit::Nanoseconds),
+            "1709294400123456789",
+        ),
+        (
+            DateTimePattern::Epoch(TimeUnit::Microseconds),
+            "1709294400123456",
+        ),
+        (
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            "1709294400123",
+        ),
+        (DateTimePattern::Epoch(TimeUnit::Seconds), "1709294400"),
+        (DateTimePattern::Epoch(TimeUnit::Days), "19783"),
+        (
+            DateTimePattern::EpochFractional(Precision::Seconds),
+            "1709294400",
+        ),
+        (
+            DateTimePattern::EpochFractional(Precision::Millis),
+            "1709294400.123",
+        ),
+        (
+            DateTimePattern::EpochFractional(Precision::Nanos),
+            "1709294400.123456789",
+        ),
+    ];
+    for (pattern, expected) in cases {
+        assert_eq!(
+            formatter::format_date_time_utc(&datetime_utc, pattern.clone()),
+            expected,
+            "{}",
+            pattern
+        );
+        assert_eq!(
+            formatter::format_naive_date_time(&datetime_utc.naive_utc(), pattern.clone()),
+            expected,
+            "{}",
+            pattern
+        );
+        // The count since the epoch does not depend on the time zone.
+        assert_eq!(
+            formatter::format_date_time(&datetime_utc.with_timezone(&shanghai), pattern.clone()),
+            expected,
+            "{}",
+            pattern
+        );
+        assert_eq!(
+            DefaultDateTimeFormatter::new(pattern.clone())
+                .with_zone(shanghai)
+                .format_date_time_utc_default(&datetime_utc),
+            expected,
+            "{}",
+            pattern
+        );
+    }
+
+    let truncated = |nanos| datetime_utc.with_nanosecond(nanos).unwrap();
+    assert_eq!(
+        formatter::parse_date_time_utc(
+            "1709294400123456789",
+            DateTimePattern::Epoch(TimeUnit::Nanoseconds)
+        ),
+        Ok(datetime_utc)
+    );
+    assert_eq!(
+        formatter::parse_date_time_utc(
+            "1709294400123",
+            DateTimePattern::Epoch(TimeUnit::Milliseconds)
+        ),
+        Ok(truncated(123_000_000))
+    );
+    assert_eq!(
+        formatter::parse_date_time_utc("19783", DateTimePattern::Epoch(TimeUnit::Days)),
+        Ok(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        formatter::parse_date_time_utc(
+            "1709294400.123456",
+            DateTimePattern::EpochFractional(Precision::Millis)
+        ),
+        Ok(truncated(123_456_000))
+    );
+    assert_eq!(
+        formatter::parse_date_time_utc(
+            "+1709294400",
+            DateTimePattern::EpochFractional(Precision::Millis)
+        ),
+        Ok(truncated(0))
     );
-    assert_eq!(TimeUnit::Minutes.to_micros(1024), 1024 * 1000 * 1000 * 60);
-    assert_eq!(TimeUnit::Minutes.to_millis(1024), 1024 * 1000 * 60);
+}
+
+#[test]
+fn test_date_time_pattern_epoch_before_1970() {
+    // Half a second, and one and a half milliseconds, before the epoch.
+    let half_second = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+        + chrono::Duration::milliseconds(500);
+    let sub_millis = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+        + chrono::Duration::microseconds(998_500);
+
+    let cases = [
+        (half_second, DateTimePattern::Timestamp, "-1"),
+        (
+            half_second,
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            "-500",
+        ),
+        (
+            half_second,
+            DateTimePattern::EpochFractional(Precision::Millis),
+            "-0.500",
+        ),
+        (
+            half_second,
+            DateTimePattern::EpochFractional(Precision::Seconds),
+            "-1",
+        ),
+        // Rounded down, as the whole units.
+        (
+            sub_millis,
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            "-2",
+        ),
+        (
+            sub_millis,
+            DateTimePattern::Epoch(TimeUnit::Microseconds),
+            "-1500",
+        ),
+        (
+            sub_millis,
+            DateTimePattern::EpochFractional(Precision::Millis),
+            "-0.002",
+        ),
+        (
+            sub_millis,
+            DateTimePattern::EpochFractional(Precision::Micros),
+            "-0.001500",
+        ),
+    ];
+    for (datetime, pattern, expected) in cases {
+        assert_eq!(
+            formatter::format_date_time_utc(&datetime, pattern.clone()),
+            expected,
+            "{}",
+            pattern
+        );
+    }
+
+    for (value, pattern, expected) in [
+        (
+            "-500",
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            half_second,
+        ),
+        (
+            "-0.5",
+            DateTimePattern::EpochFractional(Precision::Millis),
+            half_second,
+        ),
+        (
+            "-1500",
+            DateTimePattern::Epoch(TimeUnit::Microseconds),
+            sub_millis,
+        ),
+        (
+            "-0.0015",
+            DateTimePattern::EpochFractional(Precision::Nanos),
+            sub_millis,
+        ),
+        (
+            "-86400000",
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap(),
+        ),
+    ] {
+        assert_eq!(
+            formatter::parse_date_time_utc(value, pattern),
+            Ok(expected),
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_date_time_pattern_epoch_parse_error() {
+    let cases = [
+        (
+            "1709294400.123",
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            "0.1234567891",
+            DateTimePattern::EpochFractional(Precision::Nanos),
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            "1.",
+            DateTimePattern::EpochFractional(Precision::Millis),
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            ".5",
+            DateTimePattern::EpochFractional(Precision::Millis),
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            "-",
+            DateTimePattern::EpochFractional(Precision::Millis),
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            "1 000",
+            DateTimePattern::Epoch(TimeUnit::Seconds),
+            ParseDateTimeErrorKind::Mismatch,
+        ),
+        (
+            &i128::MAX.to_string(),
+            DateTimePattern::Epoch(TimeUnit::Weeks),
+            ParseDateTimeErrorKind::OutOfRange,
+        ),
+        (
+            &i64::MAX.to_string(),
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            ParseDateTimeErrorKind::OutOfRange,
+        ),
+    ];
+    for (value, pattern, kind) in cases {
+        let error = formatter::parse_date_time_utc(value, pattern.clone()).unwrap_err();
+        assert_eq!(error.kind(), kind, "{}", value);
+        assert_eq!(error.pattern(), pattern);
+    }
+}
+
+#[test]
+fn test_date_time_pattern_epoch_names() {
+    let cases = [
+        (DateTimePattern::Timestamp, "Timestamp", "%s"),
+        (
+            DateTimePattern::Epoch(TimeUnit::Milliseconds),
+            "EpochMillis",
+            "%s%3f",
+        ),
+        (
+            DateTimePattern::Epoch(TimeUnit::Nanoseconds),
+            "EpochNanos",
+            "%s%9f",
+        ),
+        (
+            DateTimePattern::Epoch(TimeUnit::Days),
+            "EpochDays",
+            "epoch-days",
+        ),
+        (
+            DateTimePattern::EpochFractional(Precision::Micros),
+            "EpochFractionalMicros",
+            "%s%.6f",
+        ),
+    ];
+    for (pattern, name, value) in cases {
+        assert_eq!(pattern.to_string(), name);
+        assert_eq!(pattern.pattern_of(), value);
+        assert_eq!(name.parse::<DateTimePattern>(), Ok(pattern.clone()));
+        assert_eq!(DateTimePattern::value_of(value), Some(pattern));
+    }
+
+    // Each of the seconds has its own pattern string, all rendering the same.
+    let seconds = [
+        DateTimePattern::Timestamp,
+        DateTimePattern::Epoch(TimeUnit::Seconds),
+        DateTimePattern::EpochFractional(Precision::Seconds),
+    ];
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    for pattern in seconds {
+        assert_eq!(
+            DateTimePattern::value_of(pattern.pattern_of()),
+            Some(pattern.clone())
+        );
+        assert_eq!(
+            formatter::format_date_time_utc(&datetime_utc, pattern),
+            "1709294400"
+        );
+    }
+    assert_eq!(
+        DateTimePattern::Epoch(TimeUnit::Seconds).pattern_of(),
+        DateTimePattern::EPOCH_SECONDS
+    );
+
+    // Both spellings of the timestamp.
+    for value in ["%s", "timestamp"] {
+        assert_eq!(
+            DateTimePattern::value_of(value),
+            Some(DateTimePattern::Timestamp)
+        );
+    }
+}
+
+// ---------------------------------------------------------------- date-time locale
+
+#[test]
+fn test_date_time_formatter_locale() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+
+    let cases = [
+        (Locale::EnUs, ["March", "Mar", "Friday", "Fri", "PM"]),
+        (Locale::ZhCn, ["三月", "3月", "星期五", "周五", "下午"]),
+        (Locale::JaJp, ["3月", "3月", "金曜日", "金", "午後"]),
+        (Locale::DeDe, ["März", "Mär", "Freitag", "Fr", "PM"]),
+        (Locale::FrFr, ["mars", "mars", "vendredi", "ven.", "PM"]),
+        (Locale::EsEs, ["marzo", "mar", "viernes", "vie", "p. m."]),
+    ];
+    let patterns = [
+        DateTimePattern::MonthFull,
+        DateTimePattern::MonthAbbr,
+        DateTimePattern::WeekdayFull,
+        DateTimePattern::WeekdayAbbr,
+        DateTimePattern::AmPm,
+    ];
+    for (locale, names) in cases {
+        let dtf = DefaultDateTimeFormatter::builtin().with_locale(locale);
+        for (pattern, name) in patterns.iter().zip(names) {
+            assert_eq!(
+                dtf.format_date_time_utc(&datetime_utc, pattern.clone()),
+                name,
+                "{} {}",
+                locale,
+                pattern
+            );
+            assert_eq!(
+                dtf.format_naive_date_time(&datetime_utc.naive_utc(), pattern.clone()),
+                name,
+                "{} {}",
+                locale,
+                pattern
+            );
+        }
+    }
+
+    // The names are those of the local time in the zone of the formatter.
+    let dtf = DefaultDateTimeFormatter::new(DateTimePattern::custom("%A %p").unwrap())
+        .with_locale(Locale::JaJp)
+        .with_zone(FixedOffset::east_opt(11 * 3600).unwrap());
+    assert_eq!(
+        dtf.format_date_time_utc_default(&datetime_utc),
+        "土曜日 午前"
+    );
+    assert_eq!(
+        dtf.format_date_time(
+            &datetime_utc,
+            DateTimePattern::java("EEEE, d MMMM").unwrap()
+        ),
+        "土曜日, 2 3月"
+    );
+    assert_eq!(
+        dtf.format_date_time_in(
+            &datetime_utc,
+            &Utc,
+            DateTimePattern::java("EEEE, d MMMM").unwrap()
+        ),
+        "金曜日, 1 3月"
+    );
+
+    // `%P` and Java patterns are localized too.
+    let dtf = DefaultDateTimeFormatter::builtin().with_locale(Locale::DeDe);
+    assert_eq!(
+        dtf.format_date_time_utc(&datetime_utc, DateTimePattern::custom("%I %P").unwrap()),
+        "01 pm"
+    );
+    assert_eq!(
+        dtf.format_date_time_utc(
+            &datetime_utc,
+            DateTimePattern::java("EEE, dd. MMM yyyy").unwrap()
+        ),
+        "Fr, 01. Mär 2024"
+    );
+    assert_eq!(
+        dtf.of_pattern(DateTimePattern::MonthFull)
+            .format_date_time_utc_default(&datetime_utc),
+        "März"
+    );
+}
+
+#[test]
+fn test_date_time_formatter_locale_neutral() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    let dtf = DefaultDateTimeFormatter::builtin().with_locale(Locale::FrFr);
+
+    // The interchange formats stay in English.
+    assert_eq!(
+        dtf.format_date_time_utc(&datetime_utc, DateTimePattern::Rfc2822),
+        "Fri, 01 Mar 2024 13:05:00 +0000"
+    );
+    assert_eq!(
+        dtf.format_date_time_utc(&datetime_utc, DateTimePattern::HttpDate),
+        "Fri, 01 Mar 2024 13:05:00 GMT"
+    );
+    assert_eq!(
+        dtf.format_date_time_utc(&datetime_utc, DateTimePattern::Rfc3339(Precision::Seconds)),
+        "2024-03-01T13:05:00Z"
+    );
+
+    // Parsing reads the English names.
+    assert_eq!(
+        dtf.parse_naive_date("01 Mar 2024", DateTimePattern::custom("%d %b %Y").unwrap()),
+        Ok(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+    );
+}
+
+#[test]
+fn test_locale_layouts() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+
+    let cases = [
+        (
+            Locale::EnUs,
+            "03/01/2024",
+            "01:05:00 PM",
+            "03/01/2024 01:05:00 PM",
+        ),
+        (
+            Locale::ZhCn,
+            "2024年3月1日",
+            "13:05:00",
+            "2024年3月1日 13:05:00",
+        ),
+        (
+            Locale::JaJp,
+            "2024/03/01",
+            "13:05:00",
+            "2024/03/01 13:05:00",
+        ),
+        (
+            Locale::DeDe,
+            "01.03.2024",
+            "13:05:00",
+            "01.03.2024 13:05:00",
+        ),
+        (
+            Locale::FrFr,
+            "01/03/2024",
+            "13:05:00",
+            "01/03/2024 13:05:00",
+        ),
+        (
+            Locale::EsEs,
+            "01/03/2024",
+            "13:05:00",
+            "01/03/2024 13:05:00",
+        ),
+    ];
+    for (locale, date, time, date_time) in cases {
+        let dtf = DefaultDateTimeFormatter::localized(locale);
+        assert_eq!(dtf.activated_locale(), locale);
+        assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), date_time);
+        assert_eq!(
+            dtf.format_date_time_utc(&datetime_utc, locale.date_pattern()),
+            date
+        );
+        assert_eq!(
+            dtf.format_date_time_utc(&datetime_utc, locale.time_pattern()),
+            time
+        );
+        assert_eq!(
+            dtf.parse_naive_date_time_default(date_time),
+            Ok(datetime_utc.naive_utc()),
+            "{}",
+            locale
+        );
+    }
+}
+
+#[test]
+fn test_locale_traits() {
+    assert_eq!(Locale::default(), Locale::EnUs);
+    assert_eq!(
+        DefaultDateTimeFormatter::builtin().activated_locale(),
+        Locale::EnUs
+    );
+    assert_eq!(Locale::ZhCn.to_string(), "zh_CN");
+    assert_eq!(Locale::EsEs.name(), Locale::ES_ES_NAME);
+
+    for locale in [
+        Locale::EnUs,
+        Locale::ZhCn,
+        Locale::JaJp,
+        Locale::DeDe,
+        Locale::FrFr,
+        Locale::EsEs,
+    ] {
+        assert_eq!(locale.to_string().parse::<Locale>(), Ok(locale));
+    }
+    assert_eq!("ZH-cn".parse::<Locale>(), Ok(Locale::ZhCn));
+    assert_eq!("ja_JP.UTF-8".parse::<Locale>(), Ok(Locale::JaJp));
+    assert_eq!("de_DE@euro".parse::<Locale>(), Ok(Locale::DeDe));
+
+    let error: ParseLocaleError = "zh".parse::<Locale>().unwrap_err();
+    assert_eq!(error.value(), "zh");
+    assert_eq!(error.to_string(), "unsupported locale: `zh`");
+
+    assert_eq!(Locale::FrFr.month_name(8), Some("août"));
+    assert_eq!(Locale::FrFr.month_name(0), None);
+    assert_eq!(Locale::EsEs.weekday_name(chrono::Weekday::Wed), "miércoles");
+}
+
+// ---------------------------------------------------------------- local formatter
+
+#[test]
+fn test_local_formatter() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let format_default = || formatter::format_date_time_utc_default(&datetime_utc);
+
+    {
+        let _tenant = formatter::override_local_formatter(
+            DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMm)
+                .with_zone(FixedOffset::east_opt(8 * 3600).unwrap()),
+        );
+        assert_eq!(format_default(), "2024-03-01 20:00");
+        assert_eq!(
+            format_naive_date_time_default!(&datetime_utc.naive_utc()),
+            "2024-03-01 12:00"
+        );
+
+        {
+            let _inner = formatter::override_local_formatter(DefaultDateTimeFormatter::new(
+                DateTimePattern::HhMm,
+            ));
+            assert_eq!(format_default(), "12:00");
+            assert_eq!(
+                formatter::parse_naive_time_default("09:30"),
+                Ok(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+            );
+        }
+        assert_eq!(format_default(), "2024-03-01 20:00");
+
+        // Other threads fall back to the process-wide default formatter.
+        let other = thread::spawn(move || formatter::format_date_time_utc_default(&datetime_utc));
+        assert_eq!(other.join().unwrap(), "2024-03-01 12:00:00");
+    }
+
+    assert_eq!(format_default(), "2024-03-01 12:00:00");
+}
+
+#[test]
+fn test_local_formatter_out_of_order() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+
+    let outer = formatter::override_local_formatter(DefaultDateTimeFormatter::new(
+        DateTimePattern::YyyyMmDd,
+    ));
+    let inner =
+        formatter::override_local_formatter(DefaultDateTimeFormatter::new(DateTimePattern::HhMm));
+
+    // Dropping the outer guard first removes the inner override too.
+    drop(outer);
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 12:00:00"
+    );
+    drop(inner);
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 12:00:00"
+    );
+}
+
+/// Overrides the formatter of its thread while formatting.
+struct ReentrantFormatter;
+
+impl DateTimeFormatter for ReentrantFormatter {
+    fn of_pattern(&self, pattern: DateTimePattern) -> Box<dyn DateTimeFormatter> {
+        Box::new(DefaultDateTimeFormatter::new(pattern))
+    }
+
+    fn activated_pattern(&self) -> DateTimePattern {
+        let _guard = formatter::override_local_formatter(DefaultDateTimeFormatter::builtin());
+        DateTimePattern::YyyyMmDd
+    }
+}
+
+#[test]
+fn test_local_formatter_reentrant() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+
+    let _guard = formatter::override_local_formatter(ReentrantFormatter);
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01"
+    );
+}
+
+/// Formats with the default formatter when dropped, and sends what it formatted.
+struct FormatOnDrop(Option<mpsc::Sender<String>>);
+
+impl Drop for FormatOnDrop {
+    fn drop(&mut self) {
+        let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        if let Some(sender) = self.0.take() {
+            let _ = sender.send(formatter::format_date_time_utc_default(&datetime_utc));
+        }
+    }
+}
+
+thread_local! {
+    static FORMAT_ON_DROP: RefCell<FormatOnDrop> = const { RefCell::new(FormatOnDrop(None)) };
+}
+
+#[test]
+fn test_local_formatter_thread_teardown() {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        FORMAT_ON_DROP.with(|on_drop| on_drop.borrow_mut().0 = Some(sender));
+        // Registered last, so torn down first.
+        drop(formatter::override_local_formatter(
+            DefaultDateTimeFormatter::builtin(),
+        ));
+    })
+    .join()
+    .unwrap();
+
+    // Falls back to the process-wide default formatter.
+    assert_eq!(receiver.recv().unwrap(), "2024-03-01 12:00:00");
+}
+
+/// A future ready on its second poll, with what it formatted on each.
+struct FormatTwice {
+    datetime: DateTime<Utc>,
+    first: Option<String>,
+}
+
+impl Future for FormatTwice {
+    type Output = (String, String);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let rendered = formatter::format_date_time_utc_default(&self.datetime);
+        match self.first.take() {
+            Some(first) => Poll::Ready((first, rendered)),
+            None => {
+                self.first = Some(rendered);
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[test]
+fn test_scope_formatter() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let mut task = formatter::scope_formatter(
+        DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDd),
+        FormatTwice {
+            datetime: datetime_utc,
+            first: None,
+        },
+    );
+    let waker = Arc::new(NoopWaker).into();
+    let mut cx = Context::from_waker(&waker);
+
+    assert!(Pin::new(&mut task).poll(&mut cx).is_pending());
+    // Between two polls, the thread is back to its own default formatter.
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 12:00:00"
+    );
+
+    // The task may be polled on another thread.
+    let polled = thread::spawn(move || {
+        let waker = Arc::new(NoopWaker).into();
+        Pin::new(&mut task).poll(&mut Context::from_waker(&waker))
+    });
+    assert_eq!(
+        polled.join().unwrap(),
+        Poll::Ready(("2024-03-01".to_string(), "2024-03-01".to_string()))
+    );
+}
+
+// ---------------------------------------------------------------- date-time write
+
+#[test]
+fn test_date_time_formatter_write() {
+    let datetime_utc =
+        Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap() + chrono::Duration::milliseconds(123);
+    let ndt = datetime_utc.naive_utc();
+
+    let patterns = [
+        DateTimePattern::YyyyMmDd,
+        DateTimePattern::YyyyMmDdHhMmSsSss,
+        DateTimePattern::YyyyMmDdHhMmSsOffset,
+        DateTimePattern::WeekdayFull,
+        DateTimePattern::Timestamp,
+        DateTimePattern::Epoch(TimeUnit::Milliseconds),
+        DateTimePattern::EpochFractional(Precision::Micros),
+        DateTimePattern::Rfc3339(Precision::Millis),
+        DateTimePattern::HttpDate,
+        DateTimePattern::custom("%d/%m/%Y %A").unwrap(),
+    ];
+    let formatters = [
+        DefaultDateTimeFormatter::builtin(),
+        DefaultDateTimeFormatter::builtin().with_locale(Locale::DeDe),
+        DefaultDateTimeFormatter::builtin().with_zone(FixedOffset::east_opt(8 * 3600).unwrap()),
+    ];
+    for dtf in formatters {
+        for pattern in &patterns {
+            let mut buffer = String::from(">");
+            dtf.write_date_time_utc(&mut buffer, &datetime_utc, pattern.clone())
+                .unwrap();
+            assert_eq!(
+                buffer,
+                format!(
+                    ">{}",
+                    dtf.format_date_time_utc(&datetime_utc, pattern.clone())
+                ),
+                "{}",
+                pattern
+            );
+
+            let mut buffer = String::new();
+            dtf.write_naive_date_time(&mut buffer, &ndt, pattern.clone())
+                .unwrap();
+            assert_eq!(
+                buffer,
+                dtf.format_naive_date_time(&ndt, pattern.clone()),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    let dtf = DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDd);
+    let mut buffer = String::new();
+    dtf.write_date_time_utc_default(&mut buffer, &datetime_utc)
+        .unwrap();
+    buffer.push(' ');
+    dtf.write_naive_date_time_default(&mut buffer, &ndt)
+        .unwrap();
+    buffer.push(' ');
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    dtf.write_date_time(
+        &mut buffer,
+        &datetime_utc.with_timezone(&shanghai),
+        DateTimePattern::YyyyMmDdHhMmSsOffset,
+    )
+    .unwrap();
+    assert_eq!(buffer, "2024-03-01 2024-03-01 2024-03-01 21:05:00 +08:00");
+}
+
+#[test]
+fn test_date_time_formatter_write_io() {
+    struct Failing;
+
+    impl std::io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "closed",
+            ))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    let dtf = DefaultDateTimeFormatter::builtin().with_locale(Locale::ZhCn);
+
+    let mut buffer = Vec::new();
+    dtf.write_date_time_utc_io(&mut buffer, &datetime_utc, DateTimePattern::MonthFull)
+        .unwrap();
+    dtf.write_naive_date_time_io(
+        &mut buffer,
+        &datetime_utc.naive_utc(),
+        DateTimePattern::HhMm,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), "三月13:05");
+
+    let error = dtf
+        .write_date_time_utc_io(&mut Failing, &datetime_utc, DateTimePattern::YyyyMmDd)
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn test_date_time_formatter_display() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    let ndt = datetime_utc.naive_utc();
+    let dtf = DefaultDateTimeFormatter::builtin();
+
+    assert_eq!(
+        dtf.display(&datetime_utc).to_string(),
+        "2024-03-01 13:05:00"
+    );
+    assert_eq!(
+        format!(
+            "[{}] [{}]",
+            dtf.display_with(&datetime_utc, DateTimePattern::HhMm),
+            dtf.display_naive_with(&ndt, DateTimePattern::YyyyMmDd)
+        ),
+        "[13:05] [2024-03-01]"
+    );
+
+    // Width, fill, alignment and precision apply to the rendered value.
+    assert_eq!(
+        format!("[{:>25}]", dtf.display(&datetime_utc)),
+        "[      2024-03-01 13:05:00]"
+    );
+    assert_eq!(
+        format!(
+            "[{:*<8}] [{:^9}] [{:.4}]",
+            dtf.display_with(&datetime_utc, DateTimePattern::HhMm),
+            dtf.display_with(&datetime_utc, DateTimePattern::HhMm),
+            dtf.display_naive_with(&ndt, DateTimePattern::YyyyMmDd)
+        ),
+        "[13:05***] [  13:05  ] [2024]"
+    );
+
+    // Any `dyn` formatter, such as the one of `of_pattern` or the shared default one.
+    let boxed = dtf.of_pattern(DateTimePattern::YyyyMmDd);
+    assert_eq!(
+        format!(
+            "{:>12}",
+            DisplayDateTime::utc(&*boxed, &datetime_utc, boxed.activated_pattern())
+        ),
+        "  2024-03-01"
+    );
+    let shared: SharedDateTimeFormatter = Arc::new(DefaultDateTimeFormatter::builtin());
+    assert_eq!(
+        DisplayDateTime::naive(&*shared, &ndt, DateTimePattern::HhMm).to_string(),
+        "13:05"
+    );
+
+    let dtf = DefaultDateTimeFormatter::localized(Locale::FrFr);
+    let mut buffer = String::new();
+    write!(buffer, "{}", dtf.display_naive(&ndt)).unwrap();
+    assert_eq!(buffer, dtf.format_naive_date_time_default(&ndt));
+}
+
+#[test]
+fn test_date_time_write_fn() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    let ndt = datetime_utc.naive_utc();
+
+    let mut buffer = String::new();
+    formatter::write_date_time_utc_default(&mut buffer, &datetime_utc).unwrap();
+    buffer.push('|');
+    formatter::write_date_time_utc(&mut buffer, &datetime_utc, DateTimePattern::HhMm).unwrap();
+    buffer.push('|');
+    formatter::write_naive_date_time_default(&mut buffer, &ndt).unwrap();
+    buffer.push('|');
+    formatter::write_naive_date_time(&mut buffer, &ndt, DateTimePattern::YyyyMmDd).unwrap();
+
+    assert_eq!(
+        buffer,
+        "2024-03-01 13:05:00|13:05|2024-03-01 13:05:00|2024-03-01"
+    );
+}
+
+// ---------------------------------------------------------------- date-time compiled
+
+#[test]
+fn test_compiled_pattern() {
+    let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let datetimes = [
+        date(2024, 3, 1).and_hms_milli_opt(13, 5, 9, 7).unwrap(),
+        date(2024, 12, 31)
+            .and_hms_nano_opt(23, 59, 59, 999_999_999)
+            .unwrap(),
+        date(2016, 12, 31)
+            .and_hms_milli_opt(23, 59, 59, 1_500)
+            .unwrap(), // A leap second
+        date(0, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+        date(999, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+        date(9999, 12, 31).and_hms_opt(23, 59, 59).unwrap(),
+        date(-1, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+        date(10000, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+    ];
+    let patterns = [
+        (
+            DateTimePattern::YyyyMmDdHhMmSs,
+            DateTimePattern::YYYY_MM_DD_HH_MM_SS,
+        ),
+        (
+            DateTimePattern::YyyyMmDdHhMmSsSss,
+            DateTimePattern::YYYY_MM_DD_HH_MM_SS_SSS,
+        ),
+    ];
+    for (pattern, value) in patterns {
+        let compiled = CompiledPattern::new(pattern.clone());
+        for ndt in datetimes {
+            let expected = ndt.format(value).to_string();
+            assert_eq!(compiled.format(&ndt.and_utc()), expected, "{}", ndt);
+            assert_eq!(
+                DefaultDateTimeFormatter::new(pattern.clone()).format_naive_date_time_default(&ndt),
+                expected
+            );
+            assert_eq!(
+                DefaultDateTimeFormatter::builtin()
+                    .format_date_time_utc(&ndt.and_utc(), pattern.clone()),
+                expected
+            );
+        }
+    }
+    assert_eq!(
+        CompiledPattern::new(DateTimePattern::YyyyMmDdHhMmSsSss).format(&datetimes[2].and_utc()),
+        "2016-12-31 23:59:60.500"
+    );
+    assert_eq!(
+        CompiledPattern::new(DateTimePattern::YyyyMmDdHhMmSs).format(&datetimes[6].and_utc()),
+        "-0001-01-01 00:00:00"
+    );
+
+    // The other patterns format as uncompiled, in the time zone of the date-time.
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+    let datetime = datetimes[0].and_utc().with_timezone(&shanghai);
+    let dtf = DefaultDateTimeFormatter::builtin();
+    for pattern in [
+        DateTimePattern::YyyyMmDdHhMmSs,
+        DateTimePattern::YyyyMmDdHhMmSsOffset,
+        DateTimePattern::WeekdayFull,
+        DateTimePattern::Epoch(TimeUnit::Milliseconds),
+        DateTimePattern::Rfc3339(Precision::Millis),
+        DateTimePattern::HttpDate,
+        DateTimePattern::custom("%d/%m/%Y %H%M").unwrap(),
+    ] {
+        let compiled = CompiledPattern::new(pattern.clone());
+        assert_eq!(compiled.pattern(), &pattern);
+        assert_eq!(
+            compiled.format(&datetime),
+            dtf.format_date_time(&datetime, pattern.clone()),
+            "{}",
+            pattern
+        );
+    }
+}
+
+#[test]
+fn test_date_time_formatter_compiled() {
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 13, 5, 0).unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+
+    let custom = DateTimePattern::custom("%Y%m%d-%H%M").unwrap();
+    let dtf = DefaultDateTimeFormatter::new(custom.clone());
+    assert_eq!(
+        dtf.compiled_pattern().map(|compiled| compiled.pattern()),
+        Some(&custom)
+    );
+    assert_eq!(
+        dtf.format_date_time_utc_default(&datetime_utc),
+        "20240301-1305"
+    );
+    assert_eq!(
+        dtf.with_zone(shanghai)
+            .format_date_time_utc_default(&datetime_utc),
+        "20240301-2105"
+    );
+
+    // Setting the pattern compiles it.
+    let mut dtf = DefaultDateTimeFormatter::builtin();
+    dtf.set_pattern(DateTimePattern::YyyyMmDd);
+    assert_eq!(dtf.activated_pattern(), DateTimePattern::YyyyMmDd);
+    assert_eq!(
+        dtf.compiled_pattern().map(|compiled| compiled.pattern()),
+        Some(&DateTimePattern::YyyyMmDd)
+    );
+    assert_eq!(
+        dtf.format_date_time_utc_default(&datetime_utc),
+        "2024-03-01"
+    );
+    assert_eq!(
+        dtf.format_naive_date_time_default(&datetime_utc.naive_utc()),
+        "2024-03-01"
+    );
+
+    // The compiled pattern is English, a localized formatter formats with the names of its locale.
+    let dtf = DefaultDateTimeFormatter::new(DateTimePattern::MonthFull).with_locale(Locale::DeDe);
+    assert_eq!(dtf.format_date_time_utc_default(&datetime_utc), "März");
+    assert_eq!(
+        dtf.of_pattern(DateTimePattern::MonthFull)
+            .format_date_time_utc_default(&datetime_utc),
+        "März"
+    );
+}
+
+// ---------------------------------------------------------------- time-unit
+
+#[test]
+fn test_time_unit_to_nanos() {
+    assert_eq!(TimeUnit::Nanoseconds.to_nanos(1024), 1024);
+    assert_eq!(TimeUnit::Microseconds.to_nanos(1024), 1024 * 1000);
+    assert_eq!(TimeUnit::Milliseconds.to_nanos(1024), 1024 * (1000 * 1000));
+    assert_eq!(
+        TimeUnit::Seconds.to_nanos(1024),
+        1024 * (1000 * 1000 * 1000)
+    );
+    assert_eq!(
+        TimeUnit::Minutes.to_nanos(1024),
+        1024 * (1000 * 1000 * 1000 * 60)
+    );
+    assert_eq!(
+        TimeUnit::Hours.to_nanos(1024),
+        1024 * (1000 * 1000 * 1000 * 60 * 60)
+    );
+    assert_eq!(
+        TimeUnit::Days.to_nanos(1024),
+        1024 * (1000 * 1000 * 1000 * 60 * 60 * 24)
+    );
+}
+
+#[test]
+fn test_time_unit_to_micros() {
+    assert_eq!(TimeUnit::Nanoseconds.to_micros(1024), 1024 / 1000);
+    assert_eq!(TimeUnit::Microseconds.to_micros(1024), 1024);
+    assert_eq!(TimeUnit::Milliseconds.to_micros(1024), 1024 * 1000);
+    assert_eq!(TimeUnit::Seconds.to_micros(1024), 1024 * (1000 * 1000));
+    assert_eq!(TimeUnit::Minutes.to_micros(1024), 1024 * (1000 * 1000 * 60));
+    assert_eq!(
+        TimeUnit::Hours.to_micros(1024),
+        1024 * (1000 * 1000 * 60 * 60)
+    );
+    assert_eq!(
+        TimeUnit::Days.to_micros(1024),
+        1024 * (1000 * 1000 * 60 * 60 * 24)
+    );
+}
+
+#[test]
+fn test_time_unit_to_millis() {
+    assert_eq!(TimeUnit::Nanoseconds.to_millis(1024), 1024 / 1000 / 1000);
+    assert_eq!(TimeUnit::Microseconds.to_millis(1024), 1024 / 1000);
+    assert_eq!(TimeUnit::Milliseconds.to_millis(1024), 1024);
+    assert_eq!(TimeUnit::Seconds.to_millis(1024), 1024 * 1000);
+    assert_eq!(TimeUnit::Minutes.to_millis(1024), 1024 * (1000 * 60));
+    assert_eq!(TimeUnit::Hours.to_millis(1024), 1024 * (1000 * 60 * 60));
+    assert_eq!(TimeUnit::Days.to_millis(1024), 1024 * (1000 * 60 * 60 * 24));
+}
+
+#[test]
+fn test_time_unit_to_seconds() {
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_seconds(1024),
+        1024 / 1000 / 1000 / 1000
+    );
+    assert_eq!(TimeUnit::Microseconds.to_seconds(1024), 1024 / 1000 / 1000);
+    assert_eq!(TimeUnit::Milliseconds.to_seconds(1024), 1024 / 1000);
+    assert_eq!(TimeUnit::Seconds.to_seconds(1024), 1024);
+    assert_eq!(TimeUnit::Minutes.to_seconds(1024), 1024 * 60);
+    assert_eq!(TimeUnit::Hours.to_seconds(1024), 1024 * (60 * 60));
+    assert_eq!(TimeUnit::Days.to_seconds(1024), 1024 * (60 * 60 * 24));
+}
+
+#[test]
+fn test_time_unit_to_minutes() {
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_minutes(1024),
+        1024 / 1000 / 1000 / 1000 / 60
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_minutes(1024),
+        1024 / 1000 / 1000 / 60
+    );
+    assert_eq!(TimeUnit::Milliseconds.to_minutes(1024), 1024 / 1000 / 60);
+    assert_eq!(TimeUnit::Seconds.to_minutes(1024), 1024 / 60);
+    assert_eq!(TimeUnit::Minutes.to_minutes(1024), 1024);
+    assert_eq!(TimeUnit::Hours.to_minutes(1024), 1024 * 60);
+    assert_eq!(TimeUnit::Days.to_minutes(1024), 1024 * (60 * 24));
+}
+
+#[test]
+fn test_time_unit_to_hours() {
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_hours(1024),
+        1024 / 1000 / 1000 / 1000 / 60 / 60
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_hours(1024),
+        1024 / 1000 / 1000 / 60 / 60
+    );
+    assert_eq!(TimeUnit::Milliseconds.to_hours(1024), 1024 / 1000 / 60 / 60);
+    assert_eq!(TimeUnit::Seconds.to_hours(1024), 1024 / 60 / 60);
+    assert_eq!(TimeUnit::Minutes.to_hours(1024), 1024 / 60);
+    assert_eq!(TimeUnit::Hours.to_hours(1024), 1024);
+    assert_eq!(TimeUnit::Days.to_hours(1024), 1024 * 24);
+}
+
+#[test]
+fn test_time_unit_to_days() {
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_days(1024),
+        1024 / 1000 / 1000 / 1000 / 60 / 60 / 24
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_days(1024),
+        1024 / 1000 / 1000 / 60 / 60 / 24
+    );
+    assert_eq!(
+        TimeUnit::Milliseconds.to_days(1024),
+        1024 / 1000 / 60 / 60 / 24
+    );
+    assert_eq!(TimeUnit::Seconds.to_days(1024), 1024 / 60 / 60 / 24);
+    assert_eq!(TimeUnit::Minutes.to_days(1024), 1024 / 60 / 24);
+    assert_eq!(TimeUnit::Hours.to_days(1024), 1024 / 24);
+    assert_eq!(TimeUnit::Days.to_days(1024), 1024);
+}
+
+#[test]
+fn test_to_duration() {
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_duration(100),
+        Duration::from_nanos(100)
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_duration(100),
+        Duration::from_micros(100)
+    );
+    assert_eq!(
+        TimeUnit::Milliseconds.to_duration(100),
+        Duration::from_millis(100)
+    );
+    assert_eq!(TimeUnit::Seconds.to_duration(1), Duration::from_secs(1));
+    assert_eq!(TimeUnit::Minutes.to_duration(1), Duration::from_secs(60));
+    assert_eq!(TimeUnit::Hours.to_duration(1), Duration::from_secs(60 * 60));
+    assert_eq!(
+        TimeUnit::Days.to_duration(1),
+        Duration::from_secs(60 * 60 * 24)
+    );
+}
+
+#[test]
+fn test_to_chrono_duration() {
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_chrono_duration(1024),
+        chrono::Duration::nanoseconds(1024)
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_chrono_duration(1024),
+        chrono::Duration::microseconds(1024)
+    );
+    assert_eq!(
+        TimeUnit::Milliseconds.to_chrono_duration(1024),
+        chrono::Duration::milliseconds(1024)
+    );
+    assert_eq!(
+        TimeUnit::Seconds.to_chrono_duration(1024),
+        chrono::Duration::seconds(1024)
+    );
+    assert_eq!(
+        TimeUnit::Minutes.to_chrono_duration(1024),
+        chrono::Duration::minutes(1024)
+    );
+    assert_eq!(
+        TimeUnit::Hours.to_chrono_duration(1024),
+        chrono::Duration::hours(1024)
+    );
+    assert_eq!(
+        TimeUnit::Days.to_chrono_duration(1024),
+        chrono::Duration::days(1024)
+    );
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_time_unit_convert() {
+    assert_eq!(
+        TimeUnit::Milliseconds.convert(1024, TimeUnit::Hours),
+        1024 * (1000 * 60 * 60)
+    );
+    assert_eq!(TimeUnit::Hours.convert(1024, TimeUnit::Minutes), 1024 / 60);
+    assert_eq!(TimeUnit::Days.convert(1024, TimeUnit::Days), 1024);
+    assert_eq!(
+        TimeUnit::Microseconds.convert(u64::MAX, TimeUnit::Days),
+        u64::MAX
+    );
+
+    let days = u64::MAX / (60 * 60 * 24);
+    assert_eq!(
+        TimeUnit::Seconds.convert(days, TimeUnit::Days),
+        days * (60 * 60 * 24)
+    );
+    assert_eq!(TimeUnit::Days.to_seconds(days), days * (60 * 60 * 24));
+}
+
+#[test]
+fn test_time_unit_checked_convert() {
+    assert_eq!(
+        TimeUnit::Seconds.checked_convert(1024, TimeUnit::Days),
+        Ok(1024 * (60 * 60 * 24))
+    );
+    let err = TimeUnit::Seconds
+        .checked_convert(u64::MAX, TimeUnit::Days)
+        .unwrap_err();
+    assert_eq!(err.unit(), TimeUnit::Days);
+    assert_eq!(err.target(), "seconds");
+}
+
+#[test]
+fn test_time_unit_convert_duration() {
+    assert_eq!(
+        TimeUnit::Hours.convert_duration(Duration::from_secs(60 * 60 * 25)),
+        25
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.convert_duration(Duration::from_nanos(1_999)),
+        1
+    );
+    assert_eq!(TimeUnit::Days.convert_duration(Duration::from_secs(1)), 0);
+    assert_eq!(TimeUnit::Seconds.convert_duration(Duration::MAX), u64::MAX);
+}
+
+#[test]
+fn test_time_unit_checked_to() {
+    assert_eq!(
+        TimeUnit::Days.checked_to_nanos(1024),
+        Ok(1024 * (1000 * 1000 * 1000 * 60 * 60 * 24))
+    );
+    assert_eq!(
+        TimeUnit::Days.checked_to_millis(1024),
+        Ok(1024 * (1000 * 60 * 60 * 24))
+    );
+    assert_eq!(TimeUnit::Seconds.checked_to_minutes(119), Ok(1));
+    assert_eq!(TimeUnit::Nanoseconds.checked_to_days(u64::MAX), Ok(213_503));
+
+    let err = TimeUnit::Days.checked_to_nanos(u64::MAX).unwrap_err();
+    assert_eq!(err.unit(), TimeUnit::Days);
+    assert_eq!(err.amount(), u64::MAX as i128);
+    assert_eq!(err.target(), "nanoseconds");
+    assert_eq!(
+        err.to_string(),
+        "overflow converting 18446744073709551615 Days to nanoseconds"
+    );
+
+    // Direct ratio, not routed through nanoseconds.
+    let days = u64::MAX / (1000 * 60 * 60 * 24);
+    assert!(TimeUnit::Days.checked_to_nanos(days).is_err());
+    assert_eq!(
+        TimeUnit::Days.checked_to_millis(days),
+        Ok(days * (1000 * 60 * 60 * 24))
+    );
+}
+
+#[test]
+fn test_time_unit_saturating_to() {
+    assert_eq!(TimeUnit::Hours.saturating_to_seconds(2), 2 * 60 * 60);
+    assert_eq!(
+        TimeUnit::Microseconds.saturating_to_nanos(u64::MAX),
+        u64::MAX
+    );
+    assert_eq!(TimeUnit::Minutes.saturating_to_millis(u64::MAX), u64::MAX);
+    assert_eq!(TimeUnit::Days.saturating_to_hours(u64::MAX), u64::MAX);
+    assert_eq!(TimeUnit::Hours.saturating_to_days(u64::MAX), u64::MAX / 24);
+}
+
+#[test]
+fn test_time_unit_wrapping_to() {
+    assert_eq!(TimeUnit::Hours.wrapping_to_seconds(2), 2 * 60 * 60);
+    assert_eq!(
+        TimeUnit::Days.wrapping_to_nanos(u64::MAX),
+        u64::MAX.wrapping_mul(1000 * 1000 * 1000 * 60 * 60 * 24)
+    );
+    assert_eq!(
+        TimeUnit::Seconds.wrapping_to_micros(u64::MAX),
+        u64::MAX.wrapping_mul(1000 * 1000)
+    );
+    assert_eq!(
+        TimeUnit::Seconds.wrapping_to_hours(u64::MAX),
+        u64::MAX / 3600
+    );
+}
+
+#[test]
+fn test_time_unit_checked_to_duration() {
+    assert_eq!(
+        TimeUnit::Days.checked_to_duration(1),
+        Ok(Duration::from_secs(60 * 60 * 24))
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.checked_to_duration(u64::MAX),
+        Ok(Duration::from_nanos(u64::MAX))
+    );
+
+    let err = TimeUnit::Minutes.checked_to_duration(u64::MAX).unwrap_err();
+    assert_eq!(err.target(), "Duration");
+
+    assert_eq!(
+        TimeUnit::Hours.saturating_to_duration(u64::MAX),
+        Duration::MAX
+    );
+    assert_eq!(
+        TimeUnit::Hours.wrapping_to_duration(u64::MAX),
+        Duration::from_secs(u64::MAX.wrapping_mul(60 * 60))
+    );
+}
+
+#[test]
+fn test_time_unit_checked_to_chrono_duration() {
+    assert_eq!(
+        TimeUnit::Days.checked_to_chrono_duration(1024),
+        Ok(chrono::Duration::days(1024))
+    );
+
+    let err = TimeUnit::Milliseconds
+        .checked_to_chrono_duration(u64::MAX)
+        .unwrap_err();
+    assert_eq!(err.target(), "chrono::Duration");
+    assert!(TimeUnit::Seconds
+        .checked_to_chrono_duration(i64::MAX as u64)
+        .is_err());
+
+    assert_eq!(
+        TimeUnit::Seconds.saturating_to_chrono_duration(u64::MAX),
+        chrono::Duration::MAX
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.wrapping_to_chrono_duration(1024),
+        chrono::Duration::microseconds(1024)
+    );
+}
+
+#[test]
+fn test_time_unit_convert_rounded() {
+    let cases = [
+        (RoundingMode::Floor, [1, 1, 2, -2, -2, -3]),
+        (RoundingMode::Ceil, [2, 2, 3, -1, -1, -2]),
+        (RoundingMode::HalfUp, [1, 2, 3, -1, -2, -3]),
+        (RoundingMode::HalfEven, [1, 2, 2, -1, -2, -2]),
+        (RoundingMode::Truncate, [1, 1, 2, -1, -1, -2]),
+    ];
+    let seconds = [61, 90, 150, -61, -90, -150];
+
+    for (mode, expected) in cases {
+        for (amount, minutes) in seconds.iter().zip(expected) {
+            assert_eq!(
+                TimeUnit::Minutes.convert_rounded_i64(*amount, TimeUnit::Seconds, mode),
+                minutes,
+                "{:?} {}",
+                mode,
+                amount
+            );
+        }
+    }
+
+    assert_eq!(
+        TimeUnit::Minutes.convert_rounded(120, TimeUnit::Seconds, RoundingMode::Ceil),
+        2
+    );
+    assert_eq!(
+        TimeUnit::Minutes.convert_rounded(119, TimeUnit::Seconds, RoundingMode::Ceil),
+        2
+    );
+    assert_eq!(
+        TimeUnit::Seconds.convert_rounded(2, TimeUnit::Minutes, RoundingMode::Floor),
+        120
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.convert_rounded(u64::MAX, TimeUnit::Days, RoundingMode::Ceil),
+        u64::MAX
+    );
+    assert_eq!(
+        TimeUnit::Days.convert_rounded(u64::MAX, TimeUnit::Nanoseconds, RoundingMode::Ceil),
+        213_504
+    );
+}
+
+#[test]
+fn test_time_unit_convert_with_remainder() {
+    assert_eq!(
+        TimeUnit::Minutes.convert_with_remainder(119, TimeUnit::Seconds),
+        (1, 59)
+    );
+    assert_eq!(
+        TimeUnit::Hours.convert_with_remainder(3_599_999, TimeUnit::Milliseconds),
+        (0, 3_599_999)
+    );
+    assert_eq!(
+        TimeUnit::Milliseconds.convert_with_remainder(1, TimeUnit::Hours),
+        (3_600_000, 0)
+    );
+    assert_eq!(
+        TimeUnit::Minutes.convert_with_remainder_i64(-119, TimeUnit::Seconds),
+        (-1, -59)
+    );
+    assert_eq!(
+        TimeUnit::Seconds.convert_with_remainder_i64(-2, TimeUnit::Minutes),
+        (-120, 0)
+    );
+}
+
+#[test]
+fn test_time_unit_to_f64() {
+    assert_eq!(TimeUnit::Milliseconds.to_hours_f64(5_400_000.0), 1.5);
+    assert_eq!(TimeUnit::Seconds.to_minutes_f64(-90.0), -1.5);
+    assert_eq!(TimeUnit::Days.to_hours_f64(0.5), 12.0);
+    assert_eq!(TimeUnit::Nanoseconds.to_micros_f64(1.0), 0.001);
+    assert_eq!(TimeUnit::Minutes.to_seconds_f64(1.0), 60.0);
+    assert_eq!(TimeUnit::Seconds.to_millis_f64(0.001), 1.0);
+    assert_eq!(TimeUnit::Hours.to_days_f64(6.0), 0.25);
+    assert_eq!(TimeUnit::Microseconds.to_nanos_f64(0.5), 500.0);
+    assert_eq!(
+        TimeUnit::Days.convert_f64(129_600.0, TimeUnit::Seconds),
+        1.5
+    );
+}
+
+#[test]
+fn test_time_unit_ratio() {
+    let ratio = TimeUnit::Days.ratio(TimeUnit::Nanoseconds);
+    assert_eq!(ratio.numerator(), 86_400_000_000_000);
+    assert_eq!(ratio.denominator(), 1);
+    assert_eq!(ratio.to_string(), "86400000000000/1");
+
+    let ratio = TimeUnit::Minutes.ratio(TimeUnit::Hours);
+    assert_eq!((ratio.numerator(), ratio.denominator()), (1, 60));
+    assert_eq!(ratio.reciprocal(), TimeUnit::Hours.ratio(TimeUnit::Minutes));
+    assert_eq!(ratio.to_f64(), 1.0 / 60.0);
+
+    let ratio = TimeUnit::Seconds.ratio(TimeUnit::Seconds);
+    assert_eq!((ratio.numerator(), ratio.denominator()), (1, 1));
+}
+
+#[test]
+fn test_time_unit_to_i64() {
+    assert_eq!(
+        TimeUnit::Days.to_nanos_i64(-1),
+        -(1000 * 1000 * 1000 * 60 * 60 * 24)
+    );
+    assert_eq!(TimeUnit::Nanoseconds.to_micros_i64(-1999), -1);
+    assert_eq!(TimeUnit::Seconds.to_millis_i64(-2), -2000);
+    assert_eq!(TimeUnit::Hours.to_seconds_i64(-1), -(60 * 60));
+    assert_eq!(TimeUnit::Seconds.to_minutes_i64(-119), -1);
+    assert_eq!(TimeUnit::Seconds.to_minutes_i64(119), 1);
+    assert_eq!(TimeUnit::Minutes.to_hours_i64(-59), 0);
+    assert_eq!(TimeUnit::Hours.to_days_i64(-49), -2);
+
+    assert_eq!(TimeUnit::Days.to_nanos_i64(i64::MAX), i64::MAX);
+    assert_eq!(TimeUnit::Days.to_nanos_i64(i64::MIN), i64::MIN);
+}
+
+#[test]
+fn test_time_unit_convert_signed() {
+    assert_eq!(TimeUnit::Minutes.convert_i64(-119, TimeUnit::Seconds), -1);
+    assert_eq!(TimeUnit::Seconds.convert_i64(-1, TimeUnit::Days), -86_400);
+    assert_eq!(
+        TimeUnit::Seconds.checked_convert_i64(i64::MIN, TimeUnit::Minutes),
+        Err(OverflowError::new(
+            TimeUnit::Minutes,
+            i64::MIN as i128,
+            "seconds"
+        ))
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.convert_i128(-(u64::MAX as i128), TimeUnit::Days),
+        -(u64::MAX as i128) * 86_400_000_000_000
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.convert_i128(i128::MIN, TimeUnit::Microseconds),
+        i128::MIN
+    );
+    assert!(TimeUnit::Nanoseconds
+        .checked_convert_i128(i128::MAX, TimeUnit::Microseconds)
+        .is_err());
+}
+
+#[test]
+fn test_time_unit_to_chrono_duration_i64() {
+    assert_eq!(
+        TimeUnit::Minutes.to_chrono_duration_i64(-5),
+        chrono::Duration::minutes(-5)
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_chrono_duration_i64(i64::MIN),
+        chrono::Duration::nanoseconds(i64::MIN)
+    );
+    assert!(TimeUnit::Seconds
+        .checked_to_chrono_duration_i64(i64::MIN)
+        .is_err());
+    assert_eq!(
+        TimeUnit::Seconds.saturating_to_chrono_duration_i64(i64::MIN),
+        chrono::Duration::MIN
+    );
+    assert_eq!(
+        TimeUnit::Seconds.saturating_to_chrono_duration_i64(i64::MAX),
+        chrono::Duration::MAX
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "overflow converting 18446744073709551615 Nanoseconds to chrono::Duration"
+)]
+fn test_time_unit_to_chrono_duration_overflow() {
+    TimeUnit::Nanoseconds.to_chrono_duration(u64::MAX);
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_time_unit_sleep() {
+    let start = std::time::Instant::now();
+    TimeUnit::Milliseconds.sleep(1024);
+    let duration = start.elapsed();
+    assert!(duration >= Duration::from_millis(1024));
+}
+
+#[test]
+fn test_time_unit_closure_sleep() {
+    let start = std::time::Instant::now();
+
+    TimeUnit::Milliseconds.closure_sleep(1024, |x| {
+        assert_eq!(x, Duration::from_millis(1024));
+        thread::sleep(x);
+    });
+
+    let duration = start.elapsed();
+    assert!(duration >= Duration::from_millis(1024));
+}
+
+#[test]
+fn test_time_unit_closure_chrono_sleep() {
+    let start = std::time::Instant::now();
+
+    TimeUnit::Milliseconds.closure_chrono_sleep(1024, |x| {
+        assert_eq!(x, chrono::Duration::milliseconds(1024));
+        thread::sleep(Duration::from_millis(x.num_milliseconds() as u64));
+    });
+
+    let duration = start.elapsed();
+    assert!(duration >= Duration::from_millis(1024));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_time_unit_nanoseconds() {
+    assert_eq!(TimeUnit::Nanoseconds.to_nanos(1024), 1024);
+    assert_eq!(TimeUnit::Nanoseconds.to_micros(1024), 1024 / 1000);
+    assert_eq!(TimeUnit::Nanoseconds.to_millis(1024), 1024 / 1000 / 1000);
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_seconds(1024),
+        1024 / 1000 / 1000 / 1000
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_minutes(1024),
+        1024 / 1000 / 1000 / 1000 / 60
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_hours(1024),
+        1024 / 1000 / 1000 / 1000 / 60 / 60
+    );
+    assert_eq!(
+        TimeUnit::Nanoseconds.to_days(1024),
+        1024 / 1000 / 1000 / 1000 / 60 / 60 / 24
+    );
+}
+
+#[test]
+fn test_time_unit_microseconds() {
+    assert_eq!(TimeUnit::Microseconds.to_nanos(1024), 1024 * 1000);
+    assert_eq!(TimeUnit::Microseconds.to_micros(1024), 1024);
+    assert_eq!(TimeUnit::Microseconds.to_millis(1024), 1024 / 1000);
+    assert_eq!(TimeUnit::Microseconds.to_seconds(1024), 1024 / 1000 / 1000);
+    assert_eq!(
+        TimeUnit::Microseconds.to_minutes(1024),
+        1024 / 1000 / 1000 / 60
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_hours(1024),
+        1024 / 1000 / 1000 / 60 / 60
+    );
+    assert_eq!(
+        TimeUnit::Microseconds.to_days(1024),
+        1024 / 1000 / 1000 / 60 / 60 / 24
+    );
+}
+
+#[test]
+fn test_time_unit_milliseconds() {
+    assert_eq!(TimeUnit::Milliseconds.to_nanos(1024), 1024 * 1000 * 1000);
+    assert_eq!(TimeUnit::Milliseconds.to_micros(1024), 1024 * 1000);
+    assert_eq!(TimeUnit::Milliseconds.to_millis(1024), 1024);
+    assert_eq!(TimeUnit::Milliseconds.to_seconds(1024), 1024 / 1000);
+    assert_eq!(TimeUnit::Milliseconds.to_minutes(1024), 1024 / 1000 / 60);
+    assert_eq!(TimeUnit::Milliseconds.to_hours(1024), 1024 / 1000 / 60 / 60);
+    assert_eq!(
+        TimeUnit::Milliseconds.to_days(1024),
+        1024 / 1000 / 60 / 60 / 24
+    );
+}
+
+#[test]
+fn test_time_unit_seconds() {
+    assert_eq!(TimeUnit::Seconds.to_nanos(1024), 1024 * 1000 * 1000 * 1000);
+    assert_eq!(TimeUnit::Seconds.to_micros(1024), 1024 * 1000 * 1000);
+    assert_eq!(TimeUnit::Seconds.to_millis(1024), 1024 * 1000);
+    assert_eq!(TimeUnit::Seconds.to_seconds(1024), 1024);
+    assert_eq!(TimeUnit::Seconds.to_minutes(1024), 1024 / 60);
+    assert_eq!(TimeUnit::Seconds.to_hours(1024), 1024 / 60 / 60);
+    assert_eq!(TimeUnit::Seconds.to_days(1024), 1024 / 60 / 60 / 24);
+}
+
+#[test]
+fn test_time_unit_minutes() {
+    assert_eq!(
+        TimeUnit::Minutes.to_nanos(1024),
+        1024 * 1000 * 1000 * 1000 * 60
+    );
+    assert_eq!(TimeUnit::Minutes.to_micros(1024), 1024 * 1000 * 1000 * 60);
+    assert_eq!(TimeUnit::Minutes.to_millis(1024), 1024 * 1000 * 60);
     assert_eq!(TimeUnit::Minutes.to_seconds(1024), 1024 * 60);
     assert_eq!(TimeUnit::Minutes.to_minutes(1024), 1024);
     assert_eq!(TimeUnit::Minutes.to_hours(1024), 1024 / 60);
@@ -1383,3 +3870,709 @@ fn test_time_unit_value() {
     assert_eq!(TimeUnit::Hours.value(), "Hours");
     assert_eq!(TimeUnit::Days.value(), "Days");
 }
+
+#[test]
+fn test_time_unit_traits() {
+    let unit = TimeUnit::Seconds;
+    let copied = unit;
+    assert_eq!(unit, copied);
+
+    assert!(TimeUnit::Nanoseconds < TimeUnit::Microseconds);
+    assert!(TimeUnit::Hours < TimeUnit::Days);
+
+    let mut timeouts = HashMap::new();
+    timeouts.insert(TimeUnit::Seconds, 30);
+    timeouts.insert(TimeUnit::Minutes, 5);
+    assert_eq!(timeouts.get(&TimeUnit::Seconds), Some(&30));
+
+    let ordered: BTreeMap<TimeUnit, u64> = [(TimeUnit::Days, 1), (TimeUnit::Nanoseconds, 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        ordered.keys().copied().collect::<Vec<_>>(),
+        vec![TimeUnit::Nanoseconds, TimeUnit::Days]
+    );
+}
+
+#[test]
+fn test_time_unit_display_from_str() {
+    assert_eq!(TimeUnit::Nanoseconds.to_string(), "Nanoseconds");
+    assert_eq!(TimeUnit::Microseconds.to_string(), "Microseconds");
+    assert_eq!(TimeUnit::Milliseconds.to_string(), "Milliseconds");
+    assert_eq!(TimeUnit::Seconds.to_string(), "Seconds");
+    assert_eq!(TimeUnit::Minutes.to_string(), "Minutes");
+    assert_eq!(TimeUnit::Hours.to_string(), "Hours");
+    assert_eq!(TimeUnit::Days.to_string(), "Days");
+
+    assert_eq!("Hours".parse::<TimeUnit>(), Ok(TimeUnit::Hours));
+    assert_eq!("minutes".parse::<TimeUnit>(), Ok(TimeUnit::Minutes));
+    assert_eq!("MILLISECONDS".parse(), Ok(TimeUnit::Milliseconds));
+
+    let err = "Invalid".parse::<TimeUnit>().unwrap_err();
+    assert_eq!(err.value(), "Invalid");
+    assert_eq!(err.to_string(), "invalid time unit: `Invalid`");
+}
+
+#[test]
+fn test_time_unit_parse_alias() {
+    let cases = [
+        ("ns", TimeUnit::Nanoseconds),
+        ("nanos", TimeUnit::Nanoseconds),
+        ("\u{b5}s", TimeUnit::Microseconds),
+        ("\u{3bc}s", TimeUnit::Microseconds),
+        ("us", TimeUnit::Microseconds),
+        ("micro", TimeUnit::Microseconds),
+        ("ms", TimeUnit::Milliseconds),
+        ("millisecond", TimeUnit::Milliseconds),
+        ("s", TimeUnit::Seconds),
+        ("sec", TimeUnit::Seconds),
+        ("secs", TimeUnit::Seconds),
+        ("second", TimeUnit::Seconds),
+        ("m", TimeUnit::Minutes),
+        ("min", TimeUnit::Minutes),
+        ("mins", TimeUnit::Minutes),
+        ("h", TimeUnit::Hours),
+        ("hr", TimeUnit::Hours),
+        ("HRS", TimeUnit::Hours),
+        ("d", TimeUnit::Days),
+        ("Day", TimeUnit::Days),
+        (" days ", TimeUnit::Days),
+    ];
+    for (alias, unit) in cases {
+        assert_eq!(
+            TimeUnit::parse_alias(alias, UnitAliasMode::Lenient),
+            Ok(unit),
+            "{}",
+            alias
+        );
+        assert_eq!(alias.parse::<TimeUnit>(), Ok(unit), "{}", alias);
+    }
+
+    assert_eq!(
+        TimeUnit::parse_alias("min", UnitAliasMode::Strict),
+        Ok(TimeUnit::Minutes)
+    );
+    let err = TimeUnit::parse_alias("m", UnitAliasMode::Strict).unwrap_err();
+    assert!(err.is_ambiguous());
+    assert_eq!(err.to_string(), "ambiguous time unit: `m`");
+
+    let err = TimeUnit::parse_alias("fortnight", UnitAliasMode::Lenient).unwrap_err();
+    assert!(!err.is_ambiguous());
+    assert_eq!(err.to_string(), "invalid time unit: `fortnight`");
+}
+
+#[test]
+fn test_time_unit_symbol_abbreviation() {
+    let units = [
+        TimeUnit::Nanoseconds,
+        TimeUnit::Microseconds,
+        TimeUnit::Milliseconds,
+        TimeUnit::Seconds,
+        TimeUnit::Minutes,
+        TimeUnit::Hours,
+        TimeUnit::Days,
+        TimeUnit::Weeks,
+    ];
+    for unit in units {
+        assert_eq!(unit.symbol().parse::<TimeUnit>(), Ok(unit));
+        assert_eq!(unit.abbreviation().parse::<TimeUnit>(), Ok(unit));
+        assert_eq!(
+            TimeUnit::parse_alias(unit.symbol(), UnitAliasMode::Strict),
+            Ok(unit)
+        );
+    }
+}
+
+#[test]
+fn test_time_unit_weeks() {
+    assert_eq!(TimeUnit::Weeks.to_days(2), 14);
+    assert_eq!(TimeUnit::Weeks.to_seconds(1), 604_800);
+    assert_eq!(TimeUnit::Days.to_weeks(20), 2);
+    assert_eq!(TimeUnit::Weeks.to_duration(1), Duration::from_secs(604_800));
+    assert_eq!(
+        TimeUnit::Weeks.to_chrono_duration(3),
+        chrono::Duration::weeks(3)
+    );
+    assert_eq!(TimeUnit::Weeks.to_days_i64(-2), -14);
+    assert_eq!(
+        TimeUnit::Weeks.checked_to_nanos(30_500).unwrap(),
+        30_500 * 604_800_000_000_000
+    );
+    assert!(TimeUnit::Weeks.checked_to_nanos(30_501).is_err());
+    assert_eq!(
+        TimeUnit::Weeks.saturating_to_duration(u64::MAX),
+        Duration::MAX
+    );
+    assert_eq!(TimeUnit::Weeks.convert(1, TimeUnit::Weeks), 1);
+    assert_eq!(TimeUnit::Weeks.ratio(TimeUnit::Days).numerator(), 7);
+    assert!(TimeUnit::Days < TimeUnit::Weeks);
+
+    assert_eq!(TimeUnit::value_of("Weeks"), Some(TimeUnit::Weeks));
+    assert_eq!(
+        TimeUnit::insensitive_case_value_of("WEEKS"),
+        Some(TimeUnit::Weeks)
+    );
+    for alias in ["w", "wk", "wks", "week", "Weeks"] {
+        assert_eq!(alias.parse::<TimeUnit>(), Ok(TimeUnit::Weeks), "{}", alias);
+    }
+    assert_eq!(TimeUnit::Weeks.to_string(), "Weeks");
+
+    assert_eq!(
+        duration::parse("2w 1d"),
+        Ok(Duration::from_secs(15 * 86_400))
+    );
+    assert_eq!(
+        duration::format(Duration::from_secs(15 * 86_400), DurationStyle::Compact),
+        "15d"
+    );
+    assert_eq!(
+        DurationFormatter::new(DurationStyle::Verbose)
+            .with_largest_unit(TimeUnit::Weeks)
+            .format(Duration::from_secs(15 * 86_400)),
+        "2 weeks, 1 day"
+    );
+}
+
+// ---------------------------------------------------------------- calendar-unit
+
+#[test]
+fn test_calendar_unit_add_to_date() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let cases = [
+        (
+            CalendarUnit::Months,
+            date(2024, 1, 31),
+            1,
+            date(2024, 2, 29),
+        ),
+        (
+            CalendarUnit::Months,
+            date(2023, 1, 31),
+            1,
+            date(2023, 2, 28),
+        ),
+        (
+            CalendarUnit::Months,
+            date(2024, 1, 31),
+            2,
+            date(2024, 3, 31),
+        ),
+        (
+            CalendarUnit::Months,
+            date(2024, 3, 31),
+            -1,
+            date(2024, 2, 29),
+        ),
+        (
+            CalendarUnit::Months,
+            date(2024, 5, 15),
+            -17,
+            date(2022, 12, 15),
+        ),
+        (
+            CalendarUnit::Months,
+            date(2024, 5, 15),
+            0,
+            date(2024, 5, 15),
+        ),
+        (
+            CalendarUnit::Quarters,
+            date(2024, 8, 31),
+            1,
+            date(2024, 11, 30),
+        ),
+        (
+            CalendarUnit::Quarters,
+            date(2024, 1, 1),
+            -1,
+            date(2023, 10, 1),
+        ),
+        (CalendarUnit::Years, date(2024, 2, 29), 1, date(2025, 2, 28)),
+        (CalendarUnit::Years, date(2024, 2, 29), 4, date(2028, 2, 29)),
+        (
+            CalendarUnit::Years,
+            date(2024, 2, 29),
+            -100,
+            date(1924, 2, 29),
+        ),
+    ];
+    for (unit, start, amount, expected) in cases {
+        assert_eq!(
+            unit.add_to_date(start, amount),
+            Some(expected),
+            "{} {} {}",
+            start,
+            amount,
+            unit
+        );
+    }
+
+    // The clamping is not remembered.
+    let once = CalendarUnit::Months
+        .add_to_date(date(2024, 1, 31), 1)
+        .unwrap();
+    assert_eq!(
+        CalendarUnit::Months.add_to_date(once, 1),
+        Some(date(2024, 3, 29))
+    );
+
+    assert_eq!(
+        CalendarUnit::Years.add_to_date(date(2024, 1, 1), 1_000_000),
+        None
+    );
+    assert_eq!(
+        CalendarUnit::Years.add_to_date(date(2024, 1, 1), i64::MIN),
+        None
+    );
+    assert_eq!(
+        CalendarUnit::Months.add_to_date(date(2024, 1, 1), 1i64 << 33),
+        None
+    );
+}
+
+#[test]
+fn test_calendar_unit_add_to_date_time() {
+    let ndt = NaiveDateTime::parse_from_str("2024-01-31 22:55:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    assert_eq!(
+        CalendarUnit::Months.add_to_naive_date_time(ndt, 1),
+        Some(NaiveDateTime::parse_from_str("2024-02-29 22:55:00", "%Y-%m-%d %H:%M:%S").unwrap())
+    );
+
+    let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+    let datetime = offset.with_ymd_and_hms(2024, 5, 31, 23, 30, 0).unwrap();
+    assert_eq!(
+        CalendarUnit::Months.add_to_date_time(datetime, 1),
+        Some(offset.with_ymd_and_hms(2024, 6, 30, 23, 30, 0).unwrap())
+    );
+    assert_eq!(
+        CalendarUnit::Years
+            .add_to_date_time(Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap(), -1),
+        Some(Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn test_calendar_unit_traits() {
+    for unit in [
+        CalendarUnit::Months,
+        CalendarUnit::Quarters,
+        CalendarUnit::Years,
+    ] {
+        assert_eq!(unit.to_string().parse::<CalendarUnit>(), Ok(unit));
+        assert_eq!(unit.value(), unit.to_string());
+    }
+    assert!(CalendarUnit::Months < CalendarUnit::Years);
+    assert_eq!(" MO ".parse::<CalendarUnit>(), Ok(CalendarUnit::Months));
+    assert_eq!(
+        "m".parse::<CalendarUnit>().unwrap_err().to_string(),
+        "invalid calendar unit: `m`"
+    );
+    assert_eq!(
+        "fortnight".parse::<CalendarUnit>().unwrap_err().value(),
+        "fortnight"
+    );
+}
+
+// ---------------------------------------------------------------- duration
+
+#[test]
+fn test_duration_parse() {
+    let cases = [
+        ("1h 30m 15s 250ms", Duration::from_millis(5_415_250)),
+        ("1h30m", Duration::from_secs(5_400)),
+        ("1h 30m 15.5s", Duration::from_millis(5_415_500)),
+        ("2 days 3 hours", Duration::from_secs(183_600)),
+        (
+            "2 days, 3 hours and 5 minutes",
+            Duration::from_secs(183_900),
+        ),
+        ("  +1.5 hours  ", Duration::from_secs(5_400)),
+        (".5s", Duration::from_millis(500)),
+        ("5.s", Duration::from_secs(5)),
+        ("1µs 1us 1ns", Duration::from_nanos(2_001)),
+        ("0.0000000001s", Duration::ZERO),
+        ("1d 1d", Duration::from_secs(172_800)),
+        ("1.5w", Duration::from_secs(907_200)),
+        (
+            "0.999999999999999999 weeks",
+            Duration::from_nanos(604_799_999_999_999),
+        ),
+        ("0", Duration::ZERO),
+        ("-0s", Duration::ZERO),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(duration::parse(value), Ok(expected), "{}", value);
+    }
+}
+
+#[test]
+fn test_duration_parse_chrono() {
+    assert_eq!(
+        duration::parse_chrono("-5m"),
+        Ok(chrono::Duration::minutes(-5))
+    );
+    assert_eq!(
+        duration::parse_chrono("- 1.5s"),
+        Ok(chrono::Duration::milliseconds(-1_500))
+    );
+    assert_eq!(
+        duration::parse_chrono("106751991167d"),
+        Ok(chrono::Duration::days(106_751_991_167))
+    );
+    assert_eq!(
+        duration::parse_chrono("106751991168d").unwrap_err().kind(),
+        &ParseDurationErrorKind::Overflow
+    );
+}
+
+#[test]
+fn test_duration_parse_error() {
+    let cases = [
+        ("", ParseDurationErrorKind::Empty, 0),
+        ("   ", ParseDurationErrorKind::Empty, 0),
+        ("-", ParseDurationErrorKind::Empty, 1),
+        ("15", ParseDurationErrorKind::MissingUnit, 2),
+        ("1h 30", ParseDurationErrorKind::MissingUnit, 5),
+        ("1h x", ParseDurationErrorKind::UnexpectedToken('x'), 3),
+        (
+            "1h 30 x",
+            ParseDurationErrorKind::UnknownUnit("x".to_string()),
+            6,
+        ),
+        (
+            "3 fortnights",
+            ParseDurationErrorKind::UnknownUnit("fortnights".to_string()),
+            2,
+        ),
+        ("1h30m!", ParseDurationErrorKind::UnexpectedToken('!'), 5),
+        ("1h,", ParseDurationErrorKind::UnexpectedToken(','), 2),
+        ("1h and ", ParseDurationErrorKind::UnexpectedToken('a'), 3),
+        ("1h -5m", ParseDurationErrorKind::UnexpectedToken('-'), 3),
+        ("1..5s", ParseDurationErrorKind::UnexpectedToken('.'), 2),
+        (". s", ParseDurationErrorKind::UnexpectedToken('.'), 0),
+        ("1h--", ParseDurationErrorKind::UnexpectedToken('-'), 2),
+        ("-5m", ParseDurationErrorKind::Negative, 0),
+        ("  -5m", ParseDurationErrorKind::Negative, 2),
+        (
+            "99999999999999999999999999999999999999999d",
+            ParseDurationErrorKind::Overflow,
+            0,
+        ),
+        ("1d 213503982334601d", ParseDurationErrorKind::Overflow, 0),
+    ];
+    for (value, kind, position) in cases {
+        let err = duration::parse(value).unwrap_err();
+        assert_eq!(err.kind(), &kind, "{}", value);
+        assert_eq!(err.position(), position, "{}", value);
+    }
+
+    assert_eq!(
+        duration::parse("1h 30").unwrap_err().to_string(),
+        "missing unit at position 5"
+    );
+    assert_eq!(
+        duration::parse("1h30m!").unwrap_err().to_string(),
+        "unexpected `!` at position 5"
+    );
+}
+
+#[test]
+fn test_duration_parser_alias_mode() {
+    let strict = DurationParser::new().with_alias_mode(UnitAliasMode::Strict);
+    assert_eq!(strict.alias_mode(), UnitAliasMode::Strict);
+
+    let err = strict.parse("1h 5m").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &ParseDurationErrorKind::AmbiguousUnit("m".to_string())
+    );
+    assert_eq!(err.position(), 4);
+    assert_eq!(strict.parse("1h 5min"), Ok(Duration::from_secs(3_900)));
+    assert_eq!(
+        DurationParser::default().parse("1h 5m"),
+        Ok(Duration::from_secs(3_900))
+    );
+}
+
+#[test]
+fn test_duration_format() {
+    let duration = Duration::from_millis(5_415_250);
+    let cases = [
+        (DurationStyle::Compact, "1h30m15s250ms"),
+        (
+            DurationStyle::Verbose,
+            "1 hour, 30 minutes, 15 seconds, 250 milliseconds",
+        ),
+        (DurationStyle::Largest(1), "~1 hour"),
+        (DurationStyle::Largest(2), "~1 hour 30 minutes"),
+        (
+            DurationStyle::Largest(4),
+            "1 hour 30 minutes 15 seconds 250 milliseconds",
+        ),
+        (
+            DurationStyle::Largest(9),
+            "1 hour 30 minutes 15 seconds 250 milliseconds",
+        ),
+        (DurationStyle::Fixed(TimeUnit::Seconds, 3), "5415.250s"),
+        (DurationStyle::Fixed(TimeUnit::Seconds, 0), "5415s"),
+        (DurationStyle::Fixed(TimeUnit::Minutes, 2), "90.25min"),
+        (
+            DurationStyle::Fixed(TimeUnit::Milliseconds, 12),
+            "5415250.000000000000ms",
+        ),
+        (
+            DurationStyle::Fixed(TimeUnit::Days, 20),
+            "0.06267650462962962962d",
+        ),
+    ];
+    for (style, expected) in cases {
+        assert_eq!(duration::format(duration, style), expected, "{:?}", style);
+    }
+
+    assert_eq!(
+        duration::format(Duration::ZERO, DurationStyle::Compact),
+        "0s"
+    );
+    assert_eq!(
+        duration::format(Duration::ZERO, DurationStyle::Largest(2)),
+        "0 seconds"
+    );
+    assert_eq!(
+        duration::format(Duration::from_nanos(1), DurationStyle::Verbose),
+        "1 nanosecond"
+    );
+    assert_eq!(
+        duration::format(Duration::MAX, DurationStyle::Compact),
+        "213503982334601d7h15s999ms999us999ns"
+    );
+    assert_eq!(
+        duration::format_chrono(
+            chrono::Duration::milliseconds(-1_500),
+            DurationStyle::Largest(1)
+        ),
+        "-~1 second"
+    );
+
+    // Adjacent components only, and at least one.
+    let duration = Duration::from_secs(2 * 86_400 + 5 * 60);
+    assert_eq!(
+        duration::format(duration, DurationStyle::Largest(2)),
+        "~2 days"
+    );
+    assert_eq!(
+        duration::format(duration, DurationStyle::Largest(0)),
+        "~2 days"
+    );
+    assert_eq!(
+        duration::format(Duration::from_secs(90), DurationStyle::Largest(0)),
+        "~1 minute"
+    );
+    assert_eq!(
+        duration::format(Duration::from_secs(60), DurationStyle::Largest(0)),
+        "1 minute"
+    );
+}
+
+#[test]
+fn test_duration_formatter_units() {
+    let duration = Duration::from_secs(2 * 86_400 + 15) + Duration::from_micros(7);
+
+    let df = DurationFormatter::new(DurationStyle::Compact).with_smallest_unit(TimeUnit::Seconds);
+    assert_eq!(df.format(duration), "2d15s");
+    assert_eq!(df.format(Duration::from_millis(999)), "0s");
+
+    let df = df.with_largest_unit(TimeUnit::Hours);
+    assert_eq!(df.format(duration), "48h15s");
+    assert_eq!(df.with_zero_suppression(false).format(duration), "48h0m15s");
+    assert_eq!(
+        df.with_style(DurationStyle::Verbose).format(duration),
+        "48 hours, 15 seconds"
+    );
+
+    let df = DurationFormatter::default()
+        .with_smallest_unit(TimeUnit::Hours)
+        .with_largest_unit(TimeUnit::Minutes);
+    assert_eq!(df.smallest_unit(), TimeUnit::Minutes);
+    assert_eq!(df.largest_unit(), TimeUnit::Minutes);
+    assert_eq!(df.format(duration), "2880m");
+    assert_eq!(df.format(Duration::ZERO), "0m");
+
+    let df = DurationFormatter::new(DurationStyle::Largest(2)).with_zero_suppression(false);
+    assert!(!df.suppresses_zero());
+    assert_eq!(df.format(duration), "~2 days 0 hours");
+}
+
+#[test]
+fn test_duration_format_parse_round_trip() {
+    let parser = DurationParser::new();
+    for nanos in [
+        0,
+        1,
+        999,
+        1_000_001,
+        5_415_250_000_000,
+        90_061_000_000_001,
+        u64::MAX,
+    ] {
+        let duration = Duration::from_nanos(nanos);
+        for style in [DurationStyle::Compact, DurationStyle::Verbose] {
+            assert_eq!(
+                parser.parse(&duration::format(duration, style)),
+                Ok(duration),
+                "{:?} {:?}",
+                style,
+                duration
+            );
+        }
+    }
+}
+
+#[test]
+fn test_duration_iso8601_parse() {
+    let cases = [
+        ("PT1H30M", 5_400_000_000_000i128),
+        ("P3DT4H", 273_600_000_000_000),
+        ("P2W", 1_209_600_000_000_000),
+        ("P1W1D", 691_200_000_000_000),
+        ("PT15.5S", 15_500_000_000),
+        ("PT0,000000001S", 1),
+        ("PT1.1234567891S", 1_123_456_789),
+        ("pt1h", 3_600_000_000_000),
+        ("+PT1M", 60_000_000_000),
+        ("P0D", 0),
+        ("-PT5M", -300_000_000_000),
+        ("PT-6H3M", -21_420_000_000_000),
+        ("-PT-6H+3M", 21_420_000_000_000),
+        ("PT-0.5S", -500_000_000),
+        ("-PT1.5S", -1_500_000_000),
+    ];
+    for (value, nanos) in cases {
+        let expected = chrono::Duration::nanoseconds(nanos as i64);
+        assert_eq!(
+            duration::parse_iso8601_chrono(value),
+            Ok(expected),
+            "{}",
+            value
+        );
+        if nanos >= 0 {
+            assert_eq!(
+                duration::parse_iso8601(value),
+                Ok(Duration::from_nanos(nanos as u64)),
+                "{}",
+                value
+            );
+        }
+    }
+
+    let iso: IsoDuration = "P1Y2M3W4DT5H6M7.8S".parse().unwrap();
+    assert_eq!(
+        (
+            iso.years(),
+            iso.months(),
+            iso.weeks(),
+            iso.days(),
+            iso.hours(),
+            iso.minutes(),
+            iso.seconds(),
+            iso.nanos()
+        ),
+        (1, 2, 3, 4, 5, 6, 7, 800_000_000)
+    );
+    assert!(iso.has_calendar_components());
+    assert_eq!(iso.to_string(), "P1Y2M3W4DT5H6M7.8S");
+}
+
+#[test]
+fn test_duration_iso8601_parse_error() {
+    let cases = [
+        ("", ParseDurationErrorKind::Empty, 0),
+        ("P", ParseDurationErrorKind::Empty, 1),
+        ("PT", ParseDurationErrorKind::Empty, 2),
+        ("P1DT", ParseDurationErrorKind::Empty, 4),
+        ("1H", ParseDurationErrorKind::UnexpectedToken('1'), 0),
+        ("P1H", ParseDurationErrorKind::UnexpectedToken('H'), 2),
+        ("PT1D", ParseDurationErrorKind::UnexpectedToken('D'), 3),
+        ("PT1S1H", ParseDurationErrorKind::UnexpectedToken('H'), 5),
+        ("P1D1D", ParseDurationErrorKind::UnexpectedToken('D'), 4),
+        ("PT1H30", ParseDurationErrorKind::MissingUnit, 6),
+        ("P1.5D", ParseDurationErrorKind::UnexpectedToken('.'), 2),
+        ("PT1.5H", ParseDurationErrorKind::UnexpectedToken('.'), 3),
+        ("PT1.S", ParseDurationErrorKind::UnexpectedToken('.'), 3),
+        ("PT 1S", ParseDurationErrorKind::UnexpectedToken(' '), 2),
+        ("PTH", ParseDurationErrorKind::UnexpectedToken('H'), 2),
+        (
+            "PT9223372036854775808S",
+            ParseDurationErrorKind::Overflow,
+            2,
+        ),
+        (
+            "-PT-9223372036854775808S",
+            ParseDurationErrorKind::Overflow,
+            3,
+        ),
+        ("P1Y", ParseDurationErrorKind::CalendarComponent('Y'), 0),
+        ("P1M", ParseDurationErrorKind::CalendarComponent('M'), 0),
+        ("-PT1S", ParseDurationErrorKind::Negative, 0),
+        (
+            "PT9223372036854775807H",
+            ParseDurationErrorKind::Overflow,
+            0,
+        ),
+    ];
+    for (value, kind, position) in cases {
+        let err = duration::parse_iso8601(value).unwrap_err();
+        assert_eq!(err.kind(), &kind, "{}", value);
+        assert_eq!(err.position(), position, "{}", value);
+    }
+
+    assert_eq!(
+        duration::parse_iso8601_chrono("P1Y")
+            .unwrap_err()
+            .to_string(),
+        "calendar component `Y` cannot be converted exactly"
+    );
+    assert_eq!(
+        duration::parse_iso8601_chrono("PT-1S"),
+        Ok(chrono::Duration::seconds(-1))
+    );
+}
+
+#[test]
+fn test_duration_iso8601_format() {
+    let cases = [
+        (Duration::ZERO, "PT0S"),
+        (Duration::from_nanos(1), "PT0.000000001S"),
+        (Duration::from_millis(250), "PT0.25S"),
+        (Duration::from_secs(59), "PT59S"),
+        (Duration::from_secs(5_400), "PT1H30M"),
+        (Duration::from_secs(3 * 86_400 + 4 * 3_600), "PT76H"),
+        (Duration::from_millis(93_784_500), "PT26H3M4.5S"),
+    ];
+    for (duration, expected) in cases {
+        assert_eq!(duration::format_iso8601(duration), expected);
+        assert_eq!(duration::parse_iso8601(expected), Ok(duration));
+    }
+
+    assert_eq!(
+        duration::format_iso8601_chrono(chrono::Duration::milliseconds(-1_500)),
+        "-PT1.5S"
+    );
+    assert_eq!(
+        IsoDuration::parse("PT-6H3M").unwrap().to_string(),
+        "PT-6H3M"
+    );
+    assert_eq!(
+        IsoDuration::parse("PT-0.5S").unwrap().to_string(),
+        "-PT0.5S"
+    );
+    assert_eq!(IsoDuration::parse("P0D").unwrap().to_string(), "PT0S");
+    assert_eq!(IsoDuration::parse("P1WT0S").unwrap().to_string(), "P1W");
+    assert_eq!(
+        IsoDuration::from(chrono::Duration::MIN).to_chrono_duration(),
+        Ok(chrono::Duration::MIN)
+    );
+    assert_eq!(
+        IsoDuration::from(Duration::MAX).to_duration(),
+        Ok(Duration::MAX)
+    );
+}
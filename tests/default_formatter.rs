@@ -0,0 +1,244 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+// The process-wide default formatter is seen by every test of a binary, so the tests
+// overriding it live in their own binary and run one at a time.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+
+use chrono::{FixedOffset, TimeZone, Utc};
+
+use chronounit::formatter::locale::Locale;
+use chronounit::formatter::pattern::DateTimePattern;
+use chronounit::formatter::{self, DateTimeFormatter, DefaultDateTimeFormatter};
+
+// ----------------------------------------------------------------
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Runs the calling test alone, even after another one failed.
+fn serial() -> MutexGuard<'static, ()> {
+    SERIAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Formats as [`DateTimePattern::YyyyMmDd`], unlike the builtin formatter, and counts its calls,
+/// or panics on them.
+#[derive(Clone)]
+struct CountingFormatter {
+    calls: Arc<AtomicUsize>,
+    panics: bool,
+}
+
+impl CountingFormatter {
+    fn new() -> Self {
+        CountingFormatter {
+            calls: Arc::new(AtomicUsize::new(0)),
+            panics: false,
+        }
+    }
+
+    fn panicking() -> Self {
+        CountingFormatter {
+            panics: true,
+            ..CountingFormatter::new()
+        }
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+impl DateTimeFormatter for CountingFormatter {
+    fn of_pattern(&self, pattern: DateTimePattern) -> Box<dyn DateTimeFormatter> {
+        Box::new(DefaultDateTimeFormatter::new(pattern))
+    }
+
+    fn activated_pattern(&self) -> DateTimePattern {
+        DateTimePattern::YyyyMmDd
+    }
+
+    fn activated_locale(&self) -> Locale {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        assert!(!self.panics, "formatting failed");
+
+        Locale::EnUs
+    }
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_default_formatter() {
+    let _serial = serial();
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let format_default = || formatter::format_date_time_utc_default(&datetime_utc);
+
+    let outer = CountingFormatter::new();
+    let inner = CountingFormatter::new();
+    {
+        let _outer = formatter::override_default_formatter(outer.clone());
+        assert_eq!(format_default(), "2024-03-01");
+        assert_eq!(outer.calls(), 1);
+
+        {
+            let _inner = formatter::override_default_formatter(DefaultDateTimeFormatter::new(
+                DateTimePattern::HhMm,
+            ));
+            assert_eq!(format_default(), "12:00");
+            assert_eq!(outer.calls(), 1);
+        }
+
+        // The inner guard restored the outer formatter.
+        assert_eq!(
+            formatter::format_date_time_default(&datetime_utc),
+            "2024-03-01"
+        );
+        assert_eq!(
+            formatter::format_naive_date_time_default(&datetime_utc.naive_utc()),
+            "2024-03-01"
+        );
+        assert_eq!(outer.calls(), 3);
+
+        // Replaced within the scope of the guard, which restores the formatter it replaced.
+        let previous = formatter::replace_default_formatter(inner.clone());
+        assert_eq!(previous.activated_pattern(), DateTimePattern::YyyyMmDd);
+        format_default();
+        assert_eq!((outer.calls(), inner.calls()), (3, 1));
+    }
+
+    assert_eq!(format_default(), "2024-03-01 12:00:00");
+    assert_eq!((outer.calls(), inner.calls()), (3, 1));
+}
+
+#[test]
+fn test_default_formatter_out_of_order() {
+    let _serial = serial();
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let format_default = || formatter::format_date_time_utc_default(&datetime_utc);
+
+    let outer = formatter::override_default_formatter(DefaultDateTimeFormatter::new(
+        DateTimePattern::YyyyMmDd,
+    ));
+    let inner =
+        formatter::override_default_formatter(DefaultDateTimeFormatter::new(DateTimePattern::HhMm));
+    assert_eq!(format_default(), "12:00");
+
+    // Dropping the outer guard first restores the builtin formatter, and removes the inner override.
+    drop(outer);
+    assert_eq!(format_default(), "2024-03-01 12:00:00");
+    drop(inner);
+    assert_eq!(format_default(), "2024-03-01 12:00:00");
+}
+
+#[test]
+fn test_default_formatter_after_panic() {
+    let _serial = serial();
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+
+    let guard = formatter::override_default_formatter(CountingFormatter::panicking());
+    let result =
+        std::panic::catch_unwind(|| formatter::format_date_time_utc_default(&datetime_utc));
+    assert!(result.is_err());
+    drop(guard);
+
+    // Nothing is poisoned by the panic.
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 12:00:00"
+    );
+    let _guard = formatter::override_default_formatter(CountingFormatter::new());
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01"
+    );
+}
+
+#[test]
+fn test_default_formatter_across_threads() {
+    let _serial = serial();
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+
+    // Every thread sees the override, unless it overrides it locally.
+    let counting = CountingFormatter::new();
+    let _guard = formatter::override_default_formatter(counting.clone());
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            thread::spawn(move || {
+                let _local = (i % 2 == 0).then(|| {
+                    formatter::override_local_formatter(DefaultDateTimeFormatter::new(
+                        DateTimePattern::HhMm,
+                    ))
+                });
+                formatter::format_date_time_utc_default(&datetime_utc)
+            })
+        })
+        .collect();
+    let rendered: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(rendered, ["12:00", "2024-03-01", "12:00", "2024-03-01"]);
+    assert_eq!(counting.calls(), 2);
+}
+
+#[test]
+fn test_default_formatter_zoned() {
+    let _serial = serial();
+    let datetime_utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+
+    let _guard = formatter::override_default_formatter(
+        DefaultDateTimeFormatter::new(DateTimePattern::YyyyMmDdHhMmSsOffset).with_zone(shanghai),
+    );
+
+    // Every instant is rendered in the zone of the default formatter.
+    assert_eq!(
+        formatter::format_date_time_utc_default(&datetime_utc),
+        "2024-03-01 20:00:00 +08:00"
+    );
+    assert_eq!(
+        formatter::format_date_time_default(&datetime_utc.with_timezone(&new_york)),
+        "2024-03-01 20:00:00 +08:00"
+    );
+    assert_eq!(
+        formatter::format_date_time(
+            &datetime_utc.with_timezone(&new_york),
+            DateTimePattern::YyyyMmDdHhMm
+        ),
+        "2024-03-01 20:00"
+    );
+    assert_eq!(
+        chronounit::format_date_time!(&datetime_utc, DateTimePattern::HhMm),
+        "20:00"
+    );
+
+    let mut buffer = String::new();
+    formatter::write_date_time_utc(&mut buffer, &datetime_utc, DateTimePattern::HhMm).unwrap();
+    assert_eq!(buffer, "20:00");
+
+    // An explicit zone wins.
+    assert_eq!(
+        formatter::format_date_time_in_default(&datetime_utc, &new_york),
+        "2024-03-01 07:00:00 -05:00"
+    );
+}